@@ -61,6 +61,9 @@ pub enum Asset {
     #[strum(ascii_case_insensitive, serialize = "BOBA")]
     BOBA,
 
+    #[strum(ascii_case_insensitive, serialize = "MNT")]
+    MNT,
+
     // bitfinex margin
     #[strum(ascii_case_insensitive, serialize = "USTF0")]
     USTF0,
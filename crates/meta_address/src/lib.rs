@@ -143,6 +143,25 @@ pub fn get_addressed_token_info(network: Network, address: Address) -> Option<To
     })
 }
 
+/// the native gas token of `network`, such as ETH on arbitrum or MNT on mantle
+pub fn get_native_token_info(network: Network) -> Option<TokenInfo> {
+    NAMED_TOKEN_INFO_BOOK
+        .iter()
+        .find(|(_, val)| val.get(&network).is_some_and(|info| info.native))
+        .and_then(|(name, _)| get_token_info(name.as_str(), network))
+}
+
+/// the token wrapping the native gas token of `network`, such as WETH or WBNB
+pub fn get_wrapped_native_token_info(network: Network) -> Option<TokenInfo> {
+    let native = get_native_token_info(network)?;
+    NAMED_TOKEN_INFO_BOOK
+        .iter()
+        .find(|(_, val)| {
+            val.get(&network).is_some_and(|info| info.unwrap_to.eq(&Some(native.token)))
+        })
+        .and_then(|(name, _)| get_token_info(name.as_str(), network))
+}
+
 pub fn get_bot_contract_info(name: BotType, network: Network) -> Option<ContractInfo> {
     BOT_ADDRESS_BOOK.get(&name.into()).map_or(None, |v| v.get(&network).cloned())
 }
@@ -157,6 +176,23 @@ pub fn get_dex_address(
         .map_or(None, |v| v.get(&chain_name).map_or(None, |v| v.get(&contract_type).cloned()))
 }
 
+/// factories (`UniV2Factory` or `UniV3Factory`) of every dex deployed on `network`
+pub fn get_dex_factories(network: Network) -> Vec<(DexExchange, ContractType, ContractInfo)> {
+    let mut factories = DEX_ADDRESS_BOOK
+        .iter()
+        .filter_map(|(dex, v)| v.get(&network).map(|v| (dex, v)))
+        .flat_map(|(dex, v)| {
+            [ContractType::UniV2Factory, ContractType::UniV3Factory].into_iter().filter_map(
+                |contract_type| {
+                    v.get(&contract_type).cloned().map(|info| (*dex, contract_type, info))
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    factories.sort_by_key(|(dex, _, _)| *dex);
+    factories
+}
+
 pub fn get_rpc_info(network: Network) -> Option<RpcInfo> {
     RPC_INFO_BOOK.get(&network.into()).cloned()
 }
@@ -222,6 +258,17 @@ mod tests {
         //     ]
         // );
     }
+
+    #[test]
+    fn test_get_native_token_info() {
+        assert_eq!(get_native_token_info(Network::ARBI).unwrap().token, Token::ETH);
+        assert_eq!(get_native_token_info(Network::BSC).unwrap().token, Token::BNB);
+        assert_eq!(get_native_token_info(Network::MANTLE).unwrap().token, Token::MNT);
+        assert_eq!(get_wrapped_native_token_info(Network::ARBI).unwrap().token, Token::WETH);
+        assert_eq!(get_wrapped_native_token_info(Network::BSC).unwrap().token, Token::WBNB);
+        assert_eq!(get_wrapped_native_token_info(Network::MANTLE).unwrap().token, Token::WMNT);
+    }
+
     #[test]
     fn test_dex_addr() {
         let quoter =
@@ -238,6 +285,22 @@ mod tests {
         // );
     }
 
+    #[test]
+    fn test_get_dex_factories() {
+        let factories = get_dex_factories(Network::ETH);
+        assert_eq!(
+            factories
+                .iter()
+                .map(|(dex, contract_type, _)| (*dex, contract_type.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (DexExchange::UniswapV2, ContractType::UniV2Factory),
+                (DexExchange::UniswapV3, ContractType::UniV3Factory)
+            ]
+        );
+        assert_eq!(factories[1].2.created_blk_num, 12369621);
+    }
+
     #[test]
     fn test_get_rpc_info() {
         assert!(get_rpc_info(Network::ETH).is_some());
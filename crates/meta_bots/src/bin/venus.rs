@@ -2,10 +2,12 @@
 
 use ethers::prelude::*;
 use gumdrop::Options;
-use meta_address::{enums::Asset, get_rpc_info, get_token_info, TokenInfo};
+use meta_address::{enums::Asset, get_native_token_info, get_rpc_info, get_token_info, TokenInfo};
 use meta_bots::{
+    mev_bots::oracle_runner::start_block_oracle,
     venus::{
-        check_arbitrage_status, notify_arbitrage_result, update_dex_swap_finalised_info,
+        check_arbitrage_status, notify_arbitrage_result, remove_dropped_dex_swap,
+        replace_dex_swap_tx_hash, rollback_dex_swap_finalised_info, update_dex_swap_finalised_info,
         ArbitrageInstruction, ArbitragePair, CexInstruction, CexTradeInfo, DexInstruction,
         DexTradeInfo, SwapFinalisedInfo, CID,
     },
@@ -21,7 +23,14 @@ use meta_common::{
     models::MarcketChange,
 };
 use meta_contracts::bindings::uniswapv3pool::SwapFilter;
-use meta_dex::{DexBackend, DexService};
+use meta_dex::{
+    balance::{BalanceService, DexEvent},
+    gas::new_gas_strategy,
+    oracle::BlockOracle,
+    tracker::{TrackerConfig, TxEvent, TxTracker},
+    twap::{DEFAULT_MAX_TWAP_DEVIATION_BPS, DEFAULT_TWAP_WINDOW_SECS},
+    DexBackend, DexService, DEFAULT_SLIPPAGE_BPS, V3_FEE_TIERS,
+};
 use meta_integration::Lark;
 use meta_tracing::init_tracing;
 use meta_util::{get_price_delta_in_bp, time::get_current_ts};
@@ -30,18 +39,24 @@ use std::{
     collections::BTreeMap,
     path::PathBuf,
     sync::{
-        atomic::{AtomicU32, Ordering},
+        atomic::{AtomicBool, AtomicU32, Ordering},
         mpsc, Arc,
     },
     time::Duration,
 };
-use tokio::sync::RwLock;
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::{watch, RwLock},
+};
 use tracing::{debug, error, info, warn};
 
 lazy_static::lazy_static! {
     // static ref TOKIO_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
     static ref ARBITRAGES: Arc<RwLock<BTreeMap<CID, ArbitragePair>>> = Arc::new(RwLock::new(BTreeMap::new())); // key is request id
     static ref TOTAL_PENDING_TRADES: AtomicU32 = AtomicU32::new(0);
+    static ref CEX_CONNECTED: AtomicBool = AtomicBool::new(true); // false while the cex feed is lost
+    static ref CEX_SPREAD_STALE: AtomicBool = AtomicBool::new(false); // the cex spread cached predates a disconnection
+    static ref EXIT_CODE: watch::Sender<Option<exitcode::ExitCode>> = watch::channel(None).0; // set once the process must stop
 }
 
 pub const MIN_ASSET_BALANCE_MULTIPLIER: usize = 5;
@@ -72,14 +87,95 @@ struct Opts {
     private_key_path: Option<PathBuf>,
 }
 
+/// fallback fee tier, until the dex backend publishes the tier quoting the best price
 pub const V3_FEE: u32 = 500u32;
 
+/// stop the process with `code` once the cefi service is shut down, see `run`; no trade is
+/// started in the meantime
+fn request_exit(code: exitcode::ExitCode) {
+    EXIT_CODE.send_if_modified(|exit_code| match exit_code {
+        Some(_) => false,
+        None => {
+            *exit_code = Some(code);
+            true
+        }
+    });
+}
+
+/// stop the process if `balance` of `asset`, on either venue, can not fund a few more trades
+async fn check_asset_balance(
+    asset: Asset,
+    balance: Decimal,
+    base_asset: Asset,
+    quote_asset: Asset,
+    dex_spread: &Spread,
+) {
+    if asset.eq(&base_asset) {
+        unsafe {
+            if balance.le(&MIN_BASE_ASSET_BALANCE_AMT) {
+                warn!(
+                    "asset {:?} balance {:?} is below threshold {:?}",
+                    asset, balance, MIN_BASE_ASSET_BALANCE_AMT
+                );
+                request_exit(exitcode::DATAERR);
+                return;
+            }
+        }
+    }
+
+    if asset.eq(&quote_asset) {
+        let _g = dex_spread.read().await;
+        if let Some(p) = *_g {
+            unsafe {
+                let min_quote_amt = p.0.checked_mul(MIN_BASE_ASSET_BALANCE_AMT).unwrap();
+                if balance.le(&min_quote_amt) {
+                    warn!(
+                        "asset {:?} balance {:?} is below threshold {:?}",
+                        asset, balance, min_quote_amt
+                    );
+                    request_exit(exitcode::DATAERR);
+                }
+            }
+        }
+    }
+}
+
+/// # Description
+/// Whether the spot price of the v3 pool of `fee` deviates from its twap by more than
+/// `max_deviation_bps`, so the pool may be manipulated and the trade is vetoed. A twap that can not
+/// be read vetoes the trade too
+async fn is_vetoed_by_twap<M: Middleware + 'static>(
+    dex_service: &DexService<M>,
+    (base, quote): (Address, Address),
+    fee: u32,
+    (window, max_deviation_bps): (u32, u32),
+) -> bool {
+    match dex_service.get_v3_twap(base, quote, fee, window).await {
+        Ok(twap) if twap.is_deviated(max_deviation_bps) => {
+            warn!(
+                "veto trade, spot tick {:?} deviates from the {:?}s twap tick {:?} by {:?} bps",
+                twap.spot_tick,
+                window,
+                twap.tick,
+                twap.get_deviation_bps()
+            );
+            true
+        }
+        Ok(_) => false,
+        Err(e) => {
+            warn!("veto trade, error in read twap of pool with fee {:?}, {:?}", fee, e);
+            true
+        }
+    }
+}
+
 /// will be invoked when a new cex trade or dex swap occurs
 async fn handle_trade_update<M: Middleware>(dex_service: Arc<DexService<M>>, lark: Arc<Lark>) {
     let (should_stop, ret) = check_arbitrage_status(Arc::clone(&ARBITRAGES)).await;
     if should_stop {
         error!("should stop");
-        std::process::exit(exitcode::DATAERR);
+        request_exit(exitcode::DATAERR);
+        return;
     }
     if let Some((cid, arbitrage_info)) = ret {
         notify_arbitrage_result(dex_service, Arc::clone(&ARBITRAGES), lark, cid, &arbitrage_info)
@@ -146,7 +242,11 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
 
     let (tx_market_change, rx_market_change) = mpsc::sync_channel::<MarcketChange>(1000);
 
-    let (dex_service, mut dex_backend): (
+    let block_oracle = BlockOracle::new(&provider_ws, config.network).await?;
+    let mut block_oracle = Arc::new(RwLock::new(block_oracle));
+    start_block_oracle(Arc::clone(&provider_ws), &mut block_oracle);
+
+    let (mut dex_service, mut dex_backend): (
         DexService<NonceManagerMiddleware<SignerMiddleware<Arc<Provider<Ws>>, LocalWallet>>>,
         DexBackend<Provider<Ws>>,
     ) = DexService::new(
@@ -157,13 +257,92 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
         base_token.clone(),
         config.base_asset_quote_amt,
         quote_token.clone(),
-        V3_FEE,
+        config.dex_slippage_bps.unwrap_or(DEFAULT_SLIPPAGE_BPS),
         tx_market_change.clone(),
     );
+    dex_service.approval_policy = config.dex_approval_policy.unwrap_or_default();
+    dex_service.gas_strategy =
+        new_gas_strategy(Arc::clone(&provider_ws), config.network, Arc::clone(&block_oracle));
+    // the quote amount of a swap is only known from its price, so its approval waits for the
+    // first swap; the check still reads the current allowance
+    dex_service
+        .ensure_allowances(&[
+            (base_token.clone(), config.base_asset_quote_amt),
+            (quote_token.clone(), Decimal::ZERO),
+        ])
+        .await?;
+    info!("dex approvals {:?}", dex_service.get_approvals().await);
     let dex_service = Arc::new(dex_service);
 
     let lark = Arc::new(Lark::new(config.lark.webhook));
 
+    // sent swaps are sped up, then cancelled, if they stay pending; their outcome finalises or
+    // drops the arbitrages on any dex, whether a swap log is subscribed or not
+    let tx_tracker =
+        Arc::new(TxTracker::new(Arc::clone(&wallet), TrackerConfig::new(config.network)));
+    {
+        let mut tx_events = tx_tracker.subscribe();
+        let tx_tracker = Arc::clone(&tx_tracker);
+        tokio::spawn(async move {
+            tx_tracker.run().await;
+        });
+        let dex_service_clone = Arc::clone(&dex_service);
+        let lark_clone = Arc::clone(&lark);
+        tokio::spawn(async move {
+            while let Ok(event) = tx_events.recv().await {
+                match event {
+                    TxEvent::Confirmed { order, hash, receipt }
+                    | TxEvent::Reverted { order, hash, receipt } => {
+                        if hash.ne(&order) {
+                            replace_dex_swap_tx_hash(Arc::clone(&ARBITRAGES), order, hash).await;
+                        }
+                        let block_number = receipt.block_number.unwrap_or_default().as_u64();
+                        let finalised = update_dex_swap_finalised_info(
+                            Arc::clone(&ARBITRAGES),
+                            hash,
+                            SwapFinalisedInfo { block_number },
+                        )
+                        .await;
+                        if finalised {
+                            handle_trade_update(dex_service_clone.clone(), Arc::clone(&lark_clone))
+                                .await;
+                        }
+                    }
+                    TxEvent::Replaced { order, previous, replacement, kind } => {
+                        warn!(
+                            "swap {:?} pending for too long, {:?} {:?} with {:?}",
+                            order, kind, previous, replacement
+                        );
+                    }
+                    TxEvent::Dropped { order } => {
+                        let dropped = remove_dropped_dex_swap(Arc::clone(&ARBITRAGES), order).await;
+                        error!("swap {:?} dropped, remove arbitrage {:?}", order, dropped);
+                    }
+                }
+            }
+        });
+    }
+
+    {
+        // swaps finalised in blocks replaced by a reorg are pending again, on any dex: the
+        // tracker follows them until their receipts, or their logs, are found on the new chain
+        let mut reorgs = block_oracle.write().await.subscribe_reorgs();
+        let tx_tracker = Arc::clone(&tx_tracker);
+        tokio::spawn(async move {
+            while let Ok(reorg) = reorgs.recv().await {
+                warn!("reorg {:?}, rollback finalised swaps", reorg);
+                let rolled_back = rollback_dex_swap_finalised_info(
+                    Arc::clone(&ARBITRAGES),
+                    reorg.common_ancestor.map(|number| number.as_u64()),
+                )
+                .await;
+                for hash in rolled_back {
+                    tx_tracker.track(hash).await;
+                }
+            }
+        });
+    }
+
     let (tx_cex_event, rx_cex_event) = mpsc::sync_channel::<CexEvent>(1000);
     let mut map = BTreeMap::new();
     let ak = match config.cex {
@@ -184,14 +363,64 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
     let cefi_service = Arc::new(RwLock::new(cefi_service));
     {
         let mut _g = cefi_service.write().await;
-        (_g).connect_pair(config.cex, config.base_asset, config.quote_asset).await;
+        (_g).connect_pair(config.cex, config.base_asset, config.quote_asset)
+            .await
+            .expect("unable to connect cex pair");
     }
 
+    {
+        // what the cex keeps for the bot, as the listen key of binance, is released on any exit:
+        // ctrl-c, SIGTERM, or a fatal error requested with `request_exit`
+        let cefi_service = Arc::clone(&cefi_service);
+        let mut exit_code = EXIT_CODE.subscribe();
+        let mut sigterm = signal(SignalKind::terminate())?;
+        tokio::spawn(async move {
+            let code = tokio::select! {
+                ret = tokio::signal::ctrl_c() => {
+                    if let Err(e) = ret {
+                        error!("error in listen to ctrl-c {:?}", e);
+                    }
+                    exitcode::OK
+                }
+                _ = sigterm.recv() => exitcode::OK,
+                ret = exit_code.wait_for(Option::is_some) => match ret {
+                    Ok(code) => code.unwrap_or(exitcode::SOFTWARE),
+                    Err(_) => exitcode::SOFTWARE,
+                },
+            };
+            request_exit(code);
+            info!("shutdown cefi service");
+            cefi_service.write().await.shutdown().await;
+            std::process::exit(code);
+        });
+    }
+
+    // only v3 pools keep the observations a twap is read from
+    let twap_guard = match config.dex {
+        DexExchange::UniswapV3 | DexExchange::AGNI => Some((
+            config.twap_window_secs.unwrap_or(DEFAULT_TWAP_WINDOW_SECS),
+            config.max_twap_deviation_bps.unwrap_or(DEFAULT_MAX_TWAP_DEVIATION_BPS),
+        )),
+        _ => None,
+    };
+
     let (cex_spread, dex_spread): (Spread, Spread) =
         (Arc::new(RwLock::new(None)), Arc::new(RwLock::new(None)));
 
     match config.dex {
         DexExchange::UniswapV3 => {
+            // orders are routed to whichever fee tier quotes best, so watch the pools of all tiers
+            let mut pool_addresses = vec![];
+            for fee in V3_FEE_TIERS {
+                let pool = dex_service
+                    .dex_contracts
+                    .get_v3_pool(base_token_address, quote_token_address, fee)
+                    .await
+                    .unwrap();
+                if !pool.address().is_zero() {
+                    pool_addresses.push(pool.address());
+                }
+            }
             let pool = dex_service
                 .dex_contracts
                 .get_v3_pool(base_token_address, quote_token_address, V3_FEE)
@@ -218,32 +447,50 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
 
             {
                 //TODO: to be moved to dex service; subscribing onchain swap event
+                let provider_ws_swap = Arc::clone(&provider_ws);
                 tokio::spawn(async move {
                     let v3_pool_swap_filter = pool
                         .event::<SwapFilter>()
+                        .address(ValueOrArray::Array(pool_addresses))
                         .from_block(last_block)
                         .topic2(ValueOrArray::Value(H256::from(wallet_address)));
 
+                    // raw logs, as the logs of the blocks replaced by a reorg are sent again with
+                    // `removed` set
                     let mut my_swap_stream =
-                        v3_pool_swap_filter.subscribe().await.unwrap().with_meta();
+                        provider_ws_swap.subscribe_logs(&v3_pool_swap_filter.filter).await.unwrap();
                     loop {
                         let next = my_swap_stream.next().await;
                         if let Some(log) = next {
-                            let (swap_log, meta) = log.unwrap() as (SwapFilter, LogMeta);
-
+                            if log.removed.eq(&Some(true)) {
+                                // rolled back with the reorg of its block
+                                warn!("swap log {:?} removed by a reorg", log.transaction_hash);
+                                continue;
+                            }
+                            let (hash, block_number) =
+                                match (log.transaction_hash, log.block_number) {
+                                    (Some(hash), Some(number)) => (hash, number.as_u64()),
+                                    _ => continue,
+                                };
                             info!(
                                 "block: {:?}, hash: {:?}, address: {:?}, log {:?}",
-                                meta.block_number, meta.transaction_hash, meta.address, swap_log
+                                block_number,
+                                hash,
+                                log.address,
+                                SwapFilter::decode_log(&log.clone().into())
                             );
-                            let swap_info =
-                                SwapFinalisedInfo { block_number: meta.block_number.as_u64() };
-                            update_dex_swap_finalised_info(
+                            let swap_info = SwapFinalisedInfo { block_number };
+                            // the tracker may have finalised the swap from its receipt already
+                            let finalised = update_dex_swap_finalised_info(
                                 Arc::clone(&ARBITRAGES),
-                                meta.transaction_hash,
+                                hash,
                                 swap_info.clone(),
                             )
                             .await;
-                            let ret = tx.send((meta.transaction_hash, swap_info));
+                            if !finalised {
+                                continue;
+                            }
+                            let ret = tx.send((hash, swap_info));
                             match ret {
                                 Err(e) => error!("error in send swap event {:?}", e),
                                 _ => {}
@@ -261,7 +508,11 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
             }
         }
         _ => {
-            todo!()
+            // the swaps of other venues are finalised from their receipts by the tracker only
+            info!("onchain swap logs on {:?} are not subscribed", config.dex);
+            tokio::spawn(async move {
+                let _ = dex_backend.event_loop().await;
+            });
         }
     }
 
@@ -281,34 +532,14 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                             // TODO: use enum
                             if wu.wallet_type.eq("exchange") {
                                 let asset = cex_currency_to_asset(config.cex, &wu.currency);
-                                if asset.eq(&config.base_asset) {
-                                    unsafe {
-                                        if wu.balance.le(&MIN_BASE_ASSET_BALANCE_AMT) {
-                                            warn!(
-                                                "asset {:?} balance {:?} is below threshold {:?}",
-                                                asset, wu.balance, MIN_BASE_ASSET_BALANCE_AMT
-                                            );
-                                            std::process::exit(exitcode::DATAERR);
-                                        }
-                                    }
-                                }
-
-                                if asset.eq(&config.quote_asset) {
-                                    let _g = dex_spread.read().await;
-                                    if let Some(p) = *_g {
-                                        unsafe {
-                                            let min_quote_amt =
-                                                p.0.checked_mul(MIN_BASE_ASSET_BALANCE_AMT)
-                                                    .unwrap();
-                                            if wu.balance.le(&min_quote_amt) {
-                                                warn!("asset {:?} balance {:?} is below threshold {:?}", asset, wu.balance, min_quote_amt);
-                                                std::process::exit(exitcode::DATAERR);
-                                            }
-                                        }
-                                    }
-
-                                    drop(_g);
-                                }
+                                check_asset_balance(
+                                    asset,
+                                    wu.balance,
+                                    config.base_asset,
+                                    config.quote_asset,
+                                    &dex_spread,
+                                )
+                                .await;
                             }
                         }
                         CexEvent::TradeExecution(trade) => {
@@ -325,21 +556,80 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                                     .await;
                             }
                         }
+                        CexEvent::Connected(cex) => {
+                            info!("cex {:?} connected, resume trading", cex);
+                            CEX_CONNECTED.store(true, Ordering::SeqCst);
+                        }
+                        CexEvent::Disconnected(cex) => {
+                            warn!("cex {:?} disconnected, pause trading", cex);
+                            CEX_CONNECTED.store(false, Ordering::SeqCst);
+                            CEX_SPREAD_STALE.store(true, Ordering::SeqCst);
+                        }
+                        CexEvent::Fatal(cex, reason) => {
+                            error!("cex {:?} feed lost for good, exit: {}", cex, reason);
+                            request_exit(exitcode::SOFTWARE);
+                        }
                     }
                 }
             }
         });
     };
 
+    {
+        // the wallet on chain is guarded as the cex wallet is
+        let (tx_dex_event, mut rx_dex_event) = tokio::sync::mpsc::unbounded_channel::<DexEvent>();
+        let native_token = get_native_token_info(config.network).expect("no native token");
+        let mut balance_service = BalanceService::new(
+            Arc::clone(&provider_ws),
+            config.network,
+            vec![wallet_address],
+            vec![base_token.clone(), quote_token.clone(), native_token],
+            tx_dex_event,
+        );
+        tokio::spawn(async move {
+            if let Err(e) = balance_service.event_loop().await {
+                error!("error in dex balance event loop {:?}", e);
+            }
+        });
+
+        let dex_spread = Arc::clone(&dex_spread);
+        let (base_asset, quote_asset) = (config.base_asset, config.quote_asset);
+        tokio::spawn(async move {
+            // subscribing dex event
+            while let Some(dex_event) = rx_dex_event.recv().await {
+                match dex_event {
+                    DexEvent::Balance(balance) => {
+                        info!("receive dex wallet balance event {:?}", balance);
+                        check_asset_balance(
+                            balance.token.into(),
+                            balance.balance,
+                            base_asset,
+                            quote_asset,
+                            &dex_spread,
+                        )
+                        .await;
+                    }
+                }
+            }
+        });
+    }
+
     let (mut cex_bid, mut cex_ask, mut dex_bid, mut dex_ask): (
         Option<Decimal>,
         Option<Decimal>,
         Option<Decimal>,
         Option<Decimal>,
     ) = (None, None, None, None);
+    let (mut dex_bid_fee, mut dex_ask_fee) = (V3_FEE, V3_FEE);
     loop {
         if let Ok(change) = rx_market_change.recv() {
             // println!("receive market change: {:?}", change);
+            if CEX_SPREAD_STALE.swap(false, Ordering::SeqCst) {
+                // wait for a fresh book after the reconnection or the resync, whose spread may be
+                // in this change already
+                (*cex_spread.write().await) = None;
+                (cex_bid, cex_ask) = (None, None);
+            }
             if let Some(spread) = change.cex {
                 {
                     let mut _g = cex_spread.write().await;
@@ -352,6 +642,8 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                     let mut _g = dex_spread.write().await;
                     (*_g) = Some((spread.best_bid, spread.best_ask));
                     (dex_bid, dex_ask) = (Some(spread.best_bid), Some(spread.best_ask));
+                    dex_bid_fee = spread.best_bid_v3_fee.unwrap_or(V3_FEE);
+                    dex_ask_fee = spread.best_ask_v3_fee.unwrap_or(V3_FEE);
                 }
             }
 
@@ -370,27 +662,40 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                             "found a cross, cex bid {:?}, dex ask {:?}, price change {:?}",
                             cex_bid, dex_ask, change
                         );
+                        if let Some(guard) = twap_guard {
+                            let pair = (base_token_address, quote_token_address);
+                            if is_vetoed_by_twap(&dex_service, pair, dex_ask_fee, guard).await {
+                                continue;
+                            }
+                        }
                         let mut amount = config.base_asset_quote_amt;
                         amount.set_sign_negative(true);
                         let instraction = ArbitrageInstruction {
                             cex: CexInstruction {
-                                venue: CexExchange::BITFINEX,
+                                venue: config.cex,
                                 amount,
                                 base_asset: config.base_asset,
                                 quote_asset: config.quote_asset,
                             },
                             dex: DexInstruction {
                                 network: config.network,
-                                venue: DexExchange::UniswapV3,
+                                venue: config.dex,
                                 amount: config.base_asset_quote_amt,
                                 base_token: base_token.clone(),
                                 quote_token: quote_token.clone(),
                                 recipient: wallet_address,
-                                fee: V3_FEE,
+                                fee: dex_ask_fee,
+                                limit_price: Some(dex_ask),
                             },
                         };
 
-                        try_arbitrage(instraction, Arc::clone(&cefi_service), &dex_service).await;
+                        try_arbitrage(
+                            instraction,
+                            Arc::clone(&cefi_service),
+                            &dex_service,
+                            &tx_tracker,
+                        )
+                        .await;
                     }
                 }
 
@@ -402,6 +707,12 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                             "found a cross, dex bid {:?}, cex ask {:?}, price change {:?}",
                             dex_bid, cex_ask, change
                         );
+                        if let Some(guard) = twap_guard {
+                            let pair = (base_token_address, quote_token_address);
+                            if is_vetoed_by_twap(&dex_service, pair, dex_bid_fee, guard).await {
+                                continue;
+                            }
+                        }
                         let mut amount = config.base_asset_quote_amt;
                         amount.set_sign_negative(true);
                         let instraction = ArbitrageInstruction {
@@ -418,11 +729,18 @@ async fn run(config: VenusConfig) -> anyhow::Result<()> {
                                 base_token: base_token.clone(),
                                 quote_token: quote_token.clone(),
                                 recipient: wallet_address,
-                                fee: V3_FEE,
+                                fee: dex_bid_fee,
+                                limit_price: Some(dex_bid),
                             },
                         };
 
-                        try_arbitrage(instraction, Arc::clone(&cefi_service), &dex_service).await;
+                        try_arbitrage(
+                            instraction,
+                            Arc::clone(&cefi_service),
+                            &dex_service,
+                            &tx_tracker,
+                        )
+                        .await;
                     }
                 }
             }
@@ -434,7 +752,16 @@ async fn try_arbitrage<'a, M: Middleware + 'static>(
     instruction: ArbitrageInstruction,
     cefi_service_ptr: Arc<RwLock<CefiService>>,
     dex_service_ref: &DexService<M>,
+    tx_tracker: &TxTracker<M>,
 ) {
+    if EXIT_CODE.borrow().is_some() {
+        warn!("exiting, skip trade");
+        return;
+    }
+    if !CEX_CONNECTED.load(Ordering::SeqCst) {
+        warn!("cex {:?} is disconnected, skip trade for now", instruction.cex.venue);
+        return;
+    }
     let total = TOTAL_PENDING_TRADES.load(Ordering::Relaxed);
     if total > 5 {
         warn!("total pending number of trades are {:?}, skip trade for now", total);
@@ -466,6 +793,7 @@ async fn try_arbitrage<'a, M: Middleware + 'static>(
                     network: instruction.dex.network,
                     venue: instruction.dex.venue,
                     tx_hash: None,
+                    recipient: instruction.dex.recipient,
                     base_token_info: instruction.dex.base_token.clone(),
                     quote_token_info: instruction.dex.quote_token.clone(),
                     v3_fee: Some(instruction.dex.fee),
@@ -478,7 +806,7 @@ async fn try_arbitrage<'a, M: Middleware + 'static>(
 
     {
         let mut _cex = cefi_service_ptr.write().await;
-        (_cex)
+        let ret = (_cex)
             .submit_order(
                 client_order_id,
                 instruction.cex.venue,
@@ -487,32 +815,39 @@ async fn try_arbitrage<'a, M: Middleware + 'static>(
                 instruction.cex.amount,
             )
             .await;
-        info!("end send cex trade");
+        match ret {
+            Ok(()) => info!("end send cex trade"),
+            Err(e) => {
+                // no cex leg to hedge, so the dex leg is not sent either
+                error!("error in send cex trade {:?}, skip dex trade", e);
+                TOTAL_PENDING_TRADES.fetch_sub(1, Ordering::SeqCst);
+                ARBITRAGES.write().await.remove(&client_order_id);
+                return;
+            }
+        }
     }
 
-    match instruction.dex.venue {
-        DexExchange::UniswapV3 => {
-            let ret = dex_service_ref
-                .submit_order(
-                    instruction.dex.base_token,
-                    instruction.dex.quote_token,
-                    instruction.dex.amount,
-                    instruction.dex.fee,
-                    instruction.dex.recipient,
-                )
-                .await;
-            match ret {
-                Ok(hash) => {
-                    let mut _g = ARBITRAGES.write().await;
-                    info!("send dex order success {:?}", hash);
-                    _g.entry(client_order_id).and_modify(|e| {
-                        e.dex.tx_hash = Some(hash);
-                    });
-                }
-                Err(e) => error!("error in send dex order {:?}", e),
-            }
+    // the dex service routes the order to the router of its own venue
+    let ret = dex_service_ref
+        .submit_order(
+            instruction.dex.base_token,
+            instruction.dex.quote_token,
+            instruction.dex.amount,
+            instruction.dex.fee,
+            instruction.dex.recipient,
+            instruction.dex.limit_price,
+        )
+        .await;
+    match ret {
+        Ok(hash) => {
+            let mut _g = ARBITRAGES.write().await;
+            info!("send dex order success {:?}", hash);
+            _g.entry(client_order_id).and_modify(|e| {
+                e.dex.tx_hash = Some(hash);
+            });
+            tx_tracker.track(hash).await;
         }
-        _ => unimplemented!(),
+        Err(e) => error!("error in send dex order {:?}", e),
     }
     info!("end send dex trade");
 }
@@ -6,6 +6,7 @@ use config::{Config, ConfigError, File};
 use meta_address::enums::Asset;
 use meta_cefi::cefi_service::AccessKey;
 use meta_common::enums::{CexExchange, DexExchange, Network, RpcProvider};
+use meta_dex::allowance::ApprovalPolicy;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::{env, path::PathBuf, result::Result, str::FromStr};
@@ -120,6 +121,14 @@ pub struct VenusConfig {
     pub quote_asset: Asset,
     pub base_asset_quote_amt: Decimal,
     pub spread_diff_threshold: u32,
+    pub dex_slippage_bps: Option<u32>,
+    /// amount approved to the dex router when its allowance is insufficient
+    pub dex_approval_policy: Option<ApprovalPolicy>,
+    /// window of the twap the spot price of a v3 pool is checked against before a trade
+    pub twap_window_secs: Option<u32>,
+    /// max deviation (in bp) of the spot price of a v3 pool from its twap, beyond which the pool
+    /// may be manipulated and trades are vetoed
+    pub max_twap_deviation_bps: Option<u32>,
     pub log: ConfigLog,
     pub provider: ConfigProvider,
     pub account: ConfigAccount,
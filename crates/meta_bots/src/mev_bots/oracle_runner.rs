@@ -3,14 +3,15 @@ use dashmap::DashMap;
 use ethers::prelude::*;
 
 use meta_dex::{
+    cache::{sync_dex_with_cache, PoolCache},
     oracle::BlockOracle,
     pool::Pool,
-    // sync_dex,
+    sync::DexFactory,
     DexService,
 };
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::info;
+use tokio::{sync::RwLock, task::JoinHandle};
+use tracing::{error, info, warn};
 
 // Update latest block variable whenever we recieve a new block
 //
@@ -32,9 +33,8 @@ pub fn start_block_oracle(client: Arc<Provider<Ws>>, oracle: &mut Arc<RwLock<Blo
                 // lock the RwLock for write access and update the variable
                 {
                     let mut lock = next_block_clone.write().await;
-                    lock.update_block_number(block.number.unwrap());
-                    lock.update_block_timestamp(block.timestamp);
-                    lock.update_base_fee(block);
+                    // reorgs are logged by the oracle and sent to its subscribers
+                    lock.update(block);
 
                     let latest_block = &lock.latest_block;
                     let next_block = &lock.next_block;
@@ -53,54 +53,101 @@ pub fn start_block_oracle(client: Arc<Provider<Ws>>, oracle: &mut Arc<RwLock<Blo
     });
 }
 
+/// blocks between two scans of the factories for new pools
+pub const ADD_NEW_POOLS_INTERVAL_BLOCKS: u64 = 50;
+
+// Factories indexed for the pools of `dexes`
+pub fn get_factories<M: Middleware + 'static>(dexes: &[Arc<DexService<M>>]) -> Vec<DexFactory> {
+    dexes.iter().flat_map(|dex| DexFactory::from_dex(dex.network, dex.dex_exchange)).collect()
+}
+
+// Add the pools created by the factories of `dexes` to `all_pools` and `pool_cache`, every
+// `ADD_NEW_POOLS_INTERVAL_BLOCKS` blocks. The factories never synced are backfilled from their
+// creation block on the first block received
+//
+// Arguments:
+// * `all_pools`: pools to add new pools to
+// * `pool_cache`: cache the factories are scanned from the checkpoints of
+// * `dexes`: dexes whose factories are scanned
+//
+// Returns:
+// handle of the task, which stops with an error if the block stream can not be subscribed
 pub fn start_add_new_pools<M: Middleware + 'static>(
-    _client: Arc<Provider<Ws>>, //Vec<Arc<Dex<M>>>,
+    client: Arc<Provider<Ws>>,
     all_pools: &mut Arc<DashMap<Address, Pool>>,
-    _dexes: Vec<Arc<DexService<M>>>,
-) {
-    let _all_pools = all_pools.clone();
+    pool_cache: Arc<RwLock<PoolCache>>,
+    dexes: Vec<Arc<DexService<M>>>,
+) -> JoinHandle<anyhow::Result<()>> {
+    let all_pools = all_pools.clone();
+    let factories = get_factories(&dexes);
 
     tokio::spawn(async move {
-        // loop so we can reconnect if the websocket connection is lost
-        loop {
-            // let mut block_stream = if let Ok(stream) = client.subscribe_blocks().await {
-            //     stream
-            // } else {
-            //     panic!("Failed to create new block stream");
-            // };
-
-            // let mut counter = 0;
-            // let mut current_block_num = client.get_block_number().await.unwrap();
-
-            // while let Some(block) = block_stream.next().await {
-            //     counter += 1;
-
-            //     // every 50 blocks fetch and new pools
-            //     if counter == 50 {
-            //         let latest_block_number = block.number.unwrap();
-            //         let fetched_new_pools = sync_dex(
-            //             dexes.clone(),
-            //             Some(BlockNumber::Number(current_block_num)),
-            //             BlockNumber::Number(latest_block_number),
-            //         )
-            //         .await
-            //         .unwrap();
-
-            //         let fetched_pools_count = fetched_new_pools.len();
-
-            //         // turn fetched pools into hashmap
-            //         for pool in fetched_new_pools {
-            //             // Create hashmap from our vec
-            //             all_pools.insert(pool.address, pool);
-            //         }
-
-            //         counter = 0;
-            //         current_block_num = latest_block_number;
-            //         info!("added {} new pools", fetched_pools_count);
-            //     }
-            // }
+        let ret = add_new_pools(client, all_pools, pool_cache, factories).await;
+        if let Err(ref e) = ret {
+            error!("stop adding new pools, {:?}", e);
         }
-    });
+        ret
+    })
+}
+
+async fn add_new_pools(
+    client: Arc<Provider<Ws>>,
+    all_pools: Arc<DashMap<Address, Pool>>,
+    pool_cache: Arc<RwLock<PoolCache>>,
+    factories: Vec<DexFactory>,
+) -> anyhow::Result<()> {
+    // kept across reconnections, `None` until the first scan
+    let mut last_indexed_block: Option<u64> = None;
+    // loop so we can reconnect if the websocket connection is lost
+    loop {
+        let mut block_stream = client
+            .subscribe_blocks()
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to create new block stream, {:?}", e))?;
+
+        while let Some(block) = block_stream.next().await {
+            let latest_block_number = block
+                .number
+                .ok_or_else(|| anyhow::anyhow!("block {:?} without number", block.hash))?
+                .as_u64();
+
+            // every `ADD_NEW_POOLS_INTERVAL_BLOCKS` blocks fetch new pools
+            if let Some(last_indexed_block) = last_indexed_block {
+                if latest_block_number < last_indexed_block + ADD_NEW_POOLS_INTERVAL_BLOCKS {
+                    continue;
+                }
+            }
+            let mut cache = pool_cache.write().await;
+            let ret = sync_dex_with_cache(
+                client.clone(),
+                factories.clone(),
+                &mut cache,
+                latest_block_number,
+            )
+            .await;
+            let fetched_new_pools = match ret {
+                Ok(pools) => pools,
+                Err(e) => {
+                    // the factories synced before the error are checkpointed, so their pools
+                    // are only in the cache; the others are retried from their checkpoints
+                    error!("error in fetch new pools, {:?}", e);
+                    for pool in cache.pools().values() {
+                        all_pools.entry(pool.address).or_insert(*pool);
+                    }
+                    continue;
+                }
+            };
+
+            let fetched_pools_count = fetched_new_pools.len();
+            for pool in fetched_new_pools {
+                all_pools.insert(pool.address, pool);
+            }
+
+            last_indexed_block = Some(latest_block_number);
+            info!("added {} new pools", fetched_pools_count);
+        }
+        warn!("block stream of new pools closed, resubscribe");
+    }
 }
 
 // pub fn start_mega_sandwich_oracle(
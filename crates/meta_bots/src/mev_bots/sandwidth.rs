@@ -8,10 +8,14 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
-use crate::mev_bots::oracle_runner::{start_add_new_pools, start_block_oracle};
+use crate::mev_bots::oracle_runner::{get_factories, start_add_new_pools, start_block_oracle};
 use meta_common::{constants::ERC20_TRANSFER_EVENT_SIG, enums::Network};
 use meta_contracts::wrappers::Erc20Wrapper;
-use meta_dex::{prelude::*, DexService};
+use meta_dex::{
+    cache::{sync_dex_with_cache, PoolCache},
+    prelude::*,
+    DexService,
+};
 
 // mod oracles;
 // mod state;
@@ -194,6 +198,7 @@ pub struct BotSandwidth<M: Middleware + 'static> {
     latest_block_oracle: Arc<RwLock<BlockOracle>>,
     provider: Arc<Provider<Ws>>,
     all_pools: Arc<DashMap<Address, Pool>>,
+    pool_cache: Arc<RwLock<PoolCache>>,
     // sandwich_maker: Arc<SandwichMaker>,
     // bundle_sender: Arc<RwLock<BundleSender>>,
     dexes: Vec<Arc<DexService<M>>>,
@@ -204,7 +209,7 @@ impl<M: Middleware + 'static> BotSandwidth<M> {
     //
     // Arguments:
     // * `client`: websocket provider used to make calls
-    // * `pool_vec`: vector of pools that the bot will monitor
+    // * `pool_cache`: cache of the pools that the bot will monitor, synced up to the latest block
     //
     // Returns:
     // * Ok(Bot) if successful
@@ -215,14 +220,20 @@ impl<M: Middleware + 'static> BotSandwidth<M> {
         sandwich_inception_block: U64,
         weth_address: Address,
         dexes: Vec<Arc<DexService<M>>>,
-        pool_vec: Vec<Pool>,
+        mut pool_cache: PoolCache,
         provider: Arc<Provider<Ws>>,
         wallet: Arc<LocalWallet>, // dexes: Vec<Dex>,
     ) -> Result<Self> {
-        // create hashmap from our vec of pools (faster access when doing lookups)
+        // only the blocks since the last run are scanned
+        let latest_block = provider.get_block_number().await?.as_u64();
+        sync_dex_with_cache(provider.clone(), get_factories(&dexes), &mut pool_cache, latest_block)
+            .await
+            .map_err(|e| eyre::eyre!("error in sync pools, {:?}", e))?;
+
+        // create hashmap from our cache of pools (faster access when doing lookups)
         let all_pools: DashMap<Address, Pool> = DashMap::new();
-        for pool in pool_vec {
-            all_pools.insert(pool.address, pool);
+        for pool in pool_cache.pools().values() {
+            all_pools.insert(pool.address, *pool);
         }
 
         let all_pools = Arc::new(all_pools);
@@ -243,7 +254,7 @@ impl<M: Middleware + 'static> BotSandwidth<M> {
                 .await,
         );
 
-        let latest_block_oracle = BlockOracle::new(&provider.clone()).await?;
+        let latest_block_oracle = BlockOracle::new(&provider.clone(), network).await?;
         let latest_block_oracle = Arc::new(RwLock::new(latest_block_oracle));
 
         // let bundle_sender = Arc::new(RwLock::new(BundleSender::new().await));
@@ -252,6 +263,7 @@ impl<M: Middleware + 'static> BotSandwidth<M> {
             provider,
             sandwich_state,
             all_pools,
+            pool_cache: Arc::new(RwLock::new(pool_cache)),
             latest_block_oracle,
             // sandwich_maker,
             // bundle_sender,
@@ -270,7 +282,12 @@ impl<M: Middleware + 'static> BotSandwidth<M> {
     pub async fn run(&mut self) -> Result<()> {
         info!("Starting bot");
 
-        start_add_new_pools(self.provider.clone(), &mut self.all_pools, self.dexes.clone());
+        start_add_new_pools(
+            self.provider.clone(),
+            &mut self.all_pools,
+            self.pool_cache.clone(),
+            self.dexes.clone(),
+        );
         start_block_oracle(self.provider.clone(), &mut self.latest_block_oracle);
         // oracles::start_mega_sandwich_oracle(
         //     self.bundle_sender.clone(),
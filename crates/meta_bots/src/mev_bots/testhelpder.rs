@@ -5,6 +5,7 @@ use ethers::{
     utils::{Anvil, AnvilInstance},
 };
 
+use meta_common::enums::Network;
 use meta_contracts::bindings::{erc20::ERC20, uniswapv2pair::UniswapV2Pair};
 use meta_dex::prelude::{BlockInfo, Pool};
 use meta_util::ether::hash::u256_to_h256_be;
@@ -18,7 +19,7 @@ use meta_util::ether::hash::u256_to_h256_be;
 
 pub async fn get_next_block_info(prev_block_number: u64, client: &Arc<Provider<Ws>>) -> BlockInfo {
     let prev_block = client.get_block(prev_block_number).await.unwrap().unwrap();
-    BlockInfo::find_next_block_info(prev_block)
+    BlockInfo::find_next_block_info(Network::ETH, prev_block)
 }
 
 // need to return anvil instance to keep it alive (so that we can make calls)
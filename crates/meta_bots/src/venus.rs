@@ -28,6 +28,8 @@ pub struct DexTradeInfo {
     pub network: Network,
     pub venue: DexExchange,
     pub tx_hash: Option<TxHash>,
+    /// wallet receiving the swap output
+    pub recipient: Address,
     pub finalised_info: Option<SwapFinalisedInfo>,
     pub base_token_info: TokenInfo,
     pub quote_token_info: TokenInfo,
@@ -65,6 +67,8 @@ pub struct DexInstruction {
     pub quote_token: TokenInfo,
     pub fee: u32,
     pub recipient: Address,
+    /// the dex price that triggered the arbitrage
+    pub limit_price: Option<Decimal>,
 }
 
 #[derive(Debug)]
@@ -73,24 +77,82 @@ pub struct ArbitrageInstruction {
     pub dex: DexInstruction,
 }
 
-/// update the swap info when onchain transaction is finalised (success/revert)
+/// update the swap info when onchain transaction is finalised (success/revert); false if the swap
+/// is unknown or already finalised by another source, e.g. the tracker and the swap log
 pub async fn update_dex_swap_finalised_info(
     map: ArbitrageInfo,
     hash: TxHash,
     swap_info: SwapFinalisedInfo,
-) {
+) -> bool {
     let mut _g = map.write().await;
     let iter = _g.iter_mut();
 
     for (_key, val) in iter {
         if val.dex.tx_hash.eq(&Some(hash)) {
+            if val.dex.finalised_info.is_some() {
+                return false;
+            }
             info!("update {:?} with finalised info {:?}", hash, swap_info);
             (val).dex.finalised_info = Some(swap_info);
+            return true;
+        }
+    }
+    false
+}
+
+/// follow a swap to the transaction `replacement`, sent at the nonce of `previous` to speed it up
+pub async fn replace_dex_swap_tx_hash(map: ArbitrageInfo, previous: TxHash, replacement: TxHash) {
+    let mut _g = map.write().await;
+    let iter = _g.iter_mut();
+
+    for (_key, val) in iter {
+        if val.dex.tx_hash.eq(&Some(previous)) {
+            info!("replace {:?} with {:?}", previous, replacement);
+            val.dex.tx_hash = Some(replacement);
             return;
         }
     }
 }
 
+/// remove the arbitrage whose swap `hash` will never be mined; return it if found
+pub async fn remove_dropped_dex_swap(
+    map: ArbitrageInfo,
+    hash: TxHash,
+) -> Option<(CID, ArbitragePair)> {
+    let mut _g = map.write().await;
+    let cid = _g.iter().find(|(_key, val)| val.dex.tx_hash.eq(&Some(hash))).map(|(key, _)| *key)?;
+    _g.remove(&cid).map(|val| (cid, val))
+}
+
+/// reset the finalised info of swaps included in blocks replaced by a reorg; the caller must
+/// track them again until their receipts are found on the new chain
+/// # Arguments
+/// * `common_ancestor` - latest block kept by the reorg; `None` resets every finalised swap
+/// # Return
+/// the hashes of the swaps rolled back
+pub async fn rollback_dex_swap_finalised_info(
+    map: ArbitrageInfo,
+    common_ancestor: Option<u64>,
+) -> Vec<TxHash> {
+    let mut _g = map.write().await;
+    let iter = _g.iter_mut();
+
+    let mut rolled_back = vec![];
+    for (_key, val) in iter {
+        let replaced = match (&val.dex.finalised_info, common_ancestor) {
+            (Some(info), Some(ancestor)) => info.block_number > ancestor,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if replaced {
+            info!("rollback finalised info of {:?} after reorg", val.dex.tx_hash);
+            val.dex.finalised_info = None;
+            rolled_back.extend(val.dex.tx_hash);
+        }
+    }
+    rolled_back
+}
+
 /// check whether certain number of trades' status have been unknown for too long. stop process if that's so
 /// check whether an arbitrage has been successful; return the info if that's so.
 /// # Return
@@ -136,20 +198,16 @@ pub async fn notify_arbitrage_result<M: Middleware>(
     info!("start notify arbitrage result {:?}", arbitrage_info);
     {
         let mut _g = arbitrage_map.write().await;
-        _g.remove(&cid);
+        if _g.remove(&cid).is_none() {
+            // already notified by another update of the same arbitrage
+            return;
+        }
     }
 
     let dex_trade_info = arbitrage_info.dex.clone();
     let cex_trade_info = arbitrage_info.cex.clone();
     let hash = dex_trade_info.tx_hash.unwrap();
-    let parsed_tx_ret = dex_service
-        .analyze_v3_tx(
-            hash,
-            dex_trade_info.base_token_info.clone(),
-            dex_trade_info.quote_token_info.clone(),
-            dex_trade_info.v3_fee.unwrap(),
-        )
-        .await;
+    let parsed_tx_ret = dex_service.analyze_tx(hash, dex_trade_info.recipient).await;
     match parsed_tx_ret {
         Ok(parsed_tx) => {
             let mut cex_outcome = ArbitrageOutcome::default();
@@ -192,6 +250,49 @@ pub async fn notify_arbitrage_result<M: Middleware>(
             };
             lark.send_arbitrage_summary(summary).await;
         }
-        Err(e) => error!("error in analyze v3 tx {:?}", e),
+        Err(e) => error!("error in analyze tx {:?}", e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn arbitrage(hash: u64, finalised_block: Option<u64>) -> ArbitragePair {
+        ArbitragePair {
+            dex: DexTradeInfo {
+                tx_hash: Some(TxHash::from_low_u64_be(hash)),
+                finalised_info: finalised_block
+                    .map(|block_number| SwapFinalisedInfo { block_number }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rollback_dex_swap_finalised_info() {
+        let map: ArbitrageInfo = Arc::new(RwLock::new(BTreeMap::from([
+            (1, arbitrage(1, Some(100))),
+            (2, arbitrage(2, Some(102))),
+            (3, arbitrage(3, None)),
+        ])));
+        let hash = TxHash::from_low_u64_be(2);
+
+        // only the swap mined after the common ancestor is rolled back
+        let rolled_back = rollback_dex_swap_finalised_info(Arc::clone(&map), Some(101)).await;
+        assert_eq!(rolled_back, vec![hash]);
+        assert!(map.read().await[&1].dex.finalised_info.is_some());
+        assert!(map.read().await[&2].dex.finalised_info.is_none());
+
+        // pending until a receipt or a log of the new chain finalises it, once
+        let info = SwapFinalisedInfo { block_number: 103 };
+        assert!(update_dex_swap_finalised_info(Arc::clone(&map), hash, info.clone()).await);
+        assert!(!update_dex_swap_finalised_info(Arc::clone(&map), hash, info).await);
+        let finalised = map.read().await[&2].dex.finalised_info.clone();
+        assert_eq!(finalised.map(|info| info.block_number), Some(103));
+
+        let rolled_back = rollback_dex_swap_finalised_info(Arc::clone(&map), None).await;
+        assert_eq!(rolled_back.len(), 2);
     }
 }
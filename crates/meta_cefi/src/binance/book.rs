@@ -0,0 +1,248 @@
+use super::{
+    market::Market,
+    model::{DiffOrderBookEvent, OrderBook as DepthSnapshot},
+    websockets::{BinanceEventHandler, BinanceWebsocketEvent},
+};
+use crate::{
+    bitfinex::book::TradingOrderBookLevel,
+    cefi_service::{KeyedOrderBook, OrderBook},
+};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{mpsc::UnboundedReceiver, RwLock};
+use tracing::{info, warn};
+
+/// number of levels of the rest depth snapshot a local book starts from
+pub const DEFAULT_DEPTH_SNAPSHOT_LIMIT: u64 = 1000;
+
+/// max number of diff events kept while a depth snapshot is pending
+pub const MAX_BUFFERED_DEPTH_UPDATES: usize = 1000;
+
+/// delay before a failed depth snapshot is fetched again
+pub const DEPTH_SNAPSHOT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthUpdate {
+    /// applied to the book
+    Applied,
+    /// kept until the pending snapshot arrives
+    Buffered,
+    /// older than the book, dropped
+    Stale,
+    /// the book is out of sync, a new snapshot must be fetched
+    Resync,
+}
+
+/// # Description
+/// L2 book of a symbol maintained from a rest depth snapshot and the diff depth stream
+/// following the rules of binance:
+/// * diff events are buffered until the snapshot arrives
+/// * events with `u` <= `lastUpdateId` of the book are dropped
+/// * the next event applied must have `U` <= `lastUpdateId` + 1 <= `u`, otherwise updates were
+///   missed and the book resyncs from a new snapshot
+/// * a level with a zero quantity is removed
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    pub order_book: OrderBook,
+    last_update_id: Option<u64>, // none until a snapshot is applied
+    buffer: Vec<DiffOrderBookEvent>,
+    snapshot_pending: bool,
+    pub resyncs: u64, // number of gaps found in the stream
+}
+
+impl Default for LocalOrderBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalOrderBook {
+    pub fn new() -> Self {
+        Self {
+            order_book: OrderBook { bids: KeyedOrderBook::new(), asks: KeyedOrderBook::new() },
+            last_update_id: None,
+            buffer: Vec::new(),
+            snapshot_pending: false,
+            resyncs: 0,
+        }
+    }
+
+    /// drop the book and what is buffered, the next diff event requests a snapshot
+    pub fn reset(&mut self) {
+        self.order_book.bids.clear();
+        self.order_book.asks.clear();
+        self.last_update_id = None;
+        self.buffer.clear();
+        self.snapshot_pending = false;
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.last_update_id.is_some()
+    }
+
+    pub fn get_last_update_id(&self) -> Option<u64> {
+        self.last_update_id
+    }
+
+    pub fn on_diff(&mut self, event: DiffOrderBookEvent) -> DepthUpdate {
+        let Some(last_update_id) = self.last_update_id else {
+            return self.buffer(event);
+        };
+        if event.final_update_id <= last_update_id {
+            return DepthUpdate::Stale;
+        }
+        if event.first_update_id > last_update_id + 1 {
+            warn!(
+                "binance {} depth gap, book at {} received {}..{}",
+                event.symbol, last_update_id, event.first_update_id, event.final_update_id
+            );
+            self.resyncs += 1;
+            self.last_update_id = None;
+            self.snapshot_pending = false;
+            return self.buffer(event);
+        }
+        apply_levels(&mut self.order_book.bids, event.bids.iter().map(|l| (l.price, l.qty)));
+        apply_levels(&mut self.order_book.asks, event.asks.iter().map(|l| (l.price, l.qty)));
+        self.last_update_id = Some(event.final_update_id);
+        DepthUpdate::Applied
+    }
+
+    /// replace the book by `snapshot` and apply the buffered events following it
+    pub fn on_snapshot(&mut self, snapshot: DepthSnapshot) -> DepthUpdate {
+        info!("binance depth snapshot at {}", snapshot.last_update_id);
+        self.snapshot_pending = false;
+        self.order_book.bids = to_keyed_order_book(snapshot.bids.iter().map(|l| (l.price, l.qty)));
+        self.order_book.asks = to_keyed_order_book(snapshot.asks.iter().map(|l| (l.price, l.qty)));
+        self.last_update_id = Some(snapshot.last_update_id);
+
+        let mut ret = DepthUpdate::Applied;
+        for event in std::mem::take(&mut self.buffer) {
+            if !self.is_synced() {
+                // a gap in the buffer, keep what follows it for the next snapshot
+                self.buffer.push(event);
+                continue;
+            }
+            if self.on_diff(event) == DepthUpdate::Resync {
+                ret = DepthUpdate::Resync;
+            }
+        }
+        ret
+    }
+
+    fn buffer(&mut self, event: DiffOrderBookEvent) -> DepthUpdate {
+        if self.buffer.len() >= MAX_BUFFERED_DEPTH_UPDATES {
+            self.buffer.remove(0);
+        }
+        self.buffer.push(event);
+        if self.snapshot_pending {
+            DepthUpdate::Buffered
+        } else {
+            self.snapshot_pending = true;
+            DepthUpdate::Resync
+        }
+    }
+}
+
+fn to_level(price: f64, qty: f64) -> Option<TradingOrderBookLevel> {
+    Some(TradingOrderBookLevel {
+        price: Decimal::from_f64(price)?,
+        count: 1,
+        amount: Decimal::from_f64(qty)?,
+    })
+}
+
+fn to_keyed_order_book(levels: impl Iterator<Item = (f64, f64)>) -> KeyedOrderBook {
+    levels
+        .filter(|(_, qty)| *qty > 0f64)
+        .filter_map(|(price, qty)| to_level(price, qty))
+        .map(|level| (level.price, level))
+        .collect()
+}
+
+fn apply_levels(book: &mut KeyedOrderBook, levels: impl Iterator<Item = (f64, f64)>) {
+    for level in levels.filter_map(|(price, qty)| to_level(price, qty)) {
+        if level.amount.is_zero() {
+            book.remove(&level.price);
+        } else {
+            book.insert(level.price, level);
+        }
+    }
+}
+
+/// # Description
+/// Fetches a depth snapshot of `symbol` each time the handler requests one and passes it to the
+/// handler as an `OrderBook` event. Runs until the handler drops its request sender
+pub async fn run_depth_snapshots(
+    market: Market,
+    symbol: String,
+    mut rx_snapshot_request: UnboundedReceiver<()>,
+    handler: Arc<RwLock<Box<dyn BinanceEventHandler + Send + Sync>>>,
+) {
+    while rx_snapshot_request.recv().await.is_some() {
+        // requests queued meanwhile are served by the same snapshot
+        while rx_snapshot_request.try_recv().is_ok() {}
+        loop {
+            let (market_ref, symbol_ref) = (market.clone(), symbol.clone());
+            let ret = tokio::task::spawn_blocking(move || {
+                market_ref
+                    .get_custom_depth(symbol_ref, DEFAULT_DEPTH_SNAPSHOT_LIMIT)
+                    .map_err(|e| anyhow::anyhow!("{}", e))
+            })
+            .await;
+            match ret {
+                Ok(Ok(snapshot)) => {
+                    let mut _g = handler.write().await;
+                    _g.on_data_event(BinanceWebsocketEvent::OrderBook(snapshot));
+                    break;
+                }
+                Ok(Err(e)) => warn!("error in get binance {} depth snapshot {:?}", symbol, e),
+                Err(e) => warn!("error in get binance {} depth snapshot {:?}", symbol, e),
+            }
+            tokio::time::sleep(DEPTH_SNAPSHOT_RETRY_DELAY).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{binance::websockets::Events, util::to_decimal};
+
+    fn get_diff(first_update_id: u64, final_update_id: u64, bid: (f64, f64)) -> DiffOrderBookEvent {
+        let data_str = format!(
+            r#"{{"e":"depthUpdate","E":1,"s":"ARBUSDT","U":{},"u":{},"b":[["{}","{}"]],"a":[]}}"#,
+            first_update_id, final_update_id, bid.0, bid.1
+        );
+        match serde_json::from_str::<Events>(&data_str).unwrap() {
+            Events::DiffOrderBook(event) => event,
+            _ => panic!("depth update expected"),
+        }
+    }
+
+    #[test]
+    fn test_local_order_book() {
+        let mut book = LocalOrderBook::new();
+        assert_eq!(book.on_diff(get_diff(99, 101, (1.0, 1.0))), DepthUpdate::Resync);
+        assert_eq!(book.on_diff(get_diff(102, 103, (1.1, 2.0))), DepthUpdate::Buffered);
+        assert!(!book.is_synced());
+
+        let snapshot: DepthSnapshot = serde_json::from_str(
+            r#"{"lastUpdateId":101,"bids":[["1.0","3.0"],["0.9","1.0"]],"asks":[["1.2","1.0"]]}"#,
+        )
+        .unwrap();
+        assert_eq!(book.on_snapshot(snapshot), DepthUpdate::Applied);
+        // the first buffered event is older than the snapshot
+        assert_eq!(book.get_last_update_id(), Some(103));
+        let (best_bid, level) = book.order_book.bids.last_key_value().unwrap();
+        assert_eq!((*best_bid, level.amount), (to_decimal(1.1), to_decimal(2.0)));
+
+        assert_eq!(book.on_diff(get_diff(100, 103, (1.3, 1.0))), DepthUpdate::Stale);
+        assert_eq!(book.on_diff(get_diff(104, 104, (1.1, 0.0))), DepthUpdate::Applied);
+        assert_eq!(book.order_book.bids.len(), 2);
+
+        // updates 105 to 106 are missed
+        assert_eq!(book.on_diff(get_diff(107, 108, (1.0, 0.0))), DepthUpdate::Resync);
+        assert!(!book.is_synced());
+        assert_eq!(book.resyncs, 1);
+    }
+}
@@ -0,0 +1,271 @@
+use super::{
+    account::Account,
+    api::Binance,
+    book::run_depth_snapshots,
+    handler::BinanceEventHandlerImpl,
+    http::Credentials,
+    hyper::BinanceHttpClient,
+    listen_key::{run_listen_key_keepalive, ListenKeyRequest},
+    market::Market,
+    model::Order,
+    util::get_binance_symbol,
+    websockets_tokio::BinanceWebSocketClient,
+};
+use crate::{
+    cefi_service::{get_pair, AccessKey, OrderBook},
+    cex_client::CexClient,
+    get_cex_pair,
+    model::{CexBalance, CexEvent, CexOrder},
+};
+use async_trait::async_trait;
+use meta_address::enums::Asset;
+use meta_common::{enums::CexExchange, models::MarcketChange};
+use meta_util::decimal::decimal_from_str;
+use rust_decimal::Decimal;
+use std::{
+    collections::BTreeMap,
+    sync::{mpsc::SyncSender, Arc},
+};
+use tokio::{
+    sync::{
+        mpsc::{unbounded_channel, UnboundedSender},
+        RwLock,
+    },
+    task::JoinHandle,
+};
+use tracing::error;
+
+pub struct BinanceCexClient {
+    access_key: Option<AccessKey>,
+    sender_market_change: Option<SyncSender<MarcketChange>>,
+    sender_cex_event: Option<SyncSender<CexEvent>>,
+    sockets: BTreeMap<String, BinanceWebSocketClient>, // (pair, socket)
+    account: Account,
+    user_data_sockets: Arc<RwLock<Vec<BinanceWebSocketClient>>>, // subscribing the listen key
+    listen_key: Option<(UnboundedSender<ListenKeyRequest>, JoinHandle<()>)>, // keepalive task
+}
+
+impl BinanceCexClient {
+    pub fn new(
+        access_key: Option<AccessKey>,
+        sender_market_change: Option<SyncSender<MarcketChange>>,
+        sender_cex_event: Option<SyncSender<CexEvent>>,
+    ) -> Self {
+        let account = match access_key {
+            Some(ref ak) => {
+                Binance::new(Some(ak.api_key.to_string()), Some(ak.api_secret.to_string()))
+            }
+            None => Binance::new(None, None),
+        };
+        Self {
+            access_key,
+            sender_market_change,
+            sender_cex_event,
+            sockets: BTreeMap::new(),
+            account,
+            user_data_sockets: Arc::new(RwLock::new(Vec::new())),
+            listen_key: None,
+        }
+    }
+
+    fn get_socket(
+        &mut self,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<&mut BinanceWebSocketClient> {
+        self.sockets
+            .get_mut(&get_pair(base, quote))
+            .ok_or_else(|| anyhow::anyhow!("binance pair {}_{} not connected", base, quote))
+    }
+}
+
+#[async_trait]
+impl CexClient for BinanceCexClient {
+    fn venue(&self) -> CexExchange {
+        CexExchange::BINANCE
+    }
+
+    async fn connect(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()> {
+        let pair = get_pair(base, quote);
+        if self.sockets.contains_key(&pair) {
+            return Ok(());
+        }
+        let (tx_snapshot_request, rx_snapshot_request) = unbounded_channel::<()>();
+        // the listen key is account-wide: the sockets of all pairs share its keepalive
+        let (tx_listen_key, rx_listen_key) = match self.listen_key {
+            Some((ref tx, _)) => (tx.clone(), None),
+            None => {
+                let (tx, rx) = unbounded_channel::<ListenKeyRequest>();
+                (tx, Some(rx))
+            }
+        };
+        let event_handler = BinanceEventHandlerImpl::new(
+            self.sender_cex_event.clone(),
+            self.sender_market_change.clone(),
+        )
+        .with_depth_book(tx_snapshot_request)
+        .with_listen_key(tx_listen_key.clone());
+        // the user data stream of the credentials is subscribed on creation
+        let (ws_client, mut socket_backend) =
+            BinanceWebSocketClient::new(self.access_key.clone(), Box::new(event_handler)).await;
+        {
+            tokio::spawn(async move {
+                let _ = socket_backend.event_loop().await;
+            });
+        }
+        if let Some(ref handler) = ws_client.event_handler {
+            let market: Market = Binance::new(None, None);
+            tokio::spawn(run_depth_snapshots(
+                market,
+                get_binance_symbol(base, quote),
+                rx_snapshot_request,
+                handler.clone(),
+            ));
+        }
+        if let (Some(ak), Some(listen_key)) = (&self.access_key, &ws_client.listen_key) {
+            self.user_data_sockets.write().await.push(ws_client.clone());
+            if let Some(rx_listen_key) = rx_listen_key {
+                let credentials = Credentials::from_hmac(ak.api_key.clone(), ak.api_secret.clone());
+                let client = BinanceHttpClient::default().credentials(credentials);
+                let keepalive = tokio::spawn(run_listen_key_keepalive(
+                    client,
+                    Arc::clone(&self.user_data_sockets),
+                    listen_key.clone(),
+                    rx_listen_key,
+                ));
+                self.listen_key = Some((tx_listen_key, keepalive));
+            }
+        }
+        self.sockets.insert(pair, ws_client);
+        Ok(())
+    }
+
+    async fn subscribe_books(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()> {
+        let symbol = get_binance_symbol(base, quote);
+        let socket = self.get_socket(base, quote)?;
+        socket.subscribe_books(symbol.as_str()).await;
+        socket.subscribe_diff_depth(symbol).await;
+        Ok(())
+    }
+
+    async fn submit_order(
+        &mut self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+        amount: Decimal,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(self.access_key.is_some(), "no binance access key to submit orders");
+        let symbol = get_cex_pair(CexExchange::BINANCE, base, quote);
+        self.get_socket(base, quote)?.submit_order(client_order_id, symbol, amount).await;
+        Ok(())
+    }
+
+    async fn cancel_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<()> {
+        let account = self.account.clone();
+        let symbol = get_cex_pair(CexExchange::BINANCE, base, quote);
+        tokio::task::spawn_blocking(move || {
+            account
+                .cancel_order_with_client_id(symbol, client_order_id.to_string())
+                .map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await??;
+        Ok(())
+    }
+
+    async fn get_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<CexOrder> {
+        let account = self.account.clone();
+        let symbol = get_cex_pair(CexExchange::BINANCE, base, quote);
+        let order = tokio::task::spawn_blocking(move || {
+            account
+                .order_status(symbol, &client_order_id.to_string())
+                .map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await??;
+        to_cex_order(order)
+    }
+
+    async fn get_balances(&self) -> anyhow::Result<Vec<CexBalance>> {
+        let account = self.account.clone();
+        let information = tokio::task::spawn_blocking(move || {
+            account.get_account().map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await??;
+        Ok(information
+            .balances
+            .into_iter()
+            .map(|b| {
+                let available = decimal_from_str(&b.free);
+                CexBalance {
+                    currency: b.asset,
+                    total: available + decimal_from_str(&b.locked),
+                    available,
+                }
+            })
+            .collect())
+    }
+
+    async fn get_order_book(&self, base: Asset, quote: Asset) -> Option<OrderBook> {
+        let socket = self.sockets.get(&get_pair(base, quote))?;
+        let handler = socket.event_handler.as_ref()?.read().await;
+        handler.as_any().downcast_ref::<BinanceEventHandlerImpl>()?.get_order_book().cloned()
+    }
+
+    /// close the listen key of the user data streams
+    async fn shutdown(&mut self) {
+        if let Some((tx, keepalive)) = self.listen_key.take() {
+            if let Err(e) = tx.send(ListenKeyRequest::Close) {
+                error!("error in request binance listen key close {:?}", e);
+            }
+            if let Err(e) = keepalive.await {
+                error!("error in binance listen key keepalive {:?}", e);
+            }
+        }
+    }
+}
+
+/// binance quantities are unsigned, the side gives the sign
+pub fn to_cex_order(order: Order) -> anyhow::Result<CexOrder> {
+    let sign = if order.side.eq("SELL") { Decimal::NEGATIVE_ONE } else { Decimal::ONE };
+    let exec_qty = decimal_from_str(&order.executed_qty);
+    let exec_price = if exec_qty.is_zero() {
+        None
+    } else {
+        Some(decimal_from_str(&order.cummulative_quote_qty) / exec_qty)
+    };
+    Ok(CexOrder {
+        client_order_id: order.client_order_id.parse::<u128>()?,
+        order_id: order.order_id,
+        symbol: order.symbol,
+        amount: decimal_from_str(&order.orig_qty) * sign,
+        exec_amount: exec_qty * sign,
+        exec_price,
+        status: order.status,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::to_decimal;
+
+    #[test]
+    fn test_to_cex_order() {
+        let data_str = r#"{"symbol":"ARBUSDT","orderId":1,"orderListId":-1,"clientOrderId":"1693068623334","price":"0.00000000","origQty":"10.00000000","executedQty":"4.00000000","cummulativeQuoteQty":"4.20000000","status":"PARTIALLY_FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL","stopPrice":"0.00000000","icebergQty":"0.00000000","time":1693068623334,"updateTime":1693068623334,"isWorking":true,"origQuoteOrderQty":"0.00000000"}"#;
+        let order = to_cex_order(serde_json::from_str::<Order>(data_str).unwrap()).unwrap();
+        assert_eq!(order.client_order_id, 1693068623334);
+        assert_eq!(order.amount, to_decimal(-10.0));
+        assert_eq!(order.exec_amount, to_decimal(-4.0));
+        assert_eq!(order.exec_price, Some(to_decimal(1.05)));
+    }
+}
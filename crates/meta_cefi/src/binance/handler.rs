@@ -1,11 +1,23 @@
-use meta_common::models::{CurrentSpread, MarcketChange};
+use meta_common::{
+    enums::CexExchange,
+    models::{CurrentSpread, MarcketChange},
+};
 use meta_util::decimal::decimal_from_str;
 
-use crate::model::{CexEvent, TradeExecutionInfo};
+use crate::{
+    bitfinex::book::TradingOrderBookLevel,
+    cefi_service::{KeyedOrderBook, OrderBook},
+    model::{CexEvent, TradeExecutionInfo},
+};
 
-use super::websockets::{BinanceEventHandler, BinanceWebsocketEvent};
+use super::{
+    book::{DepthUpdate, LocalOrderBook},
+    listen_key::ListenKeyRequest,
+    websockets::{BinanceEventHandler, BinanceWebsocketEvent},
+};
 use std::sync::mpsc::SyncSender;
-use tracing::warn;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info, warn};
 
 unsafe impl Send for BinanceEventHandlerImpl {}
 unsafe impl Sync for BinanceEventHandlerImpl {}
@@ -14,6 +26,10 @@ unsafe impl Sync for BinanceEventHandlerImpl {}
 pub struct BinanceEventHandlerImpl {
     sender_market_change: Option<SyncSender<MarcketChange>>,
     sender_cex_event: Option<SyncSender<CexEvent>>,
+    pub order_book: Option<OrderBook>, // top of book of the book ticker
+    pub depth_book: LocalOrderBook,
+    sender_snapshot_request: Option<UnboundedSender<()>>,
+    sender_listen_key: Option<UnboundedSender<ListenKeyRequest>>,
 }
 
 impl BinanceEventHandlerImpl {
@@ -21,22 +37,106 @@ impl BinanceEventHandlerImpl {
         sender_cex_event: Option<SyncSender<CexEvent>>,
         sender_market_change: Option<SyncSender<MarcketChange>>,
     ) -> Self {
-        Self { sender_cex_event, sender_market_change }
+        Self {
+            sender_cex_event,
+            sender_market_change,
+            order_book: None,
+            depth_book: LocalOrderBook::new(),
+            sender_snapshot_request: None,
+            sender_listen_key: None,
+        }
+    }
+
+    /// maintain the depth book from the diff depth stream, requesting its snapshots through
+    /// `sender_snapshot_request`, see `run_depth_snapshots`
+    pub fn with_depth_book(mut self, sender_snapshot_request: UnboundedSender<()>) -> Self {
+        self.sender_snapshot_request = Some(sender_snapshot_request);
+        self
+    }
+
+    /// report the expiry of the listen key to `sender_listen_key`, see `run_listen_key_keepalive`
+    pub fn with_listen_key(mut self, sender_listen_key: UnboundedSender<ListenKeyRequest>) -> Self {
+        self.sender_listen_key = Some(sender_listen_key);
+        self
+    }
+
+    /// the depth book once in sync, the top of book before
+    pub fn get_order_book(&self) -> Option<&OrderBook> {
+        if self.depth_book.is_synced() {
+            Some(&self.depth_book.order_book)
+        } else {
+            self.order_book.as_ref()
+        }
+    }
+
+    fn send_cex_event(&self, event: CexEvent) {
+        match self.sender_cex_event {
+            Some(ref tx) => {
+                if let Err(e) = tx.send(event) {
+                    error!("error in send binance cex event {:?}", e);
+                }
+            }
+            None => warn!("no binance cex event sender"),
+        }
+    }
+
+    fn on_depth_update(&mut self, update: DepthUpdate) {
+        if update != DepthUpdate::Resync {
+            return;
+        }
+        match self.sender_snapshot_request {
+            Some(ref tx) => {
+                if let Err(e) = tx.send(()) {
+                    error!("error in request binance depth snapshot {:?}", e);
+                }
+            }
+            None => warn!("no binance depth snapshot request sender"),
+        }
     }
 }
+
+/// single level book side of a book ticker
+fn get_top_of_book(price: &str, qty: &str) -> KeyedOrderBook {
+    let level = TradingOrderBookLevel {
+        price: decimal_from_str(price),
+        count: 1,
+        amount: decimal_from_str(qty),
+    };
+    KeyedOrderBook::from([(level.price, level)])
+}
+
 impl BinanceEventHandler for BinanceEventHandlerImpl {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
+    fn on_disconnected(&mut self) {
+        warn!("binance sockets disconnected, drop the order books");
+        self.order_book = None;
+        self.depth_book.reset();
+        self.send_cex_event(CexEvent::Disconnected(CexExchange::BINANCE));
+    }
+
+    fn on_reconnected(&mut self) {
+        info!("binance sockets reconnected");
+        self.send_cex_event(CexEvent::Connected(CexExchange::BINANCE));
+    }
+
     fn on_data_event(&mut self, event: BinanceWebsocketEvent) {
         match event {
             BinanceWebsocketEvent::BookTicker(ticker) => {
+                self.order_book = Some(OrderBook {
+                    bids: get_top_of_book(&ticker.best_bid, &ticker.best_bid_qty),
+                    asks: get_top_of_book(&ticker.best_ask, &ticker.best_ask_qty),
+                });
                 if let Some(ref tx) = self.sender_market_change {
                     let ret = tx.send(MarcketChange {
                         cex: Some(CurrentSpread {
                             best_ask: decimal_from_str(&ticker.best_ask),
                             best_bid: decimal_from_str(&ticker.best_bid),
+                            best_bid_v3_fee: None,
+                            best_ask_v3_fee: None,
+                            depth: None,
                         }),
                         dex: None,
                     });
@@ -62,6 +162,25 @@ impl BinanceEventHandler for BinanceEventHandlerImpl {
                     }
                 }
             }
+            BinanceWebsocketEvent::DiffOrderBook(event) => {
+                let update = self.depth_book.on_diff(event);
+                self.on_depth_update(update);
+            }
+            BinanceWebsocketEvent::OrderBook(snapshot) => {
+                let update = self.depth_book.on_snapshot(snapshot);
+                self.on_depth_update(update);
+            }
+            BinanceWebsocketEvent::UserDataStreamExpired(event) => {
+                warn!("binance user data stream expired at {}", event.event_time);
+                match self.sender_listen_key {
+                    Some(ref tx) => {
+                        if let Err(e) = tx.send(ListenKeyRequest::Rotate) {
+                            error!("error in request binance listen key rotation {:?}", e);
+                        }
+                    }
+                    None => warn!("no binance listen key request sender"),
+                }
+            }
             _ => {
                 warn!("got un handled binance event: {:?}", event);
             }
@@ -0,0 +1,128 @@
+use super::{
+    http::request::Request,
+    hyper::BinanceHttpClient,
+    stream::user_data::{self, ListenKeyResult, UserDataStream},
+    websockets_tokio::BinanceWebSocketClient,
+};
+use crate::reconnect::Backoff;
+use hyper::client::HttpConnector;
+use hyper_tls::HttpsConnector;
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    sync::{mpsc::UnboundedReceiver, RwLock},
+    time::Instant,
+};
+use tracing::{error, info, warn};
+
+/// a listen key expires 60 minutes after its creation or last renewal
+pub const LISTEN_KEY_RENEW_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// type of the event of the user data stream sent when its listen key expired
+pub static LISTEN_KEY_EXPIRED: &str = "listenKeyExpired";
+
+pub type HttpClient = BinanceHttpClient<HttpsConnector<HttpConnector>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListenKeyRequest {
+    /// the key expired, a new one must be created and subscribed
+    Rotate,
+    /// close the key and stop
+    Close,
+}
+
+async fn send<R: Into<Request>>(client: &HttpClient, request: R) -> anyhow::Result<String> {
+    let response = client.send(request).await.map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    response.into_body_str().await.map_err(|e| anyhow::anyhow!("{:?}", e))
+}
+
+/// a listen key of the credentials of `client`, the active one if there is one
+pub async fn create_listen_key(client: &HttpClient) -> anyhow::Result<String> {
+    let data = send(client, user_data::new_listen_key()).await?;
+    Ok(serde_json::from_str::<ListenKeyResult>(&data)?.listen_key)
+}
+
+/// subscribe the user data stream of a new listen key in place of the one of `listen_key` on
+/// every socket of `ws_clients`, retried until a key is created
+async fn rotate_listen_key(
+    client: &HttpClient,
+    ws_clients: &RwLock<Vec<BinanceWebSocketClient>>,
+    listen_key: &mut String,
+) {
+    let mut backoff = Backoff::default();
+    let new_listen_key = loop {
+        match create_listen_key(client).await {
+            Ok(key) => break key,
+            Err(e) => error!("error in create binance listen key {:?}", e),
+        }
+        tokio::time::sleep(backoff.next_delay()).await;
+    };
+    for ws_client in ws_clients.write().await.iter_mut() {
+        if new_listen_key != *listen_key {
+            ws_client.unsubscribe(vec![&UserDataStream::new(listen_key).into()]).await;
+        }
+        // the stream of an expired key may be gone even if binance gives the same key back
+        ws_client.subscribe(vec![&UserDataStream::new(&new_listen_key).into()]).await;
+    }
+    info!("binance user data stream rotated");
+    *listen_key = new_listen_key;
+}
+
+/// # Description
+/// Keeps the user data stream of one set of credentials alive on all the sockets of
+/// `ws_clients` subscribing it: the key is account-wide, so a single keepalive renews it every
+/// `LISTEN_KEY_RENEW_INTERVAL`, and rotates it when the renewal fails or a handler reports it
+/// expired. The key is closed on `ListenKeyRequest::Close` or once every request sender is
+/// dropped
+pub async fn run_listen_key_keepalive(
+    client: HttpClient,
+    ws_clients: Arc<RwLock<Vec<BinanceWebSocketClient>>>,
+    mut listen_key: String,
+    mut rx_request: UnboundedReceiver<ListenKeyRequest>,
+) {
+    let mut renew = tokio::time::interval_at(
+        Instant::now() + LISTEN_KEY_RENEW_INTERVAL,
+        LISTEN_KEY_RENEW_INTERVAL,
+    );
+    loop {
+        tokio::select! {
+            _ = renew.tick() => {
+                match send(&client, user_data::renew_listen_key(&listen_key)).await {
+                    Ok(_) => info!("binance listen key renewed"),
+                    Err(e) => {
+                        warn!("error in renew binance listen key {:?}", e);
+                        rotate_listen_key(&client, &ws_clients, &mut listen_key).await;
+                    }
+                }
+            }
+            request = rx_request.recv() => match request {
+                Some(ListenKeyRequest::Rotate) => {
+                    warn!("binance listen key expired");
+                    rotate_listen_key(&client, &ws_clients, &mut listen_key).await;
+                    renew.reset();
+                }
+                Some(ListenKeyRequest::Close) | None => {
+                    match send(&client, user_data::close_listen_key(&listen_key)).await {
+                        Ok(_) => info!("binance listen key closed"),
+                        Err(e) => error!("error in close binance listen key {:?}", e),
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::model::UserDataStreamExpiredEvent;
+
+    #[test]
+    fn test_user_data_stream_expired_event() {
+        let data_str = r#"{"e":"listenKeyExpired","E":1576653824250,"listenKey":"OfYGbUzi3PraNagEkdKuFwUHn48brFsItTdsuiIXrucEvD0rhRXZ7I6URWfE8YE8"}"#;
+        let value: serde_json::Value = serde_json::from_str(data_str).unwrap();
+        assert_eq!(value.get("e").and_then(|e| e.as_str()), Some(LISTEN_KEY_EXPIRED));
+        let event = serde_json::from_value::<UserDataStreamExpiredEvent>(value).unwrap();
+        assert_eq!(event.event_time, 1576653824250);
+    }
+}
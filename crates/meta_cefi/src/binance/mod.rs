@@ -2,6 +2,8 @@
 
 pub mod account;
 pub mod api;
+pub mod book;
+pub mod cex_client;
 pub mod client;
 pub mod config;
 pub mod constants;
@@ -10,6 +12,7 @@ pub mod general;
 pub mod handler;
 pub mod http;
 pub mod hyper;
+pub mod listen_key;
 pub mod market;
 pub mod model;
 pub mod stream;
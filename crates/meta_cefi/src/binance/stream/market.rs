@@ -40,3 +40,27 @@ impl From<BookTickerStream> for Stream {
         }
     }
 }
+
+/// Diff. Depth Stream
+///
+/// Order book price and quantity depth updates used to manage a local order book.
+///
+/// Update Speed: 100ms.
+///
+/// [API Documentation](https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream)
+pub struct DiffDepthStream {
+    symbol: String,
+}
+
+impl DiffDepthStream {
+    pub fn from_symbol(symbol: &str) -> Self {
+        Self { symbol: symbol.to_lowercase() }
+    }
+}
+
+impl From<DiffDepthStream> for Stream {
+    /// Returns stream name as `<symbol>@depth@100ms`
+    fn from(stream: DiffDepthStream) -> Stream {
+        Stream::new(&format!("{}@depth@100ms", stream.symbol))
+    }
+}
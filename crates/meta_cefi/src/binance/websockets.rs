@@ -7,7 +7,7 @@ use crate::{
         model::{
             AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent,
             DayTickerEvent, DepthOrderBookEvent, DiffOrderBookEvent, KlineEvent, OrderBook,
-            OrderTradeEvent, TradeEvent,
+            OrderTradeEvent, TradeEvent, UserDataStreamExpiredEvent,
         },
         trade::{self, order::Side},
         util::sign,
@@ -39,6 +39,10 @@ unsafe impl Sync for BinanceSocketBackhand {}
 
 pub trait BinanceEventHandler {
     fn on_data_event(&mut self, event: BinanceWebsocketEvent);
+    /// the sockets are lost, the state built from their streams is stale until `on_reconnected`
+    fn on_disconnected(&mut self);
+    /// the sockets are back, subscribed again
+    fn on_reconnected(&mut self);
     fn as_any(&self) -> &dyn std::any::Any;
 }
 
@@ -76,6 +80,7 @@ pub enum BinanceWebsocketEvent {
     DiffOrderBook(DiffOrderBookEvent),
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
+    UserDataStreamExpired(UserDataStreamExpiredEvent),
 }
 
 pub struct BinanceWebSockets {
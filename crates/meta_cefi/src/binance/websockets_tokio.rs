@@ -9,6 +9,7 @@ use crate::{
         util::sign,
     },
     cefi_service::AccessKey,
+    reconnect::{connect_with_backoff, Backoff, PING_INTERVAL, STALE_FEED_TIMEOUT},
     MessageChannel, WsBackendSenderAsync, WsMessage,
 };
 
@@ -21,16 +22,17 @@ use tokio::{
     io::{AsyncRead, AsyncWrite},
     net::TcpStream,
     sync::{
-        mpsc::{channel, error::TryRecvError, Receiver},
+        mpsc::{channel, Receiver},
         RwLock,
     },
+    time::Instant,
 };
 use tokio_tungstenite::{
     connect_async,
     tungstenite::{handshake::client::Response, protocol::Message, Error},
     MaybeTlsStream, WebSocketStream,
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use url::Url;
 use uuid::Uuid;
 
@@ -38,18 +40,22 @@ use super::{
     constants::{BINANCE_STREAM_WSS_BASE_URL, BINANCE_TRADE_WSS_URL},
     http::Credentials,
     hyper::BinanceHttpClient,
+    listen_key::{create_listen_key, LISTEN_KEY_EXPIRED},
+    model::UserDataStreamExpiredEvent,
     stream::{
-        market::BookTickerStream,
-        user_data::{self, ListenKeyResult, UserDataStream},
+        market::{BookTickerStream, DiffDepthStream},
+        user_data::UserDataStream,
     },
     websockets::{BinanceEventHandler, BinanceWebsocketEvent, Events},
 };
 /// Binance websocket client using Tungstenite.
+#[derive(Clone)]
 pub struct BinanceWebSocketClient {
     credentials: Option<AccessKey>,
     sender: WsBackendSenderAsync, // send request to backend
     pub event_handler: Option<Arc<RwLock<Box<dyn BinanceEventHandler + Send + Sync>>>>,
     id: u64,
+    pub listen_key: Option<String>, // of the user data stream subscribed on creation
 }
 
 impl BinanceWebSocketClient {
@@ -83,15 +89,15 @@ impl BinanceWebSocketClient {
             sender,
             event_handler: Some(Arc::clone(&handler_box)),
             id: 0,
+            listen_key: None,
         };
         if let Some(ref ak) = credentials_clone {
             // start subscribe user data
             let credentials = Credentials::from_hmac(ak.api_key.clone(), ak.api_secret.clone());
             let client = BinanceHttpClient::default().credentials(credentials);
-            let request = user_data::new_listen_key();
-            let data = client.send(request).await.unwrap().into_body_str().await.unwrap();
-            let lk = serde_json::from_str::<ListenKeyResult>(&data).unwrap();
-            websockets.subscribe(vec![&UserDataStream::new(&lk.listen_key).into()]).await;
+            let listen_key = create_listen_key(&client).await.expect("unable to create listen key");
+            websockets.subscribe(vec![&UserDataStream::new(&listen_key).into()]).await;
+            websockets.listen_key = Some(listen_key);
         }
         (websockets, backhand)
     }
@@ -119,6 +125,15 @@ impl BinanceWebSocketClient {
         self.subscribe(vec![&BookTickerStream::from_symbol(&symbol_str).into()]).await;
     }
 
+    /// subscribe the diff depth stream a local order book is maintained from
+    pub async fn subscribe_diff_depth<S>(&mut self, symbol: S)
+    where
+        S: Into<String>,
+    {
+        let symbol_str: String = symbol.into();
+        self.subscribe(vec![&DiffDepthStream::from_symbol(&symbol_str).into()]).await;
+    }
+
     fn get_subscribe_message<'a>(
         &mut self,
         method: &str,
@@ -236,9 +251,6 @@ impl BinanceWebSocketClient {
             let _ts = request_order.timestamp;
 
             let request: Request = request_order.clone().into();
-            let params = request.params();
-            println!("params: {:?}", params);
-
             let query_string = request.get_payload_to_sign();
 
             let signature = sign(&query_string, &credentials.signature).unwrap();
@@ -257,8 +269,6 @@ impl BinanceWebSocketClient {
                 "params":  json_value
             });
 
-            println!("order to send {:?}", msg.to_string());
-
             if let Err(error_msg) =
                 self.sender.send(crate::MessageChannel::Trade, &msg.to_string()).await
             {
@@ -307,11 +317,22 @@ impl<T> AsMut<WebSocketStream<T>> for WebSocketState<T> {
     }
 }
 
+/// # Description
+/// Owns the sockets: writes the requests of `BinanceWebSocketClient` and passes the streams read
+/// to the event handler.
+///
+/// When either socket is closed, fails or is silent for `STALE_FEED_TIMEOUT`, both are
+/// reconnected with exponential backoff and the streams subscribed so far, the user data stream
+/// of the listen key included, are subscribed again. The handler is told through
+/// `on_disconnected` and `on_reconnected`
 pub struct BinanceSocketBackhandAsync {
     rx: Receiver<WsMessage>, // any message received will send to trade socket
     pub socket_stream: WebSocketState<MaybeTlsStream<TcpStream>>,
     pub socket_trade: WebSocketState<MaybeTlsStream<TcpStream>>,
     event_handler: Option<Arc<RwLock<Box<dyn BinanceEventHandler + Send + Sync>>>>,
+    streams: Vec<String>, // streams subscribed
+    backoff: Backoff,
+    id: u64, // id of the subscriptions replayed
 }
 
 impl BinanceSocketBackhandAsync {
@@ -321,126 +342,265 @@ impl BinanceSocketBackhandAsync {
         rx: Receiver<WsMessage>,
         event_handler: Option<Arc<RwLock<Box<dyn BinanceEventHandler + Send + Sync>>>>,
     ) -> Self {
-        Self { rx, socket_stream, socket_trade, event_handler }
+        Self {
+            rx,
+            socket_stream,
+            socket_trade,
+            event_handler,
+            streams: Vec::new(),
+            backoff: Backoff::default(),
+            id: 0,
+        }
     }
 
     pub async fn event_loop(&mut self) -> anyhow::Result<()> {
+        let mut ping = tokio::time::interval(PING_INTERVAL);
+        let (mut last_stream_message, mut last_trade_message) = (Instant::now(), Instant::now());
         loop {
-            loop {
-                match self.rx.try_recv() {
-                    Ok(msg) => match msg {
-                        WsMessage::Text(ty, text) => {
-                            println!("msg to send: {:?}", text);
-                            let time = get_current_ts().as_millis();
-                            info!("socket write message {:?}, time: {:?}", text, time);
-                            match ty {
-                                MessageChannel::Stream => {
-                                    let ret =
-                                        self.socket_stream.write_message(Message::Text(text)).await;
-                                    match ret {
-                                        Err(e) => {
-                                            eprintln!("error in write to socket stream {:?}", e);
-                                        }
-                                        Ok(()) => {
-                                            println!("write to socket stream success");
-                                        }
-                                    }
-                                }
-                                MessageChannel::Trade => {
-                                    let ret =
-                                        self.socket_trade.write_message(Message::Text(text)).await;
-                                    match ret {
-                                        Err(e) => {
-                                            eprintln!("error in socket write {:?}", e);
-                                        }
-                                        Ok(()) => {
-                                            println!("write to socket success");
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        WsMessage::Close => {
-                            println!("socket close");
-                            // return self.socket_trade.close().await.map_err(|e| e.into());
-                        }
-                    },
-                    Err(TryRecvError::Disconnected) => {
-                        println!("disconnected from sender");
-                        break;
+            let connected = tokio::select! {
+                msg = self.rx.recv() => match msg {
+                    Some(WsMessage::Text(ty, text)) => self.write_text(ty, text).await,
+                    Some(WsMessage::Close) => {
+                        error!("socket close");
+                        true
+                    }
+                    None => {
+                        error!("disconnected from sender");
+                        return Ok(());
+                    }
+                },
+                message_ret = self.socket_stream.try_read_message() => match message_ret {
+                    Ok(Some(message)) => {
+                        last_stream_message = Instant::now();
+                        self.on_stream_message(message).await
+                    }
+                    Ok(None) => {
+                        error!("binance stream socket closed");
+                        false
+                    }
+                    Err(e) => {
+                        error!("error in read message {:?}", e);
+                        false
+                    }
+                },
+                message_ret = self.socket_trade.try_read_message() => match message_ret {
+                    Ok(Some(message)) => {
+                        last_trade_message = Instant::now();
+                        self.on_trade_message(message).await
+                    }
+                    Ok(None) => {
+                        error!("binance trade socket closed");
+                        false
                     }
-                    Err(TryRecvError::Empty) => {
-                        // println!("empty message to send");
-                        break;
+                    Err(e) => {
+                        error!("error in read trade socket message {:?}", e);
+                        false
+                    }
+                },
+                _ = ping.tick() => {
+                    let elapsed = last_stream_message.elapsed().max(last_trade_message.elapsed());
+                    if elapsed > STALE_FEED_TIMEOUT {
+                        error!("binance socket stale since {:?}", elapsed);
+                        false
+                    } else {
+                        // alive for a ping interval at least
+                        self.backoff.reset();
+                        self.ping().await
                     }
                 }
+            };
+            if !connected {
+                self.reconnect().await;
+                (last_stream_message, last_trade_message) = (Instant::now(), Instant::now());
             }
+        }
+    }
 
-            let message_ret = self.socket_stream.try_read_message().await;
-
-            match message_ret {
-                Ok(None) => continue,
-                Ok(Some(message)) => {
-                    match message {
-                        Message::Text(text) => {
-                            // println!("got msg: {:?}", text);
-                            if let Some(ref mut h) = self.event_handler {
-                                let mut _g_ret = h.write().await;
-
-                                let mut value: serde_json::Value = serde_json::from_str(&text)?;
-
-                                if let Some(data) = value.get("data") {
-                                    value = serde_json::from_str(&data.to_string())?;
-                                }
-
-                                if let Ok(events) = serde_json::from_value::<Events>(value) {
-                                    let action = match events {
-                                        Events::Vec(v) => BinanceWebsocketEvent::DayTickerAll(v),
-                                        Events::BookTickerEvent(v) => {
-                                            BinanceWebsocketEvent::BookTicker(v)
-                                        }
-                                        Events::BalanceUpdateEvent(v) => {
-                                            BinanceWebsocketEvent::BalanceUpdate(v)
-                                        }
-                                        Events::AccountUpdateEvent(v) => {
-                                            BinanceWebsocketEvent::AccountUpdate(v)
-                                        }
-                                        Events::OrderTradeEvent(v) => {
-                                            BinanceWebsocketEvent::OrderTrade(v)
-                                        }
-                                        Events::AggrTradesEvent(v) => {
-                                            BinanceWebsocketEvent::AggrTrades(v)
-                                        }
-                                        Events::TradeEvent(v) => BinanceWebsocketEvent::Trade(v),
-                                        Events::DayTickerEvent(v) => {
-                                            BinanceWebsocketEvent::DayTicker(v)
-                                        }
-                                        Events::KlineEvent(v) => BinanceWebsocketEvent::Kline(v),
-                                        Events::DiffOrderBook(v) => {
-                                            BinanceWebsocketEvent::DiffOrderBook(v)
-                                        }
-                                        Events::OrderBook(v) => BinanceWebsocketEvent::OrderBook(v),
-                                        Events::DepthOrderBookEvent(v) => {
-                                            BinanceWebsocketEvent::DepthOrderBook(v)
-                                        }
-                                    };
-                                    _g_ret.on_data_event(action);
-                                }
-                                // let event: BinanceWebsocketEvent = from_str(&text)?;
-                            }
-                        }
-                        Message::Binary(_) | Message::Pong(_) => {}
-                        Message::Ping(_) => {
-                            self.socket_stream.write_message(Message::Pong(vec![])).await?;
-                        }
-                        Message::Close(e) => {
-                            error!("closed {:?}", e);
+    /// false if the socket failed
+    async fn write_text(&mut self, ty: MessageChannel, text: String) -> bool {
+        let time = get_current_ts().as_millis();
+        info!("socket write message {:?}, time: {:?}", text, time);
+        match ty {
+            MessageChannel::Stream => {
+                self.record(&text);
+                let ret = self.socket_stream.write_message(Message::Text(text)).await;
+                match ret {
+                    Err(e) => {
+                        error!("error in write to socket stream {:?}", e);
+                        false
+                    }
+                    Ok(()) => {
+                        debug!("write to socket stream success");
+                        true
+                    }
+                }
+            }
+            MessageChannel::Trade => {
+                let ret = self.socket_trade.write_message(Message::Text(text)).await;
+                match ret {
+                    Err(e) => {
+                        error!("error in socket write {:?}", e);
+                        false
+                    }
+                    Ok(()) => {
+                        debug!("write to socket success");
+                        true
+                    }
+                }
+            }
+        }
+    }
+
+    /// keep the streams subscribed to subscribe them again on reconnection
+    fn record(&mut self, text: &str) {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+            return;
+        };
+        let streams = value
+            .get("params")
+            .and_then(|p| p.as_array())
+            .map(|p| p.iter().filter_map(|s| s.as_str()).map(String::from).collect::<Vec<_>>())
+            .unwrap_or_default();
+        match value.get("method").and_then(|m| m.as_str()) {
+            Some("SUBSCRIBE") => {
+                for stream in streams {
+                    if !self.streams.contains(&stream) {
+                        self.streams.push(stream);
+                    }
+                }
+            }
+            Some("UNSUBSCRIBE") => self.streams.retain(|s| !streams.contains(s)),
+            _ => {}
+        }
+    }
+
+    /// false if a socket failed
+    async fn ping(&mut self) -> bool {
+        for socket in [&mut self.socket_stream, &mut self.socket_trade] {
+            if let Err(e) = socket.write_message(Message::Ping(vec![])).await {
+                error!("error in socket ping {:?}", e);
+                return false;
+            }
+        }
+        true
+    }
+
+    async fn reconnect(&mut self) {
+        if let Some(ref h) = self.event_handler {
+            h.write().await.on_disconnected();
+        }
+        loop {
+            let (socket_stream, _) =
+                connect_with_backoff("binance stream socket", &mut self.backoff, || {
+                    BinanceWebSocketClient::connect_async(BINANCE_STREAM_WSS_BASE_URL)
+                })
+                .await;
+            self.socket_stream = socket_stream;
+            let (socket_trade, _) =
+                connect_with_backoff("binance trade socket", &mut self.backoff, || {
+                    BinanceWebSocketClient::connect_async(BINANCE_TRADE_WSS_URL)
+                })
+                .await;
+            self.socket_trade = socket_trade;
+            match self.replay().await {
+                Ok(()) => break,
+                Err(e) => error!("error in replay binance subscriptions {:?}", e),
+            }
+        }
+        if let Some(ref h) = self.event_handler {
+            h.write().await.on_reconnected();
+        }
+    }
+
+    async fn replay(&mut self) -> anyhow::Result<()> {
+        if self.streams.is_empty() {
+            return Ok(());
+        }
+        let msg = json!({"method": "SUBSCRIBE", "params": self.streams, "id": self.id});
+        self.id += 1;
+        info!("socket replay message {:?}", msg.to_string());
+        self.socket_stream.write_message(Message::Text(msg.to_string())).await?;
+        Ok(())
+    }
+
+    /// false if the socket failed
+    async fn on_trade_message(&mut self, message: Message) -> bool {
+        match message {
+            Message::Text(text) => info!("binance trade socket message {:?}", text),
+            Message::Ping(_) => {
+                if let Err(e) = self.socket_trade.write_message(Message::Pong(vec![])).await {
+                    error!("error in trade socket pong {:?}", e);
+                    return false;
+                }
+            }
+            Message::Close(e) => {
+                error!("trade socket closed {:?}", e);
+                return false;
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// false if the socket failed, messages that do not parse are logged and skipped
+    async fn on_stream_message(&mut self, message: Message) -> bool {
+        match message {
+            Message::Text(text) => {
+                if let Some(ref mut h) = self.event_handler {
+                    match parse_stream_event(&text) {
+                        Ok(Some(event)) => h.write().await.on_data_event(event),
+                        Ok(None) => {}
+                        Err(e) => {
+                            warn!("error in parse binance stream message {:?}, {:?}", e, text)
                         }
-                        _ => {}
                     }
                 }
-                Err(e) => println!("error in read message {:?}", e),
             }
+            Message::Binary(_) | Message::Pong(_) => {}
+            Message::Ping(_) => {
+                if let Err(e) = self.socket_stream.write_message(Message::Pong(vec![])).await {
+                    error!("error in stream socket pong {:?}", e);
+                    return false;
+                }
+            }
+            Message::Close(e) => {
+                error!("closed {:?}", e);
+                return false;
+            }
+            _ => {}
         }
+        true
+    }
+}
+
+/// the event of a stream message, none if it is not an event handled
+fn parse_stream_event(text: &str) -> anyhow::Result<Option<BinanceWebsocketEvent>> {
+    let mut value: serde_json::Value = serde_json::from_str(text)?;
+
+    if let Some(data) = value.get("data") {
+        value = serde_json::from_str(&data.to_string())?;
+    }
+
+    if value.get("e").and_then(|e| e.as_str()) == Some(LISTEN_KEY_EXPIRED) {
+        let event = serde_json::from_value::<UserDataStreamExpiredEvent>(value)?;
+        return Ok(Some(BinanceWebsocketEvent::UserDataStreamExpired(event)));
     }
+    let Ok(events) = serde_json::from_value::<Events>(value) else {
+        return Ok(None);
+    };
+    let action = match events {
+        Events::Vec(v) => BinanceWebsocketEvent::DayTickerAll(v),
+        Events::BookTickerEvent(v) => BinanceWebsocketEvent::BookTicker(v),
+        Events::BalanceUpdateEvent(v) => BinanceWebsocketEvent::BalanceUpdate(v),
+        Events::AccountUpdateEvent(v) => BinanceWebsocketEvent::AccountUpdate(v),
+        Events::OrderTradeEvent(v) => BinanceWebsocketEvent::OrderTrade(v),
+        Events::AggrTradesEvent(v) => BinanceWebsocketEvent::AggrTrades(v),
+        Events::TradeEvent(v) => BinanceWebsocketEvent::Trade(v),
+        Events::DayTickerEvent(v) => BinanceWebsocketEvent::DayTicker(v),
+        Events::KlineEvent(v) => BinanceWebsocketEvent::Kline(v),
+        Events::DiffOrderBook(v) => BinanceWebsocketEvent::DiffOrderBook(v),
+        Events::OrderBook(v) => BinanceWebsocketEvent::OrderBook(v),
+        Events::DepthOrderBookEvent(v) => BinanceWebsocketEvent::DepthOrderBook(v),
+    };
+    Ok(Some(action))
 }
@@ -1,4 +1,7 @@
-use crate::bitfinex::{client::*, errors::*};
+use crate::{
+    bitfinex::{client::*, errors::*},
+    cefi_service::OrderBook,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::from_str;
@@ -60,3 +63,79 @@ impl Book {
         Ok(book)
     }
 }
+
+/// number of levels of each side of the book the checksum of bitfinex covers
+pub const CHECKSUM_DEPTH: usize = 25;
+
+/// # Description
+/// CRC32 checksum of the top `CHECKSUM_DEPTH` levels of a book as bitfinex computes it: the
+/// price and amount of the bid and ask of each level, asks with negative amounts, joined by `:`
+pub fn get_book_checksum(ob: &OrderBook) -> i64 {
+    let mut bids = ob.bids.values().rev();
+    let mut asks = ob.asks.values();
+    let mut values = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+    for _ in 0..CHECKSUM_DEPTH {
+        if let Some(bid) = bids.next() {
+            values.push(format_checksum_number(bid.price));
+            values.push(format_checksum_number(bid.amount));
+        }
+        if let Some(ask) = asks.next() {
+            values.push(format_checksum_number(ask.price));
+            values.push(format_checksum_number(-ask.amount));
+        }
+    }
+    // bitfinex sends the checksum as a signed 32 bits integer
+    crc32fast::hash(values.join(":").as_bytes()) as i32 as i64
+}
+
+/// a number formatted as javascript does, the way bitfinex computes checksums: no trailing
+/// zeros, and exponent notation below 1e-6
+pub fn format_checksum_number(value: Decimal) -> String {
+    let value = value.normalize();
+    if value.is_zero() || value.abs() >= Decimal::new(1, 6) {
+        return value.to_string();
+    }
+    let digits = value.abs().to_string();
+    let fraction = digits.trim_start_matches("0.");
+    let significant = fraction.trim_start_matches('0');
+    let exponent = significant.len() as i64 - fraction.len() as i64 - 1;
+    let mantissa = match significant.split_at(1) {
+        (first, "") => first.to_string(),
+        (first, rest) => format!("{}.{}", first, rest),
+    };
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    format!("{}{}e{}", sign, mantissa, exponent)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{cefi_service::construct_order_book, util::to_decimal};
+
+    #[test]
+    fn test_format_checksum_number() {
+        assert_eq!(format_checksum_number(to_decimal(1000.1)), "1000.1");
+        assert_eq!(format_checksum_number(Decimal::new(-10, 0)), "-10");
+        assert_eq!(format_checksum_number(Decimal::new(9_4240, 5)), "0.9424");
+        assert_eq!(format_checksum_number(Decimal::new(1, 6)), "0.000001");
+        assert_eq!(format_checksum_number(Decimal::new(1, 7)), "1e-7");
+        assert_eq!(format_checksum_number(Decimal::new(-12, 8)), "-1.2e-7");
+    }
+
+    #[test]
+    fn test_get_book_checksum() {
+        let level = |price: f64, count: i64, amount: f64| TradingOrderBookLevel {
+            price: to_decimal(price),
+            count,
+            amount: to_decimal(amount),
+        };
+        let ob = construct_order_book(vec![
+            level(1000.1, 7, 1.1),
+            level(1003.4, 1, -2.1),
+            level(1000.2, 5, 2.1),
+            level(1002.4, 2, -3.1),
+        ]);
+        let expected = crc32fast::hash(b"1000.2:2.1:1002.4:-3.1:1000.1:1.1:1003.4:-2.1");
+        assert_eq!(get_book_checksum(&ob), expected as i32 as i64);
+    }
+}
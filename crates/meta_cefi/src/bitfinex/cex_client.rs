@@ -0,0 +1,242 @@
+use super::{
+    account::{Account, WalletType},
+    common::P0,
+    handler::BitfinexEventHandlerImpl,
+    model::Order,
+    orders::Orders,
+    websockets::EventType,
+    websockets_tokio::BitfinexWebSocketsAsync,
+};
+use crate::{
+    cefi_service::{get_bitfinex_trade_symbol, get_pair, AccessKey, OrderBook},
+    cex_client::CexClient,
+    get_cex_pair,
+    model::{CexBalance, CexEvent, CexOrder},
+};
+use async_trait::async_trait;
+use meta_address::enums::Asset;
+use meta_common::{enums::CexExchange, models::MarcketChange};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use std::{collections::BTreeMap, sync::mpsc::SyncSender};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tracing::info;
+
+/// frequency of the order book subscribed: realtime
+const BOOK_FREQUENCY: &str = "F0";
+
+/// number of price points of each side of the order book subscribed
+const BOOK_LENGTH: u32 = 100;
+
+pub struct BitfinexCexClient {
+    access_key: Option<AccessKey>,
+    sender_market_change: Option<SyncSender<MarcketChange>>,
+    sender_cex_event: Option<SyncSender<CexEvent>>,
+    sockets: BTreeMap<String, BitfinexWebSocketsAsync>, // (pair, socket)
+    orders: Orders,
+    account: Account,
+}
+
+impl BitfinexCexClient {
+    pub fn new(
+        access_key: Option<AccessKey>,
+        sender_market_change: Option<SyncSender<MarcketChange>>,
+        sender_cex_event: Option<SyncSender<CexEvent>>,
+    ) -> Self {
+        let (api_key, api_secret) = match access_key {
+            Some(ref ak) => (Some(ak.api_key.to_string()), Some(ak.api_secret.to_string())),
+            None => (None, None),
+        };
+        Self {
+            orders: Orders::new(api_key.clone(), api_secret.clone()),
+            account: Account::new(api_key, api_secret),
+            access_key,
+            sender_market_change,
+            sender_cex_event,
+            sockets: BTreeMap::new(),
+        }
+    }
+
+    fn get_socket(
+        &mut self,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<&mut BitfinexWebSocketsAsync> {
+        self.sockets
+            .get_mut(&get_pair(base, quote))
+            .ok_or_else(|| anyhow::anyhow!("bitfinex pair {}_{} not connected", base, quote))
+    }
+
+    /// active orders first, then the order history of the pair
+    async fn find_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<Option<Order>> {
+        let orders = self.orders.clone();
+        let symbol = get_bitfinex_trade_symbol(base, quote);
+        tokio::task::spawn_blocking(move || {
+            let is_order = |o: &Order| o.client_id as u128 == client_order_id;
+            let active = orders.active_orders().map_err(|e| anyhow::anyhow!("{}", e))?;
+            if let Some(order) = active.into_iter().find(is_order) {
+                return Ok(Some(order));
+            }
+            let history = orders.history(symbol).map_err(|e| anyhow::anyhow!("{}", e))?;
+            Ok(history.into_iter().find(is_order))
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl CexClient for BitfinexCexClient {
+    fn venue(&self) -> CexExchange {
+        CexExchange::BITFINEX
+    }
+
+    async fn connect(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()> {
+        let pair = get_pair(base, quote);
+        if self.sockets.contains_key(&pair) {
+            return Ok(());
+        }
+        let (tx_book_resync, rx_book_resync) = unbounded_channel::<i32>();
+        let event_handler = BitfinexEventHandlerImpl::new(
+            self.sender_market_change.clone(),
+            self.sender_cex_event.clone(),
+        )
+        .with_book_resync(tx_book_resync);
+        let (mut ws_client, socket_backend) =
+            BitfinexWebSocketsAsync::new(Box::new(event_handler)).await;
+        // the socket is authenticated again on reconnection
+        let mut socket_backend = match self.access_key {
+            Some(ref ak) => socket_backend.with_auth(ak.clone()),
+            None => socket_backend,
+        };
+        {
+            tokio::spawn(async move {
+                let _ = socket_backend.event_loop().await;
+            });
+        }
+        tokio::spawn(run_book_resyncs(
+            ws_client.clone(),
+            get_bitfinex_trade_symbol(base, quote),
+            rx_book_resync,
+        ));
+
+        if let Some(ref ak) = self.access_key {
+            ws_client
+                .auth(ak.api_key.to_string(), ak.api_secret.to_string(), false, &[])
+                .await
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+        }
+        ws_client.conf().await;
+        self.sockets.insert(pair, ws_client);
+        Ok(())
+    }
+
+    async fn subscribe_books(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()> {
+        let symbol = get_bitfinex_trade_symbol(base, quote);
+        subscribe_trading_books(self.get_socket(base, quote)?, symbol).await;
+        Ok(())
+    }
+
+    async fn submit_order(
+        &mut self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+        amount: Decimal,
+    ) -> anyhow::Result<()> {
+        let symbol = get_cex_pair(CexExchange::BITFINEX, base, quote);
+        self.get_socket(base, quote)?.submit_order(client_order_id, symbol, amount).await;
+        Ok(())
+    }
+
+    async fn cancel_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<()> {
+        let order = self
+            .find_order(client_order_id, base, quote)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("bitfinex order {} not found", client_order_id))?;
+        let orders = self.orders.clone();
+        tokio::task::spawn_blocking(move || {
+            orders.cancel_order(order.id).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await??;
+        Ok(())
+    }
+
+    async fn get_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<CexOrder> {
+        let order = self
+            .find_order(client_order_id, base, quote)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("bitfinex order {} not found", client_order_id))?;
+        Ok(to_cex_order(order))
+    }
+
+    async fn get_balances(&self) -> anyhow::Result<Vec<CexBalance>> {
+        let account = self.account.clone();
+        let wallets = tokio::task::spawn_blocking(move || {
+            account.get_wallets().map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await??;
+        Ok(wallets
+            .into_iter()
+            .filter(|w| w.wallet_type == WalletType::Exchange)
+            .map(|w| CexBalance {
+                currency: w.currency,
+                total: Decimal::from_f64(w.balance).unwrap_or_default(),
+                available: Decimal::from_f64(w.balance_available).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn get_order_book(&self, base: Asset, quote: Asset) -> Option<OrderBook> {
+        let socket = self.sockets.get(&get_pair(base, quote))?;
+        let handler = socket.event_handler.as_ref()?.read().await;
+        handler.as_any().downcast_ref::<BitfinexEventHandlerImpl>()?.order_book.clone()
+    }
+}
+
+async fn subscribe_trading_books(ws_client: &mut BitfinexWebSocketsAsync, symbol: String) {
+    ws_client.subscribe_books(symbol, EventType::Trading, P0, BOOK_FREQUENCY, BOOK_LENGTH).await;
+}
+
+/// # Description
+/// Resubscribes the order book channel of `symbol` each time the handler finds it out of sync,
+/// until the handler drops its resync sender
+pub async fn run_book_resyncs(
+    mut ws_client: BitfinexWebSocketsAsync,
+    symbol: String,
+    mut rx_book_resync: UnboundedReceiver<i32>,
+) {
+    while let Some(chan_id) = rx_book_resync.recv().await {
+        info!("resubscribe bitfinex {} order book of channel {}", symbol, chan_id);
+        ws_client.unsubscribe(chan_id).await;
+        subscribe_trading_books(&mut ws_client, symbol.clone()).await;
+    }
+}
+
+/// the amount left of a bitfinex order is signed as its original amount
+pub fn to_cex_order(order: Order) -> CexOrder {
+    let amount = Decimal::from_f64(order.amount_original).unwrap_or_default();
+    let remaining = Decimal::from_f64(order.amount).unwrap_or_default();
+    CexOrder {
+        client_order_id: order.client_id as u128,
+        order_id: order.id as u64,
+        symbol: order.symbol,
+        amount,
+        exec_amount: amount - remaining,
+        exec_price: Decimal::from_f64(order.price_avg).filter(|p| !p.is_zero()),
+        status: order.order_status.unwrap_or_default(),
+    }
+}
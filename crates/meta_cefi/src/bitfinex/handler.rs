@@ -2,19 +2,33 @@
 
 use crate::{
     bitfinex::{
+        book::get_book_checksum,
         errors::*,
         events::{DataEvent, NotificationEvent, SEQUENCE},
         websockets::BitfinexEventHandler,
     },
     cefi_service::{construct_order_book, update_order_book, OrderBook}, model::{CexEvent, TradeExecutionInfo},
 };
-use meta_common::models::{CurrentSpread, MarcketChange};
+use meta_common::{
+    enums::CexExchange,
+    models::{CurrentSpread, MarcketChange},
+};
 use rust_decimal::Decimal;
 use std::sync::mpsc::SyncSender;
+use tokio::sync::mpsc::UnboundedSender;
 extern crate core_affinity;
 use tracing::{debug, error, info, warn};
 
+/// number of resyncs in a row, without a checksum verified in between, after which the order
+/// book is given up
+pub const MAX_BOOK_RESYNC_RETRIES: u32 = 5;
 
+#[derive(Clone, Debug, Default)]
+pub struct BookResyncMetrics {
+    pub sequence_gaps: u64,
+    pub checksum_mismatches: u64,
+    pub resyncs: u64,
+}
 
 #[derive(Clone, Debug)]
 pub struct BitfinexEventHandlerImpl {
@@ -22,6 +36,11 @@ pub struct BitfinexEventHandlerImpl {
     sender_cex_event: Option<SyncSender<CexEvent>>,
     pub order_book: Option<OrderBook>,
     sequence: u32,
+    book_channel: Option<i32>, // channel of the book subscribed, none while resyncing
+    sender_book_resync: Option<UnboundedSender<i32>>,
+    resyncing: bool,
+    consecutive_resyncs: u32,
+    pub metrics: BookResyncMetrics,
 }
 
 impl BitfinexEventHandlerImpl {
@@ -29,20 +48,113 @@ impl BitfinexEventHandlerImpl {
         sender_market_change: Option<SyncSender<MarcketChange>>,
         sender_cex_event: Option<SyncSender<CexEvent>>,
     ) -> Self {
-        Self { order_book: None, sequence: 0, sender_market_change, sender_cex_event }
+        Self {
+            order_book: None,
+            sequence: 0,
+            sender_market_change,
+            sender_cex_event,
+            book_channel: None,
+            sender_book_resync: None,
+            resyncing: false,
+            consecutive_resyncs: 0,
+            metrics: BookResyncMetrics::default(),
+        }
+    }
+
+    /// resync the order book by resubscribing the channel sent to `sender_book_resync`, see
+    /// `run_book_resyncs`
+    pub fn with_book_resync(mut self, sender_book_resync: UnboundedSender<i32>) -> Self {
+        self.sender_book_resync = Some(sender_book_resync);
+        self
     }
 
+    /// a gap in the sequence of the connection means the book may have missed updates
     fn check_sequence(&mut self, seq: u32) {
-        if self.sequence == 0 {
-            self.sequence = seq;
-        } else {
-            if seq - self.sequence != 1 {
-                panic!("out of sequence current {} received {}", self.sequence, seq);
+        if self.sequence != 0 && seq != self.sequence.wrapping_add(1) {
+            warn!("bitfinex out of sequence current {} received {}", self.sequence, seq);
+            self.metrics.sequence_gaps += 1;
+            self.resync_book();
+        }
+        self.sequence = seq;
+    }
+
+    /// # Description
+    /// Drops the order book and asks for its channel to be resubscribed, the book is rebuilt from
+    /// the snapshot following the subscription. The owner is sent a `CexEvent::Disconnected` so it
+    /// stops trading on the spread of the dropped book, then a `CexEvent::Connected` with the
+    /// spread of the new snapshot. Once `MAX_BOOK_RESYNC_RETRIES` resyncs in a row did not bring
+    /// the book back in sync, the book is left dropped and a `CexEvent::Fatal` is sent
+    fn resync_book(&mut self) {
+        if self.resyncing {
+            return;
+        }
+        let Some(channel) = self.book_channel else {
+            return;
+        };
+        self.consecutive_resyncs += 1;
+        self.resyncing = true;
+        self.order_book = None;
+        self.book_channel = None;
+        self.send_cex_event(CexEvent::Disconnected(CexExchange::BITFINEX));
+        if self.consecutive_resyncs > MAX_BOOK_RESYNC_RETRIES {
+            // the book is not resynced any more, the owner must stop trading on it
+            let reason = format!(
+                "bitfinex order book still out of sync after {} resyncs, {:?}",
+                MAX_BOOK_RESYNC_RETRIES, self.metrics
+            );
+            error!("{}", reason);
+            self.send_cex_event(CexEvent::Fatal(CexExchange::BITFINEX, reason));
+            return;
+        }
+        self.metrics.resyncs += 1;
+        info!("resync bitfinex order book of channel {}, {:?}", channel, self.metrics);
+        match self.sender_book_resync {
+            Some(ref tx) => {
+                if let Err(e) = tx.send(channel) {
+                    error!("error in request bitfinex book resync {:?}", e);
+                }
+            }
+            None => warn!("no bitfinex book resync sender"),
+        }
+    }
+
+    fn send_cex_event(&self, event: CexEvent) {
+        match self.sender_cex_event {
+            Some(ref tx) => {
+                if let Err(e) = tx.send(event) {
+                    error!("error in send bitfinex cex event {:?}", e);
+                }
             }
-            self.sequence = seq;
+            None => warn!("no bitfinex cex event sender"),
         }
     }
 
+    /// publish the best bid and ask of the order book
+    fn send_spread(&self, best_bid: Decimal, best_ask: Decimal) {
+        if let Some(ref tx) = self.sender_market_change {
+            let ret = tx.send(MarcketChange {
+                cex: Some(CurrentSpread {
+                    best_ask,
+                    best_bid,
+                    best_bid_v3_fee: None,
+                    best_ask_v3_fee: None,
+                    depth: None,
+                }),
+                dex: None,
+            });
+            match ret {
+                Ok(_) => {}
+                Err(e) => {
+                    error!("error in send marcket change in bitfinex {:?}", e);
+                }
+            }
+        }
+    }
+
+    fn is_book_channel(&self, channel: i32) -> bool {
+        self.book_channel == Some(channel)
+    }
+
     fn log_order_book(&self) {
         debug!("new order book");
 
@@ -80,15 +192,22 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
     fn on_subscribed(&mut self, event: NotificationEvent) {
         if let NotificationEvent::TradingSubscribed(msg) = event {
             info!("bitfinex trading order book subscribed: {:?}", msg);
+            self.book_channel = Some(msg.chan_id as i32);
         }
     }
 
-    fn on_checksum(&mut self, _event: i64) {
-        // debug!("received checksum event: {:?}", event);
-        // match event {
-        //     DataEvent::CheckSumEvent(_a, _b, _c, sequence) => self.check_sequence(sequence),
-        //     _ => panic!("checksum event expected"),
-        // }
+    fn on_checksum(&mut self, event: i64) {
+        let Some(ref ob) = self.order_book else {
+            return;
+        };
+        let checksum = get_book_checksum(ob);
+        if checksum == event {
+            self.consecutive_resyncs = 0;
+        } else {
+            warn!("bitfinex order book checksum {} received {}", checksum, event);
+            self.metrics.checksum_mismatches += 1;
+            self.resync_book();
+        }
     }
 
     fn on_heart_beat(&mut self, _channel: i32, _data: String, _seq: SEQUENCE) {}
@@ -99,10 +218,12 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
             debug!("handle heart beat event");
             self.check_sequence(seq);
             self.on_heart_beat(a, b, seq);
-        } else if let DataEvent::CheckSumEvent(_a, _b, data, seq) = event {
+        } else if let DataEvent::CheckSumEvent(channel, _b, data, seq) = event {
             debug!("handle checksum event");
             self.check_sequence(seq);
-            self.on_checksum(data);
+            if self.is_book_channel(channel) {
+                self.on_checksum(data);
+            }
         } else if let DataEvent::FundingCreditSnapshotEvent(_, _, _, seq, _) = event {
             debug!("handle fcs event {:?}", event);
             self.check_sequence(seq);
@@ -155,7 +276,21 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
             debug!("handle ob snapshot event sequence {:?}", { seq });
             info!("bitfinex order book snapshot channel({}) sequence({})", channel, seq);
             self.check_sequence(seq);
-            self.order_book = Some(construct_order_book(book_snapshot));
+            if !self.is_book_channel(channel) {
+                // left from the subscription resynced
+                return;
+            }
+            let resynced = std::mem::replace(&mut self.resyncing, false);
+            let ob = construct_order_book(book_snapshot);
+            let best_bid = ob.bids.last_key_value().map_or(Decimal::default(), |x| *x.0);
+            let best_ask = ob.asks.first_key_value().map_or(Decimal::default(), |x| *x.0);
+            self.order_book = Some(ob);
+            if resynced {
+                info!("bitfinex order book resynced");
+                self.send_cex_event(CexEvent::Connected(CexExchange::BITFINEX));
+            }
+            // the spread of the new book, without waiting for the next update
+            self.send_spread(best_bid, best_ask);
         } else if let DataEvent::BookTradingUpdateEvent(channel, book_update, seq) = event {
             debug!("handle ob update event sequence {:?}", { seq });
             debug!(
@@ -163,6 +298,9 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
                 channel, seq, book_update
             );
             self.check_sequence(seq);
+            if !self.is_book_channel(channel) {
+                return;
+            }
             let prev_best_bid = self.order_book.as_ref().map_or(Decimal::default(), |ob| {
                 ob.bids.last_key_value().map_or(Decimal::default(), |x| *x.0)
             });
@@ -183,21 +321,7 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
             });
 
             if !current_best_ask.eq(&prev_best_ask) || !current_best_bid.eq(&prev_best_bid) {
-                if let Some(ref tx) = self.sender_market_change {
-                    let ret = tx.send(MarcketChange {
-                        cex: Some(CurrentSpread {
-                            best_ask: current_best_ask,
-                            best_bid: current_best_bid,
-                        }),
-                        dex: None,
-                    });
-                    match ret {
-                        Ok(_) => {}
-                        Err(e) => {
-                            error!("error in send marcket change in bitfinex {:?}", e);
-                        }
-                    }
-                }
+                self.send_spread(current_best_bid, current_best_ask);
             }
 
             // self.log_order_book();
@@ -207,4 +331,19 @@ impl BitfinexEventHandler for BitfinexEventHandlerImpl {
     fn on_error(&mut self, message: Error) {
         error!("{:?}", message);
     }
+
+    fn on_disconnected(&mut self) {
+        warn!("bitfinex socket disconnected, drop the order book");
+        // channels and sequence numbers start over on the new connection
+        self.order_book = None;
+        self.book_channel = None;
+        self.sequence = 0;
+        self.resyncing = false;
+        self.send_cex_event(CexEvent::Disconnected(CexExchange::BITFINEX));
+    }
+
+    fn on_reconnected(&mut self) {
+        info!("bitfinex socket reconnected");
+        self.send_cex_event(CexEvent::Connected(CexExchange::BITFINEX));
+    }
 }
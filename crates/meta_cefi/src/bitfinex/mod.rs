@@ -2,6 +2,7 @@ pub mod account;
 pub mod api;
 pub mod auth;
 pub mod book;
+pub mod cex_client;
 pub mod client;
 pub mod common;
 pub mod errors;
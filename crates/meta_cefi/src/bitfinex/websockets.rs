@@ -26,6 +26,7 @@ use url::Url;
 
 pub static INFO: &str = "info";
 pub static SUBSCRIBED: &str = "subscribed";
+pub static UNSUBSCRIBED: &str = "unsubscribed";
 pub static AUTH: &str = "auth";
 pub static CONF: &str = "conf";
 pub static CHECKSUM: &str = "cs";
@@ -41,6 +42,10 @@ pub trait BitfinexEventHandler {
     fn on_checksum(&mut self, event: i64);
     fn on_data_event(&mut self, event: DataEvent);
     fn on_error(&mut self, message: Error);
+    /// the socket is lost, the state built from its stream is stale until `on_reconnected`
+    fn on_disconnected(&mut self);
+    /// the socket is back, authenticated and subscribed again
+    fn on_reconnected(&mut self);
     fn as_any(&self) -> &dyn std::any::Any;
 }
 
@@ -2,7 +2,7 @@ use super::{
     events::{DataEvent, NotificationEvent},
     websockets::{
         BitfinexEventHandler, EventType, AUTH, CONF, DEAD_MAN_SWITCH_FLAG, INFO, SUBSCRIBED,
-        WEBSOCKET_URL,
+        UNSUBSCRIBED, WEBSOCKET_URL,
     },
 };
 use crate::{
@@ -11,7 +11,10 @@ use crate::{
         common::{CONF_FLAG_SEQ_ALL, CONF_OB_CHECKSUM},
         errors::*,
         orders::OrderType,
-    }, WsBackendSenderAsync, WsMessage,
+    },
+    cefi_service::AccessKey,
+    reconnect::{connect_with_backoff, Backoff, PING_INTERVAL, STALE_FEED_TIMEOUT},
+    WsBackendSenderAsync, WsMessage,
 };
 use futures_util::{SinkExt, TryStreamExt};
 use meta_util::time::get_current_ts;
@@ -21,9 +24,10 @@ use std::sync::Arc;
 use tokio::{
     net::TcpStream,
     sync::{
-        mpsc::{channel, error::TryRecvError, Receiver},
+        mpsc::{channel, Receiver},
         RwLock,
     },
+    time::Instant,
 };
 use tokio_tungstenite::{
     connect_async,
@@ -33,6 +37,7 @@ use tokio_tungstenite::{
 use tracing::{debug, error, info, warn};
 use url::Url;
 
+#[derive(Clone)]
 pub struct BitfinexWebSocketsAsync {
     sender: WsBackendSenderAsync, // send request to backend
     pub event_handler: Option<Arc<RwLock<Box<dyn BitfinexEventHandler + Send + Sync>>>>,
@@ -113,24 +118,10 @@ impl BitfinexWebSocketsAsync {
     where
         S: AsRef<str>,
     {
-        let nonce = auth::generate_nonce()?;
-        let auth_payload = format!("AUTH{}", nonce);
-        let signature =
-            auth::sign_payload(api_secret.as_ref().as_bytes(), auth_payload.as_bytes())?;
-
-        let msg = json!({
-            "event": "auth",
-            "apiKey": api_key.as_ref(),
-            "authSig": signature,
-            "authNonce": nonce,
-            "authPayload": auth_payload,
-            "dms": if dms {Some(DEAD_MAN_SWITCH_FLAG)} else {None},
-            "filters": filters,
-        });
+        let msg = get_auth_message(api_key, api_secret, dms, filters)
+            .map_err(|e| crate::bitfinex::errors::Error::from(e.to_string()))?;
 
-        if let Err(error_msg) =
-            self.sender.send(crate::MessageChannel::Trade, &msg.to_string()).await
-        {
+        if let Err(error_msg) = self.sender.send(crate::MessageChannel::Trade, &msg).await {
             error!("auth error: {:?}", error_msg);
         }
 
@@ -252,6 +243,17 @@ impl BitfinexWebSocketsAsync {
         }
     }
 
+    /// unsubscribe a channel, its events may still arrive until bitfinex confirms it
+    pub async fn unsubscribe(&mut self, chan_id: i32) {
+        let msg = json!({"event": "unsubscribe", "chanId": chan_id});
+
+        if let Err(error_msg) =
+            self.sender.send(crate::MessageChannel::Trade, &msg.to_string()).await
+        {
+            error!("unsubscribe error: {:?}", error_msg);
+        }
+    }
+
     pub async fn subscribe_raw_books<S>(&mut self, symbol: S, et: EventType)
     where
         S: Into<String>,
@@ -285,10 +287,48 @@ impl BitfinexWebSocketsAsync {
     }
 }
 
+/// auth message signed with a fresh nonce, an auth message can not be sent twice
+pub fn get_auth_message<S>(
+    api_key: S,
+    api_secret: S,
+    dms: bool,
+    filters: &[&str],
+) -> anyhow::Result<String>
+where
+    S: AsRef<str>,
+{
+    let nonce = auth::generate_nonce().map_err(|e| anyhow::anyhow!("{}", e))?;
+    let auth_payload = format!("AUTH{}", nonce);
+    let signature = auth::sign_payload(api_secret.as_ref().as_bytes(), auth_payload.as_bytes())
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let msg = json!({
+        "event": "auth",
+        "apiKey": api_key.as_ref(),
+        "authSig": signature,
+        "authNonce": nonce,
+        "authPayload": auth_payload,
+        "dms": if dms {Some(DEAD_MAN_SWITCH_FLAG)} else {None},
+        "filters": filters,
+    });
+    Ok(msg.to_string())
+}
+
+/// # Description
+/// Owns the socket: writes the requests of `BitfinexWebSocketsAsync` and passes what is read to
+/// the event handler.
+///
+/// A socket closed, failing or silent for `STALE_FEED_TIMEOUT` is reconnected with exponential
+/// backoff; the auth, the conf and the subscriptions sent so far are replayed on the new socket.
+/// The handler is told through `on_disconnected` and `on_reconnected`
 pub struct BitfinexSocketBackhandAsync {
     rx: Receiver<WsMessage>,
     pub socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
     event_handler: Option<Arc<RwLock<Box<dyn BitfinexEventHandler + Send + Sync>>>>,
+    access_key: Option<AccessKey>, // authenticates the new socket of a reconnection
+    conf: Option<String>,
+    subscriptions: Vec<String>, // subscribe messages sent
+    backoff: Backoff,
 }
 
 impl BitfinexSocketBackhandAsync {
@@ -297,88 +337,208 @@ impl BitfinexSocketBackhandAsync {
         rx: Receiver<WsMessage>,
         event_handler: Option<Arc<RwLock<Box<dyn BitfinexEventHandler + Send + Sync>>>>,
     ) -> Self {
-        Self { rx, socket, event_handler }
+        Self {
+            rx,
+            socket,
+            event_handler,
+            access_key: None,
+            conf: None,
+            subscriptions: Vec::new(),
+            backoff: Backoff::default(),
+        }
+    }
+
+    /// authenticate the socket again after a reconnection, without dead man switch nor filters
+    pub fn with_auth(mut self, access_key: AccessKey) -> Self {
+        self.access_key = Some(access_key);
+        self
     }
 
     pub async fn event_loop(&mut self) -> anyhow::Result<()> {
+        let mut ping = tokio::time::interval(PING_INTERVAL);
+        let mut last_message = Instant::now();
         loop {
-            loop {
-                match self.rx.try_recv() {
-                    Ok(msg) => match msg {
-                        WsMessage::Text(_, text) => {
-                            let time = get_current_ts().as_millis();
-                            info!("socket write message {:?}, time: {:?}", text, time);
-                            let ret = self.socket.send(Message::Text(text)).await;
-                            match ret {
-                                Err(e) => {
-                                    error!("error in socket write {:?}", e);
-                                }
-                                Ok(()) => {}
-                            }
-                        }
-                        WsMessage::Close => {
-                            error!("socket close");
-                            return self.socket.close(None).await.map_err(|e| e.into());
-                        }
-                    },
-                    Err(TryRecvError::Disconnected) => {
+            let connected = tokio::select! {
+                msg = self.rx.recv() => match msg {
+                    Some(WsMessage::Text(_, text)) => self.write_text(text).await,
+                    Some(WsMessage::Close) => {
+                        error!("socket close");
+                        return self.socket.close(None).await.map_err(|e| e.into());
+                    }
+                    None => {
                         error!("disconnected from sender");
-                        break;
+                        return self.socket.close(None).await.map_err(|e| e.into());
+                    }
+                },
+                message_ret = self.socket.try_next() => match message_ret {
+                    Ok(Some(message)) => {
+                        last_message = Instant::now();
+                        self.on_message(message).await
+                    }
+                    Ok(None) => {
+                        error!("bitfinex socket closed");
+                        false
+                    }
+                    Err(e) => {
+                        error!("error in read message {:?}", e);
+                        false
                     }
-                    Err(TryRecvError::Empty) => {
-                        break;
+                },
+                _ = ping.tick() => {
+                    if last_message.elapsed() > STALE_FEED_TIMEOUT {
+                        error!("bitfinex socket stale since {:?}", last_message.elapsed());
+                        false
+                    } else {
+                        // alive for a ping interval at least
+                        self.backoff.reset();
+                        match self.socket.send(Message::Ping(vec![])).await {
+                            Ok(()) => true,
+                            Err(e) => {
+                                error!("error in socket ping {:?}", e);
+                                false
+                            }
+                        }
                     }
                 }
+            };
+            if !connected {
+                self.reconnect().await;
+                last_message = Instant::now();
             }
+        }
+    }
 
-            let message_ret = self.socket.try_next().await;
-
-            match message_ret {
-                Ok(None) => continue,
-                Ok(Some(message)) => match message {
-                    Message::Text(text) => {
-                        // println!("got msg: {:?}", text);
-                        if let Some(ref mut h) = self.event_handler {
-                            let mut _g = h.write().await;
-                            if text.contains(INFO) {
-                                let event: NotificationEvent = from_str(&text)?;
-                                _g.on_connect(event);
-                            } else if text.contains(SUBSCRIBED) {
-                                let event: NotificationEvent = from_str(&text)?;
-                                _g.on_subscribed(event);
-                            } else if text.contains(AUTH) {
-                                let event: NotificationEvent = from_str(&text)?;
-                                _g.on_auth(event);
-                            } else if text.contains(CONF) {
-                                info!("got conf msg: {:?}", text);
-                            } else {
-                                debug!("receive raw event text: {:?}", text);
-                                let event_ret = from_str::<DataEvent>(&text);
-                                match event_ret {
-                                    Ok(event) => {
-                                        // println!("parsed event: {:?}", event);
-                                        _g.on_data_event(event);
-                                    }
-                                    Err(e) => {
-                                        warn!("err {:?}", e);
-                                    }
-                                }
+    /// false if the socket failed
+    async fn write_text(&mut self, text: String) -> bool {
+        self.record(&text);
+        let time = get_current_ts().as_millis();
+        info!("socket write message {:?}, time: {:?}", text, time);
+        match self.socket.send(Message::Text(text)).await {
+            Ok(()) => true,
+            Err(e) => {
+                error!("error in socket write {:?}", e);
+                false
+            }
+        }
+    }
+
+    /// keep the conf and the subscriptions to replay them on reconnection
+    fn record(&mut self, text: &str) {
+        let Ok(value) = from_str::<serde_json::Value>(text) else {
+            return;
+        };
+        match value.get("event").and_then(|e| e.as_str()) {
+            Some("conf") => self.conf = Some(text.to_string()),
+            Some("subscribe") => {
+                if !self.subscriptions.iter().any(|s| s == text) {
+                    self.subscriptions.push(text.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn reconnect(&mut self) {
+        if let Some(ref h) = self.event_handler {
+            h.write().await.on_disconnected();
+        }
+        loop {
+            self.socket = connect_with_backoff("bitfinex socket", &mut self.backoff, || {
+                BitfinexWebSocketsAsync::connect_async(WEBSOCKET_URL)
+            })
+            .await;
+            match self.replay().await {
+                Ok(()) => break,
+                Err(e) => error!("error in replay bitfinex socket messages {:?}", e),
+            }
+        }
+        if let Some(ref h) = self.event_handler {
+            h.write().await.on_reconnected();
+        }
+    }
+
+    async fn replay(&mut self) -> anyhow::Result<()> {
+        let mut messages = Vec::new();
+        if let Some(ref ak) = self.access_key {
+            messages.push(get_auth_message(
+                ak.api_key.as_str(),
+                ak.api_secret.as_str(),
+                false,
+                &[],
+            )?);
+        }
+        messages.extend(self.conf.iter().cloned());
+        messages.extend(self.subscriptions.iter().cloned());
+        for text in messages {
+            info!("socket replay message {:?}", text);
+            self.socket.send(Message::Text(text)).await?;
+        }
+        Ok(())
+    }
+
+    /// false if the socket is closed
+    /// false if the socket failed, messages that do not parse are logged and skipped
+    async fn on_message(&mut self, message: Message) -> bool {
+        match message {
+            Message::Text(text) => {
+                // println!("got msg: {:?}", text);
+                if let Some(ref mut h) = self.event_handler {
+                    let mut _g = h.write().await;
+                    if text.contains(INFO) {
+                        if let Some(event) = parse_notification(&text) {
+                            _g.on_connect(event);
+                        }
+                    } else if text.contains(UNSUBSCRIBED) {
+                        info!("got unsubscribed msg: {:?}", text);
+                    } else if text.contains(SUBSCRIBED) {
+                        if let Some(event) = parse_notification(&text) {
+                            _g.on_subscribed(event);
+                        }
+                    } else if text.contains(AUTH) {
+                        if let Some(event) = parse_notification(&text) {
+                            _g.on_auth(event);
+                        }
+                    } else if text.contains(CONF) {
+                        info!("got conf msg: {:?}", text);
+                    } else {
+                        debug!("receive raw event text: {:?}", text);
+                        let event_ret = from_str::<DataEvent>(&text);
+                        match event_ret {
+                            Ok(event) => {
+                                // println!("parsed event: {:?}", event);
+                                _g.on_data_event(event);
+                            }
+                            Err(e) => {
+                                warn!("err {:?}", e);
                             }
                         }
                     }
-                    Message::Binary(_) | Message::Pong(_) => {}
-                    Message::Ping(_) => {
-                        self.socket.send(Message::Pong(vec![])).await?;
-                    }
-                    Message::Close(e) => {
-                        error!("Disconnected {:?}", e);
-                    }
-                    _ => {}
-                },
-                Err(e) => {
-                    error!("error in read message {:?}", e)
                 }
             }
+            Message::Binary(_) | Message::Pong(_) => {}
+            Message::Ping(_) => {
+                if let Err(e) = self.socket.send(Message::Pong(vec![])).await {
+                    error!("error in socket pong {:?}", e);
+                    return false;
+                }
+            }
+            Message::Close(e) => {
+                error!("Disconnected {:?}", e);
+                return false;
+            }
+            _ => {}
+        }
+        true
+    }
+}
+
+/// the notification event of `text`, none if it does not parse
+fn parse_notification(text: &str) -> Option<NotificationEvent> {
+    match from_str(text) {
+        Ok(event) => Some(event),
+        Err(e) => {
+            warn!("error in parse bitfinex notification {:?}, {:?}", e, text);
+            None
         }
     }
 }
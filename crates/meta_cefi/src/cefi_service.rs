@@ -1,17 +1,8 @@
 use crate::{
-    model::CexEvent,
-    binance::{
-        handler::BinanceEventHandlerImpl, util::get_binance_symbol,
-        websockets_tokio::BinanceWebSocketClient,
-    },
-    bitfinex::{
-        book::TradingOrderBookLevel,
-        common::*,
-        handler::{BitfinexEventHandlerImpl},
-        websockets::EventType,
-        websockets_tokio::BitfinexWebSocketsAsync,
-    },
-    get_cex_pair,
+    binance::cex_client::BinanceCexClient,
+    bitfinex::{book::TradingOrderBookLevel, cex_client::BitfinexCexClient},
+    cex_client::CexClient,
+    model::{CexBalance, CexEvent, CexOrder},
 };
 use meta_address::enums::Asset;
 use meta_common::{
@@ -21,11 +12,7 @@ use meta_common::{
 use meta_util::time::get_current_ts;
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use std::{
-    collections::BTreeMap,
-    sync::{mpsc::SyncSender, Arc},
-};
-use tokio::sync::RwLock as TokioRwLock;
+use std::{collections::BTreeMap, sync::mpsc::SyncSender};
 extern crate core_affinity;
 use core_affinity::CoreId;
 use lazy_static::lazy_static;
@@ -64,8 +51,7 @@ pub struct CefiService {
     config: Option<CexConfig>,
     sender_cex_event: Option<SyncSender<CexEvent>>,
     sender_market_change: Option<SyncSender<MarcketChange>>,
-    bitfinex_sockets: BTreeMap<String, Arc<TokioRwLock<BitfinexWebSocketsAsync>>>, // (pair, (socket))
-    binance_sockets: BTreeMap<String, Arc<TokioRwLock<BinanceWebSocketClient>>>, // (pair, (socket))
+    clients: BTreeMap<CexExchange, Box<dyn CexClient>>, // (venue, client)
 }
 
 unsafe impl Send for CefiService {}
@@ -77,75 +63,50 @@ impl CefiService {
         sender_market_change: Option<SyncSender<MarcketChange>>,
         sender_cex_event: Option<SyncSender<CexEvent>>,
     ) -> Self {
-        Self {
-            config,
-            sender_market_change,
-            sender_cex_event,
-            bitfinex_sockets: BTreeMap::new(),
-            binance_sockets: BTreeMap::new(),
-        }
+        Self { config, sender_market_change, sender_cex_event, clients: BTreeMap::new() }
     }
 
-    pub async fn connect_pair(&mut self, cex: CexExchange, base: Asset, quote: Asset) {
-        let pair = get_pair(base, quote);
-        match cex {
-            CexExchange::BITFINEX => {
-                let ak = self.config.as_ref().unwrap().keys.as_ref().unwrap().get(&cex).unwrap();
-                if !self.bitfinex_sockets.contains_key(&pair) {
-                    let event_handler = BitfinexEventHandlerImpl::new(
-                        self.sender_market_change.clone(),
-                        self.sender_cex_event.clone(),
-                    );
-
-                    let (mut ws_client, mut socket_backend) =
-                        BitfinexWebSocketsAsync::new(Box::new(event_handler)).await;
-
-                    {
-                        tokio::spawn(async move {
-                            let _ = socket_backend.event_loop().await;
-                        });
-                    }
-
-                    let _ = ws_client
-                        .auth(ak.api_key.to_string(), ak.api_secret.to_string(), false, &[])
-                        .await;
-                    ws_client.conf().await;
-                    ws_client
-                        .subscribe_books(
-                            get_bitfinex_trade_symbol(base, quote),
-                            EventType::Trading,
-                            P0,
-                            "F0",
-                            100,
-                        )
-                        .await;
-
-                    self.bitfinex_sockets
-                        .insert(pair.to_owned(), Arc::new(TokioRwLock::new(ws_client)));
-                }
-            }
-            CexExchange::BINANCE => {
-                if !self.binance_sockets.contains_key(&pair) {
-                    let handler_reader = BinanceEventHandlerImpl::new(self.sender_cex_event.clone(), self.sender_market_change.clone());
-
-                    let credential =
-                        self.config.as_ref().unwrap().keys.as_ref().unwrap().get(&cex).cloned();
+    /// register the client of a venue, replacing the one it may have
+    pub fn add_client(&mut self, client: Box<dyn CexClient>) {
+        self.clients.insert(client.venue(), client);
+    }
 
-                    let (mut ws_client, mut socket_backend) =
-                        BinanceWebSocketClient::new(credential, Box::new(handler_reader)).await;
+    fn get_client(&self, cex: CexExchange) -> anyhow::Result<&dyn CexClient> {
+        self.clients
+            .get(&cex)
+            .map(|client| client.as_ref())
+            .ok_or_else(|| anyhow::anyhow!("no client of {:?}", cex))
+    }
 
-                    {
-                        tokio::spawn(async move {
-                            let _ = socket_backend.event_loop().await;
-                        });
-                    }
+    fn get_client_mut(&mut self, cex: CexExchange) -> anyhow::Result<&mut Box<dyn CexClient>> {
+        self.clients.get_mut(&cex).ok_or_else(|| anyhow::anyhow!("no client of {:?}", cex))
+    }
 
-                    ws_client.subscribe_books(get_binance_symbol(base, quote)).await;
-                    self.binance_sockets
-                        .insert(pair.to_owned(), Arc::new(TokioRwLock::new(ws_client)));
-                }
+    /// client of a venue with the access key of the config, created on first use
+    fn get_or_create_client(&mut self, cex: CexExchange) -> &mut Box<dyn CexClient> {
+        let access_key =
+            self.config.as_ref().and_then(|c| c.keys.as_ref()).and_then(|k| k.get(&cex)).cloned();
+        let (sender_market_change, sender_cex_event) =
+            (self.sender_market_change.clone(), self.sender_cex_event.clone());
+        self.clients.entry(cex).or_insert_with(|| match cex {
+            CexExchange::BITFINEX => {
+                Box::new(BitfinexCexClient::new(access_key, sender_market_change, sender_cex_event))
             }
-        }
+            CexExchange::BINANCE => {
+                Box::new(BinanceCexClient::new(access_key, sender_market_change, sender_cex_event))
+            }
+        })
+    }
+
+    pub async fn connect_pair(
+        &mut self,
+        cex: CexExchange,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<()> {
+        let client = self.get_or_create_client(cex);
+        client.connect(base, quote).await?;
+        client.subscribe_books(base, quote).await
     }
 
     pub async fn submit_order(
@@ -155,31 +116,47 @@ impl CefiService {
         base: Asset,
         quote: Asset,
         amount: Decimal,
-    ) {
+    ) -> anyhow::Result<()> {
         let pair = get_pair(base, quote);
         let time = get_current_ts().as_millis();
         info!(
             "start submit cex order cex: {:?}, pair: {:?}, amount: {:?}, ts: {:?}",
             cex, pair, amount, time
         );
-        match cex {
-            CexExchange::BITFINEX => {
-                let symbol = get_cex_pair(cex, base, quote);
-                if self.bitfinex_sockets.contains_key(&pair) {
-                    let socket_reader = self.bitfinex_sockets.get(&pair).unwrap();
-                    let mut _g = socket_reader.write().await;
-                    (_g).submit_order(client_order_id, symbol, amount).await;
-                }
-            }
-            CexExchange::BINANCE => {
-                let symbol = get_cex_pair(cex, base, quote);
-                if self.binance_sockets.contains_key(&pair) {
-                    let socket_reader = self.binance_sockets.get(&pair).unwrap();
-                    let mut _g = socket_reader.write().await;
-                    (_g).submit_order(client_order_id, symbol, amount).await;
-                }
-            }
-        }
+        self.get_client_mut(cex)?.submit_order(client_order_id, base, quote, amount).await
+    }
+
+    pub async fn cancel_order(
+        &self,
+        client_order_id: u128,
+        cex: CexExchange,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<()> {
+        self.get_client(cex)?.cancel_order(client_order_id, base, quote).await
+    }
+
+    pub async fn get_order(
+        &self,
+        client_order_id: u128,
+        cex: CexExchange,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<CexOrder> {
+        self.get_client(cex)?.get_order(client_order_id, base, quote).await
+    }
+
+    pub async fn get_balances(&self, cex: CexExchange) -> anyhow::Result<Vec<CexBalance>> {
+        self.get_client(cex)?.get_balances().await
+    }
+
+    pub async fn get_order_book(
+        &self,
+        cex: CexExchange,
+        base: Asset,
+        quote: Asset,
+    ) -> Option<OrderBook> {
+        self.get_client(cex).ok()?.get_order_book(base, quote).await
     }
 
     pub async fn get_spread(
@@ -188,44 +165,30 @@ impl CefiService {
         base: Asset,
         quote: Asset,
     ) -> Option<CurrentSpread> {
-        let pair = get_pair(base, quote);
-        let mut best_ask = Decimal::default();
-        let mut best_bid = Decimal::default();
-        match cex {
-            CexExchange::BITFINEX => {
-                if self.bitfinex_sockets.contains_key(&pair) {
-                    let web_socket = self.bitfinex_sockets.get(&pair);
-                    if let Some(socket_reader) = web_socket {
-                        let _g = socket_reader.read().await;
-                        if let Some(ref handler) = (_g).event_handler {
-                            let _g_handler = handler.read().await;
-                            let btf_handler =
-                                (_g_handler.as_any()).downcast_ref::<BitfinexEventHandlerImpl>();
-
-                            if let Some(btf) = btf_handler {
-                                if let Some(ref ob) = btf.order_book {
-                                    if let Some((_, ask_level)) = ob.asks.first_key_value() {
-                                        best_ask = ask_level.price;
-                                    }
-                                    if let Some((_, bid_level)) = ob.bids.last_key_value() {
-                                        best_bid = bid_level.price;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            CexExchange::BINANCE => unimplemented!(),
-        }
-        if best_ask.is_zero() || best_bid.is_zero() {
-            None
-        } else {
-            Some(CurrentSpread { best_bid, best_ask })
+        self.get_client(cex).ok()?.get_spread(base, quote).await
+    }
+
+    /// shut the clients of all venues down
+    pub async fn shutdown(&mut self) {
+        for client in self.clients.values_mut() {
+            client.shutdown().await;
         }
     }
 }
 
+/// best bid and ask of a book, none if either side is empty
+pub fn get_book_spread(ob: &OrderBook) -> Option<CurrentSpread> {
+    let (best_bid, _) = ob.bids.last_key_value()?;
+    let (best_ask, _) = ob.asks.first_key_value()?;
+    Some(CurrentSpread {
+        best_bid: *best_bid,
+        best_ask: *best_ask,
+        best_bid_v3_fee: None,
+        best_ask_v3_fee: None,
+        depth: None,
+    })
+}
+
 pub fn get_pair(base: Asset, quote: Asset) -> String {
     format!("{}_{}", base, quote)
 }
@@ -337,6 +300,23 @@ mod test_cefi {
         }
     }
 
+    #[test]
+    fn test_get_book_spread() {
+        let data_str: &'static str = r#"[1,[[1000.1,7,1.1],[1003.4,1,-2.1],[1002.4,2,-3.1]],1]"#;
+        let event: DataEvent = from_str(data_str).unwrap();
+        if let DataEvent::BookTradingSnapshotEvent(_channel, book_snapshot, _seq) = event {
+            let mut ob = construct_order_book(book_snapshot);
+            let spread = get_book_spread(&ob).unwrap();
+            assert_eq!(spread.best_bid, to_decimal(1000.1));
+            assert_eq!(spread.best_ask, to_decimal(1002.4));
+
+            ob.bids.clear();
+            assert!(get_book_spread(&ob).is_none());
+        } else {
+            panic!("test data deser failed");
+        }
+    }
+
     #[test]
     fn should_update_order_book() {
         let data_str: &'static str = r#"[1,[[1000.1,7,1.1],[1003.4,1,-2.1],[1004.4,4,-5.1],[1000.2,5,2.1],[1002.4,2,-3.1],[999.2,3,3.1]],1]"#;
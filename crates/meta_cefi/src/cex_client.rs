@@ -0,0 +1,63 @@
+use crate::{
+    cefi_service::{get_book_spread, OrderBook},
+    model::{CexBalance, CexOrder},
+};
+use async_trait::async_trait;
+use meta_address::enums::Asset;
+use meta_common::{enums::CexExchange, models::CurrentSpread};
+use rust_decimal::Decimal;
+
+/// # Description
+/// A cex venue: the websockets of the pairs it is connected to, which stream the order book and
+/// execute orders, and the rest api queried for orders and balances.
+///
+/// `CefiService` holds one client per venue and knows nothing else of it; a new venue only
+/// needs an implementation of this trait
+#[async_trait]
+pub trait CexClient: Send + Sync {
+    fn venue(&self) -> CexExchange;
+
+    /// open (and authenticate) the websocket of a pair, no-op if it is already open
+    async fn connect(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()>;
+
+    /// subscribe the order book of a connected pair
+    async fn subscribe_books(&mut self, base: Asset, quote: Asset) -> anyhow::Result<()>;
+
+    /// submit a market order of `amount` base through the websocket of a connected pair;
+    /// positive `amount` buys base, negative sells base
+    async fn submit_order(
+        &mut self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+        amount: Decimal,
+    ) -> anyhow::Result<()>;
+
+    async fn cancel_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<()>;
+
+    async fn get_order(
+        &self,
+        client_order_id: u128,
+        base: Asset,
+        quote: Asset,
+    ) -> anyhow::Result<CexOrder>;
+
+    /// balances of the spot wallet
+    async fn get_balances(&self) -> anyhow::Result<Vec<CexBalance>>;
+
+    /// snapshot of the local order book of a subscribed pair
+    async fn get_order_book(&self, base: Asset, quote: Asset) -> Option<OrderBook>;
+
+    /// best bid and ask of a subscribed pair, none until both sides of the book are known
+    async fn get_spread(&self, base: Asset, quote: Asset) -> Option<CurrentSpread> {
+        self.get_order_book(base, quote).await.as_ref().and_then(get_book_spread)
+    }
+
+    /// release what the venue keeps for the client, before exit
+    async fn shutdown(&mut self) {}
+}
@@ -4,8 +4,10 @@ use meta_common::enums::CexExchange;
 pub mod binance;
 pub mod bitfinex;
 pub mod cefi_service;
+pub mod cex_client;
 pub mod util;
 pub mod model;
+pub mod reconnect;
 
 use bitfinex::errors::*;
 use std::sync::mpsc::Sender;
@@ -1,3 +1,4 @@
+use meta_common::enums::CexExchange;
 use rust_decimal::Decimal;
 
 use crate::bitfinex::wallet::WalletSnapshot;
@@ -17,4 +18,27 @@ pub struct TradeExecutionInfo {
 pub enum CexEvent {
     TradeExecution(TradeExecutionInfo),
     Balance(WalletSnapshot),
+    Connected(CexExchange), // (re)connected, subscriptions and auth replayed
+    Disconnected(CexExchange), // the feed is lost until the next `Connected`
+    Fatal(CexExchange, String), // the feed is lost for good, with the reason
+}
+
+/// an order as reported by the rest api of a cex
+#[derive(Debug, Clone)]
+pub struct CexOrder {
+    pub client_order_id: u128,
+    pub order_id: u64,
+    pub symbol: String,
+    pub amount: Decimal,             // Positive means buy, negative means sell
+    pub exec_amount: Decimal,        // Amount executed so far, signed as the amount
+    pub exec_price: Option<Decimal>, // Average execution price, none if nothing executed
+    pub status: String,
+}
+
+/// balance of a currency in the spot (exchange) wallet of a cex
+#[derive(Debug, Clone)]
+pub struct CexBalance {
+    pub currency: String,
+    pub total: Decimal,
+    pub available: Decimal,
 }
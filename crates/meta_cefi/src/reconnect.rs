@@ -0,0 +1,80 @@
+use std::{fmt::Debug, future::Future, time::Duration};
+use tracing::{info, warn};
+
+/// interval of the pings sent to keep a websocket alive and find out it is dead
+pub const PING_INTERVAL: Duration = Duration::from_secs(15);
+
+/// a websocket with nothing received for this long, pongs and heartbeats included, is stale
+pub const STALE_FEED_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// delay before the first reconnection attempt
+pub const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// upper bound of the delay between two reconnection attempts
+pub const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// exponential backoff of reconnection attempts
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(INITIAL_RECONNECT_DELAY, MAX_RECONNECT_DELAY)
+    }
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self { initial, max, current: initial }
+    }
+
+    /// delay before the next attempt, doubled after each call up to `max`
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = std::cmp::min(self.current * 2, self.max);
+        delay
+    }
+
+    /// back to the initial delay, once a connection proved alive
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+/// # Description
+/// Retries `connect` until it succeeds, sleeping the delays of `backoff` between attempts
+pub async fn connect_with_backoff<T, E, F, Fut>(name: &str, backoff: &mut Backoff, connect: F) -> T
+where
+    E: Debug,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    loop {
+        let delay = backoff.next_delay();
+        info!("reconnect {} in {:?}", name, delay);
+        tokio::time::sleep(delay).await;
+        match connect().await {
+            Ok(connection) => return connection,
+            Err(e) => warn!("error in reconnect {} {:?}", name, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+        let delays: Vec<u64> = (0..5).map(|_| backoff.next_delay().as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+    }
+}
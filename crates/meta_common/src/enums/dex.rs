@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, EnumVariantNames};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PoolVariant {
     UniswapV2,
     UniswapV3,
@@ -93,12 +93,24 @@ pub enum ContractType {
     #[strum(ascii_case_insensitive, serialize = "UniV3SwapRouterV2")]
     UniV3SwapRouterV2,
 
+    #[strum(ascii_case_insensitive, serialize = "UniV3SwapRouter02")]
+    UniV3SwapRouter02,
+
+    #[strum(ascii_case_insensitive, serialize = "UniversalRouter")]
+    UniversalRouter,
+
+    #[strum(ascii_case_insensitive, serialize = "Permit2")]
+    Permit2,
+
     #[strum(ascii_case_insensitive, serialize = "UniV3Nft")]
     UniV3Nft,
 
     #[strum(ascii_case_insensitive, serialize = "UniV3QuoterV2")]
     UniV3QuoterV2,
 
+    #[strum(ascii_case_insensitive, serialize = "SyncSwapStablePoolFactory")]
+    SyncSwapStablePoolFactory,
+
     #[strum(ascii_case_insensitive, serialize = "IzumiLiquidityManager")]
     IzumiLiquidityManager,
 
@@ -107,6 +119,9 @@ pub enum ContractType {
 
     #[strum(ascii_case_insensitive, serialize = "IzumiSwapRouter")]
     IzumiSwapRouter,
+
+    #[strum(ascii_case_insensitive, serialize = "IzumiQuoter")]
+    IzumiQuoter,
 }
 
 impl Into<String> for ContractType {
@@ -1,9 +1,57 @@
 use rust_decimal::Decimal;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CurrentSpread {
     pub best_bid: Decimal,
     pub best_ask: Decimal,
+    /// uniswap v3 fee tier quoting the best bid; dex only
+    pub best_bid_v3_fee: Option<u32>,
+    /// uniswap v3 fee tier quoting the best ask; dex only
+    pub best_ask_v3_fee: Option<u32>,
+    /// executable prices at several sizes; `None` if only the best prices are known
+    pub depth: Option<DepthLadder>,
+}
+
+/// average price of trading `amount` of base at once
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceLevel {
+    pub amount: Decimal,
+    pub price: Decimal,
+    /// uniswap v3 fee tier quoting the price; dex only
+    pub v3_fee: Option<u32>,
+}
+
+/// executable prices at several sizes, each side sorted by ascending amount. A size that can not
+/// be filled has no level
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepthLadder {
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+impl DepthLadder {
+    /// bid level for selling exactly `amount` of base
+    pub fn bid_at(&self, amount: Decimal) -> Option<&PriceLevel> {
+        self.bids.iter().find(|level| level.amount.eq(&amount))
+    }
+
+    /// ask level for buying exactly `amount` of base
+    pub fn ask_at(&self, amount: Decimal) -> Option<&PriceLevel> {
+        self.asks.iter().find(|level| level.amount.eq(&amount))
+    }
+
+    /// spread of trading `amount` of base, carrying the whole ladder; `None` if either side can
+    /// not fill `amount`
+    pub fn spread_at(&self, amount: Decimal) -> Option<CurrentSpread> {
+        let (bid, ask) = (self.bid_at(amount)?, self.ask_at(amount)?);
+        Some(CurrentSpread {
+            best_bid: bid.price,
+            best_ask: ask.price,
+            best_bid_v3_fee: bid.v3_fee,
+            best_ask_v3_fee: ask.v3_fee,
+            depth: Some(self.clone()),
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
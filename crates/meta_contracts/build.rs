@@ -13,13 +13,30 @@ fn main() {
     bindgen("UniswapV3Factory", "./static/abis/uniswap_v3/UniswapV3Factory.json");
     bindgen("UniswapV3Pool", "./static/abis/uniswap_v3/UniswapV3Pool.json");
     bindgen("SwapRouter", "./static/abis/uniswap_v3/SwapRouter.json");
+    bindgen("SwapRouter02", "./static/abis/uniswap_v3/SwapRouter02.json");
     bindgen("QuoterV2", "./static/abis/uniswap_v3/QuoterV2.json");
+    bindgen(
+        "NonfungiblePositionManager",
+        "./static/abis/uniswap_v3/NonfungiblePositionManager.json",
+    );
     bindgen(
         "NonfungibleTokenPositionDescriptor",
         "./static/abis/uniswap_v3/NonfungibleTokenPositionDescriptor.json",
     );
+    // universal_router
+    bindgen("UniversalRouter", "./static/abis/universal_router/UniversalRouter.json");
+    bindgen("Permit2", "./static/abis/universal_router/Permit2.json");
 
     bindgen("MuteSwitchFactory", "./static/abis/mute_switch/factory.json");
+    bindgen("MuteSwitchPair", "./static/abis/mute_switch/pair.json");
+    bindgen("MuteSwitchRouter", "./static/abis/mute_switch/router.json");
+    // izumi
+    bindgen("IzumiQuoter", "./static/abis/izumi/Quoter.json");
+    bindgen("IzumiSwap", "./static/abis/izumi/Swap.json");
+    // sync_swap
+    bindgen("SyncSwapClassicPoolFactory", "./static/abis/sync_swap/ClassicPoolFactory.json");
+    bindgen("SyncSwapClassicPool", "./static/abis/sync_swap/ClassicPool.json");
+    bindgen("SyncSwapRouter", "./static/abis/sync_swap/Router.json");
     bindgen("Migration", "./out/Migration.sol/Migration.json");
     bindgen("FlashBotsRouter", "./out/FlashBotsRouter.sol/FlashBotsRouter.json");
 }
@@ -4,10 +4,21 @@ pub mod uniswapv2factory;
 pub mod uniswapv2pair;
 pub mod uniswapv2router02;
 pub mod uniswapv3factory;
+pub mod nonfungiblepositionmanager;
 pub mod nonfungibletokenpositiondescriptor;
 pub mod quoterv2;
 pub mod swaprouter;
+pub mod swaprouter02;
 pub mod uniswapv3pool;
+pub mod universalrouter;
+pub mod permit2;
 pub mod muteswitchfactory;
+pub mod muteswitchpair;
+pub mod muteswitchrouter;
+pub mod izumiquoter;
+pub mod izumiswap;
+pub mod syncswapclassicpoolfactory;
+pub mod syncswapclassicpool;
+pub mod syncswaprouter;
 pub mod flashbotsrouter;
 pub mod migration;
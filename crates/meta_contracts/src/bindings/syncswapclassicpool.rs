@@ -0,0 +1,594 @@
+pub use sync_swap_classic_pool::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types,
+)]
+pub mod sync_swap_classic_pool {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("getAmountIn"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("getAmountIn"),
+                            inputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_tokenOut"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_amountOut"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_sender"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                            ],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_amountIn"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getAmountOut"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("getAmountOut"),
+                            inputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_tokenIn"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_amountIn"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_sender"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                            ],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_amountOut"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getReserves"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("getReserves"),
+                            inputs: ::std::vec![],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_reserve0"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::borrow::ToOwned::to_owned("_reserve1"),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(
+                                        256usize,
+                                    ),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint256"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("poolType"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("poolType"),
+                            inputs: ::std::vec![],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::string::String::new(),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(16usize),
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("uint16"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("token0"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("token0"),
+                            inputs: ::std::vec![],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::string::String::new(),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("token1"),
+                    ::std::vec![
+                        ::ethers::core::abi::ethabi::Function {
+                            name: ::std::borrow::ToOwned::to_owned("token1"),
+                            inputs: ::std::vec![],
+                            outputs: ::std::vec![
+                                ::ethers::core::abi::ethabi::Param {
+                                    name: ::std::string::String::new(),
+                                    kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                    internal_type: ::core::option::Option::Some(
+                                        ::std::borrow::ToOwned::to_owned("address"),
+                                    ),
+                                },
+                            ],
+                            constant: ::core::option::Option::None,
+                            state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                        },
+                    ],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static SYNCSWAPCLASSICPOOL_ABI: ::ethers::contract::Lazy<
+        ::ethers::core::abi::Abi,
+    > = ::ethers::contract::Lazy::new(__abi);
+    pub struct SyncSwapClassicPool<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for SyncSwapClassicPool<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for SyncSwapClassicPool<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for SyncSwapClassicPool<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for SyncSwapClassicPool<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(SyncSwapClassicPool))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> SyncSwapClassicPool<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(
+                ::ethers::contract::Contract::new(
+                    address.into(),
+                    SYNCSWAPCLASSICPOOL_ABI.clone(),
+                    client,
+                ),
+            )
+        }
+        ///Calls the contract's `getAmountIn` (0xa287c795) function
+        pub fn get_amount_in(
+            &self,
+            token_out: ::ethers::core::types::Address,
+            amount_out: ::ethers::core::types::U256,
+            sender: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([162, 135, 199, 149], (token_out, amount_out, sender))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `getAmountOut` (0xff9c8ac6) function
+        pub fn get_amount_out(
+            &self,
+            token_in: ::ethers::core::types::Address,
+            amount_in: ::ethers::core::types::U256,
+            sender: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([255, 156, 138, 198], (token_in, amount_in, sender))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `getReserves` (0x0902f1ac) function
+        pub fn get_reserves(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([9, 2, 241, 172], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `poolType` (0xb1dd61b6) function
+        pub fn pool_type(&self) -> ::ethers::contract::builders::ContractCall<M, u16> {
+            self.0
+                .method_hash([177, 221, 97, 182], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `token0` (0x0dfe1681) function
+        pub fn token_0(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::ethers::core::types::Address,
+        > {
+            self.0
+                .method_hash([13, 254, 22, 129], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `token1` (0xd21220a7) function
+        pub fn token_1(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::ethers::core::types::Address,
+        > {
+            self.0
+                .method_hash([210, 18, 32, 167], ())
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+    for SyncSwapClassicPool<M> {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `getAmountIn` function with signature `getAmountIn(address,uint256,address)` and selector `0xa287c795`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "getAmountIn", abi = "getAmountIn(address,uint256,address)")]
+    pub struct GetAmountInCall {
+        pub token_out: ::ethers::core::types::Address,
+        pub amount_out: ::ethers::core::types::U256,
+        pub sender: ::ethers::core::types::Address,
+    }
+    ///Container type for all input parameters for the `getAmountOut` function with signature `getAmountOut(address,uint256,address)` and selector `0xff9c8ac6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "getAmountOut", abi = "getAmountOut(address,uint256,address)")]
+    pub struct GetAmountOutCall {
+        pub token_in: ::ethers::core::types::Address,
+        pub amount_in: ::ethers::core::types::U256,
+        pub sender: ::ethers::core::types::Address,
+    }
+    ///Container type for all input parameters for the `getReserves` function with signature `getReserves()` and selector `0x0902f1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "getReserves", abi = "getReserves()")]
+    pub struct GetReservesCall;
+    ///Container type for all input parameters for the `poolType` function with signature `poolType()` and selector `0xb1dd61b6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "poolType", abi = "poolType()")]
+    pub struct PoolTypeCall;
+    ///Container type for all input parameters for the `token0` function with signature `token0()` and selector `0x0dfe1681`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "token0", abi = "token0()")]
+    pub struct Token0Call;
+    ///Container type for all input parameters for the `token1` function with signature `token1()` and selector `0xd21220a7`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "token1", abi = "token1()")]
+    pub struct Token1Call;
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum SyncSwapClassicPoolCalls {
+        GetAmountIn(GetAmountInCall),
+        GetAmountOut(GetAmountOutCall),
+        GetReserves(GetReservesCall),
+        PoolType(PoolTypeCall),
+        Token0(Token0Call),
+        Token1(Token1Call),
+    }
+    impl ::ethers::core::abi::AbiDecode for SyncSwapClassicPoolCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) = <GetAmountInCall as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::GetAmountIn(decoded));
+            }
+            if let Ok(decoded) = <GetAmountOutCall as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::GetAmountOut(decoded));
+            }
+            if let Ok(decoded) = <GetReservesCall as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::GetReserves(decoded));
+            }
+            if let Ok(decoded) = <PoolTypeCall as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::PoolType(decoded));
+            }
+            if let Ok(decoded) = <Token0Call as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::Token0(decoded));
+            }
+            if let Ok(decoded) = <Token1Call as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::Token1(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for SyncSwapClassicPoolCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::GetAmountIn(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::GetAmountOut(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::GetReserves(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::PoolType(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::Token0(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Token1(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for SyncSwapClassicPoolCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::GetAmountIn(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetAmountOut(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetReserves(element) => ::core::fmt::Display::fmt(element, f),
+                Self::PoolType(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Token0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Token1(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<GetAmountInCall> for SyncSwapClassicPoolCalls {
+        fn from(value: GetAmountInCall) -> Self {
+            Self::GetAmountIn(value)
+        }
+    }
+    impl ::core::convert::From<GetAmountOutCall> for SyncSwapClassicPoolCalls {
+        fn from(value: GetAmountOutCall) -> Self {
+            Self::GetAmountOut(value)
+        }
+    }
+    impl ::core::convert::From<GetReservesCall> for SyncSwapClassicPoolCalls {
+        fn from(value: GetReservesCall) -> Self {
+            Self::GetReserves(value)
+        }
+    }
+    impl ::core::convert::From<PoolTypeCall> for SyncSwapClassicPoolCalls {
+        fn from(value: PoolTypeCall) -> Self {
+            Self::PoolType(value)
+        }
+    }
+    impl ::core::convert::From<Token0Call> for SyncSwapClassicPoolCalls {
+        fn from(value: Token0Call) -> Self {
+            Self::Token0(value)
+        }
+    }
+    impl ::core::convert::From<Token1Call> for SyncSwapClassicPoolCalls {
+        fn from(value: Token1Call) -> Self {
+            Self::Token1(value)
+        }
+    }
+    ///Container type for all return fields from the `getAmountIn` function with signature `getAmountIn(address,uint256,address)` and selector `0xa287c795`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct GetAmountInReturn {
+        pub amount_in: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `getAmountOut` function with signature `getAmountOut(address,uint256,address)` and selector `0xff9c8ac6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct GetAmountOutReturn {
+        pub amount_out: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `getReserves` function with signature `getReserves()` and selector `0x0902f1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct GetReservesReturn {
+        pub reserve_0: ::ethers::core::types::U256,
+        pub reserve_1: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `poolType` function with signature `poolType()` and selector `0xb1dd61b6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct PoolTypeReturn(pub u16);
+    ///Container type for all return fields from the `token0` function with signature `token0()` and selector `0x0dfe1681`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct Token0Return(pub ::ethers::core::types::Address);
+    ///Container type for all return fields from the `token1` function with signature `token1()` and selector `0xd21220a7`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct Token1Return(pub ::ethers::core::types::Address);
+}
@@ -54,6 +54,7 @@ async fn main() {
             Decimal::from_f64(3.0).unwrap(),
             V3_FEE,
             wallet_address,
+            None,
         )
         .await;
     println!("ret {:?}", ret);
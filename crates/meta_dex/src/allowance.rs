@@ -0,0 +1,307 @@
+use crate::{error::OrderError, router::V3Router, DexService};
+use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
+use meta_address::TokenInfo;
+use meta_common::enums::{ContractType, DexExchange};
+use meta_contracts::bindings::{erc20::ERC20, permit2::Permit2};
+use meta_util::{ether::decimal_to_wei, time::get_current_ts};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+/// multiple of the required amount approved by the default `ApprovalPolicy`
+pub const DEFAULT_APPROVAL_MULTIPLE: u32 = 10;
+
+/// seconds a `Permit2` approval of the universal router lasts
+pub const DEFAULT_PERMIT2_EXPIRATION_SECS: u64 = 30 * 24 * 3_600;
+
+/// amount a router is approved to spend when its allowance is insufficient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ApprovalPolicy {
+    /// exactly the amount required
+    Exact,
+    /// a multiple of the amount required, so the next swaps need no approval
+    Multiple(u32),
+    /// the max amount, approved once for all
+    Infinite,
+}
+
+impl Default for ApprovalPolicy {
+    fn default() -> Self {
+        Self::Multiple(DEFAULT_APPROVAL_MULTIPLE)
+    }
+}
+
+impl ApprovalPolicy {
+    /// amount to approve when `required` must be spendable
+    pub fn get_approve_amount(&self, required: U256) -> U256 {
+        match self {
+            Self::Exact => required,
+            Self::Multiple(multiple) => {
+                required.saturating_mul(U256::from(*multiple).max(1.into()))
+            }
+            Self::Infinite => U256::MAX,
+        }
+    }
+}
+
+/// # Description
+/// Approvals bringing an `allowance` below the required amount to `amount`. Tokens such as USDT
+/// revert an approval of a nonzero allowance to another nonzero amount, so a nonzero allowance is
+/// reset to zero first
+pub fn get_approval_steps(allowance: U256, amount: U256) -> Vec<U256> {
+    match allowance.is_zero() {
+        true => vec![amount],
+        false => vec![U256::zero(), amount],
+    }
+}
+
+/// allowance of a spender over a token of the wallet, as last read or approved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Approval {
+    pub token: Address,
+    pub spender: Address,
+    pub allowance: U256,
+}
+
+impl<M: Middleware> DexService<M> {
+    /// router swapping on behalf of the wallet; `None` if swaps are not supported on the dex
+    pub async fn get_router_address(&self) -> Option<Address> {
+        if self.dex_contracts.v3_contracts.is_some() {
+            return self.dex_contracts.get_v3_swap_router().await.ok().map(|r| r.address());
+        }
+        match self.dex_exchange {
+            DexExchange::IZUMI => {
+                return self.get_contract_address(ContractType::IzumiSwapRouter).ok()
+            }
+            DexExchange::SyncSwap | DexExchange::MuteSwitch => {
+                return self.get_contract_address(ContractType::UniV2RouterV2).ok()
+            }
+            _ => {}
+        }
+        self.dex_contracts.get_v2_router().await.ok().map(|r| r.address())
+    }
+
+    /// # Description
+    /// Checks the allowance of the router over each token, approving under `approval_policy`
+    /// where it can not cover the amount
+    ///
+    /// # Arguments
+    /// * `requirements` - tokens with the largest amount a single swap may spend
+    pub async fn ensure_allowances(
+        &self,
+        requirements: &[(TokenInfo, Decimal)],
+    ) -> Result<(), OrderError<M>> {
+        let router = match self.get_router_address().await {
+            Some(router) => router,
+            None => {
+                warn!("no router on {:?}, skip allowance check", self.dex_exchange);
+                return Ok(());
+            }
+        };
+        let v3_router = match self.dex_contracts.v3_contracts.is_some() {
+            true => self.dex_contracts.get_v3_swap_router().await.ok(),
+            false => None,
+        };
+        for (token, amount) in requirements.iter() {
+            let required = decimal_to_wei(*amount, token.decimals.into());
+            match v3_router {
+                Some(ref v3_router) => {
+                    self.ensure_v3_router_allowance(v3_router, token.address, required).await?
+                }
+                None => self.ensure_allowance(token.address, router, required).await?,
+            }
+        }
+        Ok(())
+    }
+
+    /// # Description
+    /// Makes sure the v3 `router` may spend `required` of `token` from the wallet. The universal
+    /// router pulls tokens through `Permit2`, so the token is approved to `Permit2` and `Permit2`
+    /// approves the router
+    pub async fn ensure_v3_router_allowance(
+        &self,
+        router: &V3Router<M>,
+        token: Address,
+        required: U256,
+    ) -> Result<(), OrderError<M>> {
+        match router {
+            V3Router::UniversalRouter { router, permit2 } => {
+                self.ensure_allowance(token, permit2.address(), required).await?;
+                self.ensure_permit2_allowance(permit2, token, router.address(), required).await
+            }
+            _ => self.ensure_allowance(token, router.address(), required).await,
+        }
+    }
+
+    /// # Description
+    /// Makes sure `Permit2` lets `spender` pull `required` of `token` from the wallet, approving
+    /// under `approval_policy` for `DEFAULT_PERMIT2_EXPIRATION_SECS` if not. The cached allowance
+    /// ignores the expiration, which only a service running longer than it may reach
+    async fn ensure_permit2_allowance(
+        &self,
+        permit2: &Permit2<M>,
+        token: Address,
+        spender: Address,
+        required: U256,
+    ) -> Result<(), OrderError<M>> {
+        let key = (token, spender);
+        if self.approvals.read().await.get(&key).is_some_and(|allowance| *allowance >= required) {
+            return Ok(());
+        }
+
+        let owner = self.client.default_sender().ok_or(OrderError::SenderNotFound)?;
+        let (allowance, expiration, _) = permit2.allowance(owner, token, spender).call().await?;
+        let now = get_current_ts().as_secs();
+        if allowance >= required && expiration > now {
+            self.approvals.write().await.insert(key, allowance);
+            return Ok(());
+        }
+
+        // permit2 allowances are uint160
+        let max_amount = (U256::one() << 160) - 1;
+        let amount = self.approval_policy.get_approve_amount(required).min(max_amount);
+        info!(
+            "permit2 approve {:?} of token {:?} to {:?}, allowance {:?} below {:?} or expired at {:?}",
+            amount, token, spender, allowance, required, expiration
+        );
+        let mut call =
+            permit2.approve(token, spender, amount, now + DEFAULT_PERMIT2_EXPIRATION_SECS);
+        self.fill_gas(&mut call.tx).await?;
+        let tx = call.send().await?;
+        let receipt = tx.await.map_err(|_| OrderError::SendTxError)?;
+        if !receipt.is_some_and(|receipt| receipt.status.eq(&Some(U64::one()))) {
+            return Err(OrderError::ApproveFailed { token, spender });
+        }
+        // permit2 does not spend an allowance of max_amount, as tokens do not spend U256::MAX
+        let amount = if amount == max_amount { U256::MAX } else { amount };
+        self.approvals.write().await.insert(key, amount);
+        Ok(())
+    }
+
+    /// # Description
+    /// Makes sure `spender` may spend `required` of `token` from the wallet. The allowance is read
+    /// from the chain unless the cached one covers `required`, then approved under
+    /// `approval_policy` if it does not, see `get_approval_steps`; returns once the approval is
+    /// mined
+    pub async fn ensure_allowance(
+        &self,
+        token: Address,
+        spender: Address,
+        required: U256,
+    ) -> Result<(), OrderError<M>> {
+        let key = (token, spender);
+        if self.approvals.read().await.get(&key).is_some_and(|allowance| *allowance >= required) {
+            return Ok(());
+        }
+
+        let owner = self.client.default_sender().ok_or(OrderError::SenderNotFound)?;
+        let erc20 = ERC20::new(token, self.client.clone());
+        let call = erc20.allowance(owner, spender);
+        let allowance = call.call().await?;
+        if allowance >= required {
+            self.approvals.write().await.insert(key, allowance);
+            return Ok(());
+        }
+
+        let amount = self.approval_policy.get_approve_amount(required);
+        info!(
+            "approve {:?} of token {:?} to {:?}, allowance {:?} below {:?}",
+            amount, token, spender, allowance, required
+        );
+        for step in get_approval_steps(allowance, amount) {
+            let mut call = erc20.approve(spender, step);
+            self.fill_gas(&mut call.tx).await?;
+            let tx = call.send().await?;
+            let receipt = tx.await.map_err(|_| OrderError::SendTxError)?;
+            if !receipt.is_some_and(|receipt| receipt.status.eq(&Some(U64::one()))) {
+                return Err(OrderError::ApproveFailed { token, spender });
+            }
+        }
+        self.approvals.write().await.insert(key, amount);
+        Ok(())
+    }
+
+    /// sends the swap `tx` of `router`, approving it first to spend `amount_in` of `token_in` unless
+    /// the native token is paid
+    pub(crate) async fn send_swap_tx(
+        &self,
+        tx: TypedTransaction,
+        token_in: &TokenInfo,
+        address_in: Address,
+        router: Address,
+        amount_in: U256,
+    ) -> Result<TxHash, OrderError<M>> {
+        if !token_in.native {
+            self.ensure_allowance(address_in, router, amount_in).await?;
+        }
+        let hash = self.send_tx(tx).await?;
+        info!("send {:?} swap transaction {:?}", self.dex_exchange, hash);
+        if !token_in.native {
+            self.consume_allowance(address_in, router, amount_in).await;
+        }
+        Ok(hash)
+    }
+
+    /// lowers the cached allowance of `spender` once it was sent a swap spending `amount` of `token`
+    pub(crate) async fn consume_allowance(&self, token: Address, spender: Address, amount: U256) {
+        if let Some(allowance) = self.approvals.write().await.get_mut(&(token, spender)) {
+            // tokens do not spend an infinite allowance
+            if *allowance != U256::MAX {
+                *allowance = allowance.saturating_sub(amount);
+            }
+        }
+    }
+
+    /// lowers the cached allowances of the v3 `router` once it was sent a swap spending `amount` of
+    /// `token`
+    pub(crate) async fn consume_v3_router_allowance(
+        &self,
+        router: &V3Router<M>,
+        token: Address,
+        amount: U256,
+    ) {
+        if let V3Router::UniversalRouter { permit2, .. } = router {
+            self.consume_allowance(token, permit2.address(), amount).await;
+        }
+        self.consume_allowance(token, router.address(), amount).await;
+    }
+
+    /// allowances of the wallet known to the service, for audit
+    pub async fn get_approvals(&self) -> Vec<Approval> {
+        let mut approvals = self
+            .approvals
+            .read()
+            .await
+            .iter()
+            .map(|((token, spender), allowance)| Approval {
+                token: *token,
+                spender: *spender,
+                allowance: *allowance,
+            })
+            .collect::<Vec<_>>();
+        approvals.sort_by_key(|approval| (approval.token, approval.spender));
+        approvals
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_approve_amount() {
+        let required = U256::from(1_000);
+        assert_eq!(ApprovalPolicy::Exact.get_approve_amount(required), required);
+        assert_eq!(ApprovalPolicy::default().get_approve_amount(required), U256::from(10_000));
+        assert_eq!(ApprovalPolicy::Multiple(0).get_approve_amount(required), required);
+        assert_eq!(ApprovalPolicy::Multiple(2).get_approve_amount(U256::MAX), U256::MAX);
+        assert_eq!(ApprovalPolicy::Infinite.get_approve_amount(required), U256::MAX);
+    }
+
+    #[test]
+    fn test_get_approval_steps() {
+        let amount = U256::from(10_000);
+        assert_eq!(get_approval_steps(U256::zero(), amount), vec![amount]);
+        assert_eq!(get_approval_steps(U256::from(1_000), amount), vec![U256::zero(), amount]);
+    }
+}
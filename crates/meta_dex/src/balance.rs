@@ -0,0 +1,315 @@
+use crate::receipt::decode_log;
+use ethers::prelude::*;
+use futures::future::try_join_all;
+use meta_address::{Token, TokenInfo};
+use meta_common::enums::Network;
+use meta_contracts::bindings::erc20::{TransferFilter, ERC20};
+use meta_util::ether::{decimal_from_wei, get_block_time_millis};
+use rust_decimal::Decimal;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info, warn};
+
+/// default time between two refreshes of the balances; the transfers of the wallets move them in
+/// between, so a refresh only catches what logs miss, as the gas paid in native token
+pub const DEFAULT_BALANCE_REFRESH_SECS: u64 = 60;
+
+/// number of blocks of `network` between two refreshes of the balances, about
+/// `DEFAULT_BALANCE_REFRESH_SECS` apart
+pub fn get_default_refresh_interval_blocks(network: Network) -> u64 {
+    (DEFAULT_BALANCE_REFRESH_SECS * 1_000 / get_block_time_millis(network)).max(1)
+}
+
+/// balance of a token in a wallet, as the cex wallet updates
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletBalance {
+    pub network: Network,
+    pub wallet: Address,
+    pub token: Token,
+    pub token_address: Address,
+    pub balance: Decimal,
+    /// change from the previous balance; the whole balance when first read
+    pub delta: Decimal,
+    pub block_number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DexEvent {
+    Balance(WalletBalance),
+}
+
+/// # Description
+/// Watches the native and erc20 balances of wallets. Balances are read from the chain every
+/// `refresh_interval_blocks`, and moved by the `Transfer` logs of the wallets in between; each
+/// change is published as a `DexEvent::Balance`
+pub struct BalanceService<M> {
+    client: Arc<M>,
+    pub network: Network,
+    pub wallets: Vec<Address>,
+    /// tokens to watch; a native token is read with `eth_getBalance`
+    pub tokens: Vec<TokenInfo>,
+    pub refresh_interval_blocks: u64,
+    /// (wallet, token address) => balance
+    balances: HashMap<(Address, Address), U256>,
+    /// block of the latest refresh; logs up to it are already counted
+    refreshed_block: u64,
+    sender: UnboundedSender<DexEvent>,
+}
+
+impl<M: Middleware> BalanceService<M> {
+    pub fn new(
+        client: Arc<M>,
+        network: Network,
+        wallets: Vec<Address>,
+        tokens: Vec<TokenInfo>,
+        sender: UnboundedSender<DexEvent>,
+    ) -> Self {
+        Self {
+            client,
+            network,
+            wallets,
+            tokens,
+            refresh_interval_blocks: get_default_refresh_interval_blocks(network),
+            balances: HashMap::new(),
+            refreshed_block: 0,
+            sender,
+        }
+    }
+
+    /// latest known balance of `token` in `wallet`
+    pub fn get_balance(&self, wallet: Address, token: Address) -> Option<Decimal> {
+        let info = self.tokens.iter().find(|info| info.address.eq(&token))?;
+        let balance = self.balances.get(&(wallet, token))?;
+        Some(decimal_from_wei(*balance, info.decimals.into()))
+    }
+
+    /// # Description
+    /// Reads every balance at `block_number`, publishing those changed
+    pub async fn refresh(&mut self, block_number: u64) -> anyhow::Result<Vec<WalletBalance>> {
+        let block = Some(BlockId::from(block_number));
+        let mut queries = vec![];
+        for wallet in self.wallets.iter() {
+            for info in self.tokens.iter() {
+                let (client, wallet, info) = (self.client.clone(), *wallet, info.clone());
+                queries.push(async move {
+                    let balance = match info.native {
+                        true => client
+                            .get_balance(wallet, block)
+                            .await
+                            .map_err(|e| anyhow::anyhow!("error in get native balance, {:?}", e))?,
+                        false => {
+                            let erc20 = ERC20::new(info.address, client);
+                            let call = erc20.balance_of(wallet).block(block_number);
+                            call.call().await.map_err(|e| {
+                                anyhow::anyhow!("error in get {:?} balance, {:?}", info.token, e)
+                            })?
+                        }
+                    };
+                    Ok::<_, anyhow::Error>((wallet, info.address, balance))
+                });
+            }
+        }
+        let balances = try_join_all(queries).await?;
+
+        self.refreshed_block = block_number;
+        let changes = balances
+            .into_iter()
+            .filter_map(|(wallet, token, balance)| {
+                self.update_balance(wallet, token, balance, block_number)
+            })
+            .collect::<Vec<_>>();
+        self.publish(&changes);
+        Ok(changes)
+    }
+
+    /// # Description
+    /// Moves the balances of the wallets by the `Transfer` in `log`, publishing those changed.
+    /// Logs of blocks already refreshed, or reorged out, are skipped; the next refresh corrects
+    /// the latter
+    ///
+    /// # Arguments
+    /// * `incoming` - counts the transfer to the wallets if true, the one from them otherwise; a
+    ///   transfer between two wallets is received once of each
+    pub fn apply_transfer_log(&mut self, log: &Log, incoming: bool) -> Vec<WalletBalance> {
+        let block_number = match log.block_number {
+            Some(block_number) => block_number.as_u64(),
+            None => return vec![],
+        };
+        if block_number <= self.refreshed_block || log.removed.unwrap_or(false) {
+            return vec![];
+        }
+        let transfer = match decode_log::<TransferFilter>(log) {
+            Some(transfer) => transfer,
+            None => return vec![],
+        };
+        let wallet = if incoming { transfer.to } else { transfer.from };
+        if !self.wallets.contains(&wallet) {
+            return vec![];
+        }
+        let balance = match self.balances.get(&(wallet, log.address)) {
+            Some(balance) if incoming => balance.saturating_add(transfer.value),
+            Some(balance) => balance.saturating_sub(transfer.value),
+            // not read yet, the first refresh will
+            None => return vec![],
+        };
+        let changes = self
+            .update_balance(wallet, log.address, balance, block_number)
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.publish(&changes);
+        changes
+    }
+
+    fn update_balance(
+        &mut self,
+        wallet: Address,
+        token: Address,
+        balance: U256,
+        block_number: u64,
+    ) -> Option<WalletBalance> {
+        let info = self.tokens.iter().find(|info| info.address.eq(&token))?;
+        let previous = self.balances.insert((wallet, token), balance);
+        if previous.is_some_and(|previous| previous.eq(&balance)) {
+            return None;
+        }
+        let decimals = info.decimals.into();
+        let balance_decimal = decimal_from_wei(balance, decimals);
+        let previous = decimal_from_wei(previous.unwrap_or_default(), decimals);
+        Some(WalletBalance {
+            network: self.network,
+            wallet,
+            token: info.token,
+            token_address: token,
+            balance: balance_decimal,
+            delta: balance_decimal - previous,
+            block_number,
+        })
+    }
+
+    fn publish(&self, changes: &[WalletBalance]) {
+        for change in changes {
+            if let Err(e) = self.sender.send(DexEvent::Balance(change.clone())) {
+                error!("error in send balance event {:?}", e);
+            }
+        }
+    }
+}
+
+impl BalanceService<Provider<Ws>> {
+    /// # Description
+    /// Refreshes the balances on new blocks and applies the `Transfer` logs of the wallets in
+    /// between; returns once a subscription is closed
+    pub async fn event_loop(&mut self) -> anyhow::Result<()> {
+        let tokens = self
+            .tokens
+            .iter()
+            .filter(|info| !info.native)
+            .map(|info| info.address)
+            .collect::<Vec<_>>();
+        let wallets = self.wallets.iter().map(|wallet| H256::from(*wallet)).collect::<Vec<_>>();
+        let filter = Filter::new().address(tokens).topic0(TransferFilter::signature());
+        let (outgoing_filter, incoming_filter) =
+            (filter.clone().topic1(wallets.clone()), filter.topic2(wallets));
+
+        // subscribe before the first refresh, so no transfer after it is missed
+        let client = self.client.clone();
+        let mut outgoing_stream = client.subscribe_logs(&outgoing_filter).await?;
+        let mut incoming_stream = client.subscribe_logs(&incoming_filter).await?;
+        let mut new_block_stream = client.subscribe_blocks().await?;
+
+        let latest_block = client.get_block_number().await?.as_u64();
+        self.refresh(latest_block).await?;
+        info!("wallet balances at block {:?}: {:?}", latest_block, self.balances);
+
+        loop {
+            tokio::select! {
+                Some(log) = outgoing_stream.next() => {
+                    self.apply_transfer_log(&log, false);
+                }
+                Some(log) = incoming_stream.next() => {
+                    self.apply_transfer_log(&log, true);
+                }
+                Some(block) = new_block_stream.next() => {
+                    let block_number = match block.number {
+                        Some(block_number) => block_number.as_u64(),
+                        None => continue,
+                    };
+                    if block_number < self.refreshed_block + self.refresh_interval_blocks {
+                        continue;
+                    }
+                    if let Err(e) = self.refresh(block_number).await {
+                        warn!("error in refresh balances at block {:?}, {:?}", block_number, e);
+                    }
+                }
+                else => {
+                    return Err(anyhow::anyhow!("balance service subscription closed"));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{encode, Token as AbiToken};
+    use meta_address::get_token_info;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_get_default_refresh_interval_blocks() {
+        assert_eq!(get_default_refresh_interval_blocks(Network::ETH), 5);
+        assert_eq!(get_default_refresh_interval_blocks(Network::ARBI), 240);
+    }
+
+    #[test]
+    fn test_apply_transfer_log() {
+        let usdc = get_token_info("USDC", Network::ARBI).unwrap();
+        let wallet = Address::from_low_u64_be(1);
+        let other = Address::from_low_u64_be(2);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (provider, _) = Provider::mocked();
+        let mut service = BalanceService::new(
+            Arc::new(provider),
+            Network::ARBI,
+            vec![wallet],
+            vec![usdc.clone()],
+            tx,
+        );
+
+        let transfer = |from: Address, to: Address, value: u64, block_number: u64| Log {
+            address: usdc.address,
+            topics: vec![TransferFilter::signature(), H256::from(from), H256::from(to)],
+            data: encode(&[AbiToken::Uint(U256::from(value))]).into(),
+            block_number: Some(block_number.into()),
+            ..Default::default()
+        };
+
+        // not refreshed yet
+        assert!(service
+            .apply_transfer_log(&transfer(other, wallet, 1_000_000, 11), true)
+            .is_empty());
+
+        let first = service.update_balance(wallet, usdc.address, U256::from(5_000_000), 10);
+        assert_eq!(first.unwrap().delta, Decimal::from(5));
+        service.refreshed_block = 10;
+
+        // counted by the refresh already
+        assert!(service
+            .apply_transfer_log(&transfer(other, wallet, 1_000_000, 10), true)
+            .is_empty());
+        let changes = service.apply_transfer_log(&transfer(other, wallet, 1_000_000, 11), true);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].balance, Decimal::from(6));
+        assert_eq!(changes[0].delta, Decimal::from(1));
+        assert_eq!(rx.try_recv().unwrap(), DexEvent::Balance(changes[0].clone()));
+
+        let changes = service.apply_transfer_log(&transfer(wallet, other, 2_500_000, 12), false);
+        assert_eq!(changes[0].balance, Decimal::new(35, 1));
+        // an outgoing transfer received on the incoming subscription is not the wallet's
+        assert!(service
+            .apply_transfer_log(&transfer(wallet, other, 2_500_000, 12), true)
+            .is_empty());
+        assert_eq!(service.get_balance(wallet, usdc.address), Some(Decimal::new(35, 1)));
+    }
+}
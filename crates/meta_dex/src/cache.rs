@@ -0,0 +1,310 @@
+use crate::{
+    pool::Pool,
+    sync::{get_all_pools, DexFactory},
+};
+use ethers::prelude::*;
+use futures::{stream, StreamExt};
+use meta_contracts::bindings::erc20::ERC20;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::{info, warn};
+
+/// number of tokens whose metadata is fetched concurrently
+const TOKEN_FETCH_CONCURRENCY: usize = 16;
+
+/// metadata of a token of an indexed pool
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMeta {
+    pub address: Address,
+    pub decimals: u8,
+    /// `None` if the token has no (string) symbol
+    pub symbol: Option<String>,
+}
+
+/// one line of the cache file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CacheRecord {
+    Pool(Pool),
+    Token(TokenMeta),
+    /// all pools of `factory` created up to and including `block` are in the cache
+    Checkpoint {
+        factory: Address,
+        block: u64,
+    },
+}
+
+/// # Description
+/// Pools and token metadata found by the pool indexer, persisted to a JSON lines file so that a
+/// restart only scans the factories from their last synced block.
+///
+/// The file is append only; on load, later records override earlier ones
+#[derive(Debug)]
+pub struct PoolCache {
+    path: PathBuf,
+    pools: HashMap<Address, Pool>,
+    tokens: HashMap<Address, TokenMeta>,
+    last_synced_blocks: HashMap<Address, u64>,
+}
+
+impl PoolCache {
+    /// load the cache at `path`; an empty cache if the file does not exist yet
+    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let mut cache = Self {
+            path: path.as_ref().to_path_buf(),
+            pools: HashMap::new(),
+            tokens: HashMap::new(),
+            last_synced_blocks: HashMap::new(),
+        };
+        if !cache.path.exists() {
+            return Ok(cache);
+        }
+
+        let reader = BufReader::new(File::open(&cache.path)?);
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<CacheRecord>(&line) {
+                Ok(record) => cache.apply(record),
+                // a partially written last line, e.g. the process was killed while appending
+                Err(e) => {
+                    warn!("skip invalid line {} of pool cache {:?}, {:?}", i + 1, cache.path, e)
+                }
+            }
+        }
+        Ok(cache)
+    }
+
+    fn apply(&mut self, record: CacheRecord) {
+        match record {
+            CacheRecord::Pool(pool) => {
+                self.pools.insert(pool.address, pool);
+            }
+            CacheRecord::Token(token) => {
+                self.tokens.insert(token.address, token);
+            }
+            CacheRecord::Checkpoint { factory, block } => {
+                self.last_synced_blocks.insert(factory, block);
+            }
+        }
+    }
+
+    fn append(&mut self, records: Vec<CacheRecord>) -> anyhow::Result<()> {
+        let mut lines = String::new();
+        for record in records.iter() {
+            lines.push_str(&serde_json::to_string(record)?);
+            lines.push('\n');
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(lines.as_bytes())?;
+        file.flush()?;
+
+        records.into_iter().for_each(|record| self.apply(record));
+        Ok(())
+    }
+
+    pub fn pools(&self) -> &HashMap<Address, Pool> {
+        &self.pools
+    }
+
+    pub fn get_token(&self, address: &Address) -> Option<&TokenMeta> {
+        self.tokens.get(address)
+    }
+
+    /// last block up to which the pools of `factory` are cached
+    pub fn last_synced_block(&self, factory: &Address) -> Option<u64> {
+        self.last_synced_blocks.get(factory).cloned()
+    }
+
+    /// persist pools and tokens found by scanning `factory` up to `block`, then its checkpoint
+    pub fn insert(
+        &mut self,
+        factory: Address,
+        block: u64,
+        pools: Vec<Pool>,
+        tokens: Vec<TokenMeta>,
+    ) -> anyhow::Result<()> {
+        let mut records = tokens.into_iter().map(CacheRecord::Token).collect::<Vec<_>>();
+        records.extend(pools.into_iter().map(CacheRecord::Pool));
+        // the checkpoint is written last, so a crash in between only causes a rescan
+        records.push(CacheRecord::Checkpoint { factory, block });
+        self.append(records)
+    }
+}
+
+/// # Description
+/// fetch decimals and symbol of the tokens
+///
+/// Returns the metadata of the tokens that implement `decimals`
+pub async fn fetch_token_meta<M: Middleware + 'static>(
+    client: Arc<M>,
+    tokens: Vec<Address>,
+) -> Vec<TokenMeta> {
+    stream::iter(tokens)
+        .map(|address| {
+            let token = ERC20::new(address, client.clone());
+            async move {
+                let (decimals_call, symbol_call) = (token.decimals(), token.symbol());
+                let (decimals, symbol) = futures::join!(decimals_call.call(), symbol_call.call());
+                match decimals {
+                    Ok(decimals) => Some(TokenMeta { address, decimals, symbol: symbol.ok() }),
+                    Err(e) => {
+                        warn!("skip token {:?} without decimals, {:?}", address, e);
+                        None
+                    }
+                }
+            }
+        })
+        .buffer_unordered(TOKEN_FETCH_CONCURRENCY)
+        .filter_map(|token| async move { token })
+        .collect()
+        .await
+}
+
+/// # Description
+/// sync the pools of the factories up to `end_block` into `cache`, scanning each factory from
+/// its last synced block (or its creation block if it was never synced)
+///
+/// Returns the pools created since the last sync
+pub async fn sync_dex_with_cache<M: Middleware + 'static>(
+    client: Arc<M>,
+    factories: Vec<DexFactory>,
+    cache: &mut PoolCache,
+    end_block: u64,
+) -> anyhow::Result<Vec<Pool>> {
+    let mut new_pools = vec![];
+    for factory in factories {
+        let from_block = match cache.last_synced_block(&factory.address) {
+            Some(block) => block + 1,
+            None => factory.created_blk_num,
+        };
+        if from_block > end_block {
+            continue;
+        }
+
+        let pools = get_all_pools(client.clone(), factory, from_block, end_block).await?;
+        let mut unknown_tokens = pools
+            .iter()
+            .flat_map(|pool| [pool.token_0, pool.token_1])
+            .filter(|token| cache.get_token(token).is_none())
+            .collect::<Vec<_>>();
+        unknown_tokens.sort();
+        unknown_tokens.dedup();
+        let tokens = fetch_token_meta(client.clone(), unknown_tokens).await;
+
+        info!(
+            "synced {:?} pools, {:?} tokens of {:?} from block {:?} to {:?}",
+            pools.len(),
+            tokens.len(),
+            factory.dex,
+            from_block,
+            end_block
+        );
+        cache.insert(factory.address, end_block, pools.clone(), tokens)?;
+        new_pools.extend(pools);
+    }
+    Ok(new_pools)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use meta_common::enums::{DexExchange, Network, PoolVariant};
+    use meta_contracts::bindings::uniswapv3factory::UniswapV3Factory;
+    use std::str::FromStr;
+
+    const SEEDED_CACHE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/static/pool_cache.jsonl");
+
+    #[test]
+    fn test_load_seeded_cache() {
+        let cache = PoolCache::load(SEEDED_CACHE).unwrap();
+
+        let factory = Address::from_str("0x1F98431c8aD98523631AE4a59f267346ea31F984").unwrap();
+        assert_eq!(cache.last_synced_block(&factory), Some(12380000));
+        assert_eq!(cache.pools().len(), 2);
+
+        let pool = cache
+            .pools()
+            .get(&Address::from_str("0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640").unwrap())
+            .unwrap();
+        assert_eq!(pool.swap_fee, U256::from(500));
+        assert_eq!(pool.pool_variant, PoolVariant::UniswapV3);
+        assert_eq!(pool.factory, Some(factory));
+
+        let weth = cache.get_token(&pool.token_1).unwrap();
+        assert_eq!(weth.decimals, 18);
+        assert_eq!(weth.symbol, Some("WETH".to_string()));
+    }
+
+    #[test]
+    fn test_insert_and_reload() {
+        let path = std::env::temp_dir().join(format!("pool_cache_{}.jsonl", std::process::id()));
+        std::fs::copy(SEEDED_CACHE, &path).unwrap();
+
+        let factory = Address::from_str("0x1F98431c8aD98523631AE4a59f267346ea31F984").unwrap();
+        let token = TokenMeta { address: Address::random(), decimals: 6, symbol: None };
+        let pool = Pool::new(
+            Address::random(),
+            token.address,
+            Address::from_str("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").unwrap(),
+            U256::from(3000),
+            PoolVariant::UniswapV3,
+        );
+        let mut cache = PoolCache::load(&path).unwrap();
+        cache.insert(factory, 12390000, vec![pool], vec![token.clone()]).unwrap();
+
+        let cache = PoolCache::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cache.last_synced_block(&factory), Some(12390000));
+        assert_eq!(cache.pools().len(), 3);
+        assert_eq!(cache.pools().get(&pool.address), Some(&pool));
+        assert_eq!(cache.get_token(&token.address), Some(&token));
+    }
+
+    #[tokio::test]
+    async fn test_sync_resumes_from_checkpoint() {
+        let path =
+            std::env::temp_dir().join(format!("pool_cache_sync_{}.jsonl", std::process::id()));
+        std::fs::copy(SEEDED_CACHE, &path).unwrap();
+
+        let factory = DexFactory {
+            network: Network::ETH,
+            dex: DexExchange::UniswapV3,
+            pool_variant: PoolVariant::UniswapV3,
+            address: Address::from_str("0x1F98431c8aD98523631AE4a59f267346ea31F984").unwrap(),
+            created_blk_num: 12369621,
+        };
+        let (provider, mock) = Provider::mocked();
+        let client = Arc::new(provider);
+        mock.push::<Vec<Log>, _>(vec![]).unwrap();
+
+        let mut cache = PoolCache::load(&path).unwrap();
+        let pools =
+            sync_dex_with_cache(client.clone(), vec![factory], &mut cache, 12380100).await.unwrap();
+        assert!(pools.is_empty());
+        // only the blocks after the checkpoint of the seeded cache are scanned
+        let filter = UniswapV3Factory::new(factory.address, client.clone())
+            .pool_created_filter()
+            .from_block(12380001u64)
+            .to_block(12380100u64)
+            .filter;
+        mock.assert_request("eth_getLogs", [filter]).unwrap();
+
+        // the new checkpoint is kept on reload, nothing is left to scan
+        let mut cache = PoolCache::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cache.last_synced_block(&factory.address), Some(12380100));
+        let pools =
+            sync_dex_with_cache(client.clone(), vec![factory], &mut cache, 12380100).await.unwrap();
+        assert!(pools.is_empty());
+        assert!(mock.assert_request("eth_getLogs", ()).is_err());
+    }
+}
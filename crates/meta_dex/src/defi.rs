@@ -10,16 +10,28 @@ use tokio::sync::RwLock;
 use meta_address::get_dex_address;
 use meta_common::enums::{ContractType, DexExchange, Network, PoolVariant};
 use meta_contracts::bindings::{
-    quoterv2::QuoterV2, swaprouter::SwapRouter, uniswapv3factory::UniswapV3Factory,
-    uniswapv3pool::UniswapV3Pool,
+    quoterv2::QuoterV2, uniswapv2factory::UniswapV2Factory, uniswapv2router02::UniswapV2Router02,
+    uniswapv3factory::UniswapV3Factory, uniswapv3pool::UniswapV3Pool,
 };
 
+use crate::router::{V3Router, V3RouterType};
+
+pub type V3Pools<M> = HashMap<(Address, Address, u32), Arc<UniswapV3Pool<M>>>;
+
 #[derive(Debug, Clone)]
 pub struct UniV3Contracts<M> {
     pub factory: UniswapV3Factory<M>,
     pub quoter_v2: Arc<RwLock<QuoterV2<M>>>,
-    pub swap_router: Arc<RwLock<SwapRouter<M>>>,
-    pub pools: Arc<RwLock<HashMap<Address, HashMap<Address, Arc<UniswapV3Pool<M>>>>>>,
+    /// the most preferred router deployed, unless another one is selected
+    pub swap_router: Arc<RwLock<Option<V3Router<M>>>>, // none if no router is deployed
+    /// pools created, keyed by `get_v3_pool_key`
+    pub pools: Arc<RwLock<V3Pools<M>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UniV2Contracts<M> {
+    pub factory: UniswapV2Factory<M>,
+    pub router: Arc<RwLock<UniswapV2Router02<M>>>,
 }
 
 #[derive(Clone, Debug)]
@@ -28,6 +40,7 @@ pub struct DexWrapper<M> {
     pub network: Network,
     pub dex: DexExchange,
     pub pool_variant: PoolVariant,
+    pub v2_contracts: Option<UniV2Contracts<M>>,
     pub v3_contracts: Option<UniV3Contracts<M>>,
 }
 
@@ -35,30 +48,51 @@ impl<M: Middleware> DexWrapper<M> {
     /// # Description
     /// Creates a new dex instance
     pub fn new(client: Arc<M>, network: Network, dex_exchange: DexExchange) -> Self {
-        let (pool_variant, factory) = match dex_exchange {
-            DexExchange::UniswapV3 => {
+        let (pool_variant, v2_contracts, v3_contracts) = match dex_exchange {
+            DexExchange::UniswapV3 | DexExchange::AGNI => {
                 let factory_contract_info =
                     get_dex_address(dex_exchange, network, ContractType::UniV3Factory).unwrap();
                 let quoter_v2_contract_info =
                     get_dex_address(dex_exchange, network, ContractType::UniV3QuoterV2).unwrap();
-                let swap_router_info =
-                    get_dex_address(dex_exchange, network, ContractType::UniV3SwapRouterV2)
-                        .unwrap();
                 let v3_factory =
                     UniswapV3Factory::new(factory_contract_info.address, client.clone());
                 let v3_quoter_v2 = QuoterV2::new(quoter_v2_contract_info.address, client.clone());
-                let swap_router = SwapRouter::new(swap_router_info.address, client.clone());
+                // the pools are still quoted where no router is deployed
+                let swap_router =
+                    V3Router::from_address_book(client.clone(), dex_exchange, network);
                 (
                     PoolVariant::UniswapV3,
-                    UniV3Contracts {
+                    None,
+                    Some(UniV3Contracts {
                         factory: v3_factory,
                         quoter_v2: Arc::new(RwLock::new(v3_quoter_v2)),
                         swap_router: Arc::new(RwLock::new(swap_router)),
                         pools: Arc::new(RwLock::new(HashMap::new())),
-                    },
+                    }),
+                )
+            }
+            DexExchange::PANCAKE
+            | DexExchange::BISWAP
+            | DexExchange::SUSHISWAP
+            | DexExchange::UniswapV2 => {
+                let factory_contract_info =
+                    get_dex_address(dex_exchange, network, ContractType::UniV2Factory).unwrap();
+                let router_contract_info =
+                    get_dex_address(dex_exchange, network, ContractType::UniV2RouterV2).unwrap();
+                let v2_factory =
+                    UniswapV2Factory::new(factory_contract_info.address, client.clone());
+                let router = UniswapV2Router02::new(router_contract_info.address, client.clone());
+                (
+                    PoolVariant::UniswapV2,
+                    Some(UniV2Contracts {
+                        factory: v2_factory,
+                        router: Arc::new(RwLock::new(router)),
+                    }),
+                    None,
                 )
             }
-            _ => unimplemented!(),
+            // quoted through `DexQuoter` and swapped through the dex's own router by `DexService`
+            _ => (PoolVariant::UniswapV2, None, None),
         };
 
         Self {
@@ -66,7 +100,8 @@ impl<M: Middleware> DexWrapper<M> {
             network,
             dex: dex_exchange,
             pool_variant,
-            v3_contracts: Some(factory),
+            v2_contracts,
+            v3_contracts,
         }
     }
 
@@ -87,6 +122,9 @@ impl<M: Middleware> DexWrapper<M> {
         UniswapV3Factory::new(factory_address, self.client.clone())
     }
 
+    /// # Description
+    /// The v3 pool of a pair with `fee`, cached once the factory has created it. A pool not
+    /// created yet has a zero address and is not cached, it may be created later
     pub async fn get_v3_pool(
         &self,
         token_0: Address,
@@ -94,34 +132,22 @@ impl<M: Middleware> DexWrapper<M> {
         fee: u32,
     ) -> anyhow::Result<Arc<UniswapV3Pool<M>>> {
         if let Some(ref v3_contract) = self.v3_contracts {
-            {
-                let _g = v3_contract.pools.read().await;
-                _g.get(&token_0).and_then(|inner| {
-                    inner.get_key_value(&token_1).map(|x| {
-                        x.1.clone()
-                    })
-                })
-            };
-            {
-                let mut _g = v3_contract.pools.write().await;
-                let address = v3_contract.factory.get_pool(token_0, token_1, fee).call().await;
-                match address {
-                    Ok(addr) => {
-                        let pool = Arc::new(UniswapV3Pool::new(addr, self.client.clone()));
-                        let ret = _g
-                            .entry(token_0)
-                            .or_insert(HashMap::new())
-                            .entry(token_1)
-                            .or_insert(pool);
-                        Ok(ret.clone())
-                    }
-                    Err(_e) => {
-                        todo!()
-                    }
-                }
+            let key = get_v3_pool_key(token_0, token_1, fee);
+            if let Some(pool) = v3_contract.pools.read().await.get(&key) {
+                return Ok(pool.clone());
+            }
+            let addr =
+                v3_contract.factory.get_pool(token_0, token_1, fee).call().await.map_err(|e| {
+                    anyhow::anyhow!("error in get v3 pool with fee {}: {:?}", fee, e)
+                })?;
+            let pool = Arc::new(UniswapV3Pool::new(addr, self.client.clone()));
+            if addr.is_zero() {
+                return Ok(pool);
             }
+            let mut _g = v3_contract.pools.write().await;
+            Ok(_g.entry(key).or_insert(pool).clone())
         } else {
-            todo!()
+            Err(anyhow::anyhow!("{} is not a uniswap v3 style dex", self.dex))
         }
     }
 
@@ -133,19 +159,70 @@ impl<M: Middleware> DexWrapper<M> {
                 Ok(a)
             }
         } else {
-            todo!()
+            Err(anyhow::anyhow!("{} is not a uniswap v3 style dex", self.dex))
         }
     }
 
-    pub async fn get_v3_swap_router(&self) -> anyhow::Result<SwapRouter<M>> {
+    pub async fn get_v3_swap_router(&self) -> anyhow::Result<V3Router<M>> {
         if let Some(ref v3_contract) = self.v3_contracts {
             {
                 let _g = v3_contract.swap_router.read().await;
-                let a = _g.clone();
-                Ok(a)
+                _g.clone().ok_or_else(|| {
+                    anyhow::anyhow!("no v3 router deployed for {} on {}", self.dex, self.network)
+                })
             }
         } else {
-            todo!()
+            Err(anyhow::anyhow!("{} is not a uniswap v3 style dex", self.dex))
+        }
+    }
+
+    /// # Description
+    /// Swaps v3 pools through the router of `router_type`, if deployed for the dex on the network
+    pub async fn set_v3_swap_router(&self, router_type: V3RouterType) -> anyhow::Result<()> {
+        let v3_contract = self
+            .v3_contracts
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("{} is not a uniswap v3 style dex", self.dex))?;
+        let router = V3Router::new(self.client.clone(), self.dex, self.network, router_type)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{:?} not deployed for {} on {}",
+                    router_type,
+                    self.dex,
+                    self.network
+                )
+            })?;
+        *v3_contract.swap_router.write().await = Some(router);
+        Ok(())
+    }
+
+    pub async fn get_v2_router(&self) -> anyhow::Result<UniswapV2Router02<M>> {
+        if let Some(ref v2_contract) = self.v2_contracts {
+            let _g = v2_contract.router.read().await;
+            Ok(_g.clone())
+        } else {
+            Err(anyhow::anyhow!("{} is not a uniswap v2 style dex", self.dex))
         }
     }
 }
+
+/// key of a v3 pool in the cache, the factory has one pool per pair whatever the token order
+fn get_v3_pool_key(token_0: Address, token_1: Address, fee: u32) -> (Address, Address, u32) {
+    if token_0 < token_1 {
+        (token_0, token_1, fee)
+    } else {
+        (token_1, token_0, fee)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_v3_pool_key() {
+        let (token_0, token_1) = (Address::from_low_u64_be(1), Address::from_low_u64_be(2));
+        assert_eq!(get_v3_pool_key(token_1, token_0, 500), (token_0, token_1, 500));
+        assert_ne!(get_v3_pool_key(token_0, token_1, 500), get_v3_pool_key(token_0, token_1, 100));
+    }
+}
@@ -3,6 +3,7 @@ use ethers::{
     providers::{Provider, ProviderError, Ws},
     types::H160,
 };
+use meta_common::enums::{ContractType, DexExchange, Network};
 use thiserror::Error;
 use tokio::task::JoinError;
 
@@ -30,6 +31,42 @@ pub enum OrderError<M: Middleware> {
     #[error("error in find receipt")]
     UnableFetchTxReceiptError,
 
+    #[error("quoted amount {quoted} breaches the slippage bound of expected {expected}")]
+    SlippageExceeded { quoted: U256, expected: U256 },
+
+    #[error("route can not be executed by a single router of the dex")]
+    UnsupportedRoute,
+
+    #[error("orders are not supported on {0}")]
+    UnsupportedDex(DexExchange),
+
+    #[error("no native token of {0} in address book")]
+    NativeTokenNotFound(Network),
+
+    #[error("no sender address in the client to act for")]
+    SenderNotFound,
+
+    #[error("approve of token {token:?} to {spender:?} failed")]
+    ApproveFailed { token: Address, spender: Address },
+
+    #[error("error in get gas price, {0}")]
+    GasPriceError(anyhow::Error),
+
+    #[error("no {1} of {0} in address book")]
+    ContractNotFound(DexExchange, ContractType),
+
+    #[error("error in quote order, {0}")]
+    QuoteError(anyhow::Error),
+
+    #[error("no pool of {0} can fill the order")]
+    NoLiquidity(DexExchange),
+
+    #[error("tick range [{tick_lower}, {tick_upper}] is not a valid range of the pool")]
+    InvalidTickRange { tick_lower: i32, tick_upper: i32 },
+
+    #[error("liquidity of the position can not cover the order")]
+    InsufficientLiquidity,
+
     #[error(transparent)]
     ContractError(#[from] ContractError<M>),
 }
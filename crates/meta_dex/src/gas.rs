@@ -0,0 +1,276 @@
+use crate::{error::OrderError, oracle::BlockOracle, DexService};
+use async_trait::async_trait;
+use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
+use meta_common::enums::Network;
+use meta_mev_service::club48::{Club48Client, CLUB48_URL};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+use tracing::warn;
+
+/// default margin (in bp) added to the estimated gas limit of a transaction
+pub const DEFAULT_GAS_LIMIT_MARGIN_BPS: u32 = 2_000;
+
+/// default priority fee paid on top of the base fee by `Eip1559GasStrategy`: 1 gwei
+pub const DEFAULT_PRIORITY_FEE: u64 = 1_000_000_000;
+
+/// default multiplier (in bp) applied to `eth_gasPrice` by `ArbitrumGasStrategy`
+pub const DEFAULT_ARBITRUM_GAS_PRICE_MULTIPLIER_BPS: u32 = 12_000;
+
+/// default multiplier (in bp) applied to `eth_gasPrice` by `SequencerGasStrategy`
+pub const DEFAULT_SEQUENCER_GAS_PRICE_MULTIPLIER_BPS: u32 = 15_000;
+
+/// min gas price (in wei) accepted by bsc validators: 3 gwei
+pub const BSC_GAS_PRICE_FLOOR: u64 = 3_000_000_000;
+
+/// gas price of a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasPrice {
+    Legacy(U256),
+    Eip1559 { max_fee_per_gas: U256, max_priority_fee_per_gas: U256 },
+}
+
+impl GasPrice {
+    /// sets the price on `tx`; a legacy price caps both fees of an eip-1559 transaction
+    pub fn apply(&self, tx: &mut TypedTransaction) {
+        match (self, tx) {
+            (
+                GasPrice::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas },
+                TypedTransaction::Eip1559(inner),
+            ) => {
+                inner.max_fee_per_gas = Some(*max_fee_per_gas);
+                inner.max_priority_fee_per_gas = Some(*max_priority_fee_per_gas);
+            }
+            (GasPrice::Eip1559 { max_fee_per_gas, .. }, tx) => {
+                tx.set_gas_price(*max_fee_per_gas);
+            }
+            (GasPrice::Legacy(gas_price), tx) => {
+                tx.set_gas_price(*gas_price);
+            }
+        }
+    }
+}
+
+/// # Description
+/// Prices the gas of the transactions sent to a network
+#[async_trait]
+pub trait GasStrategy: Send + Sync + std::fmt::Debug {
+    async fn get_gas_price(&self) -> anyhow::Result<GasPrice>;
+}
+
+/// eip-1559 pricing for mainnet: the base fee of the next block from the `BlockOracle`, doubled to
+/// stay includable through a few base fee rises, plus a priority fee
+#[derive(Debug)]
+pub struct Eip1559GasStrategy {
+    pub oracle: Arc<RwLock<BlockOracle>>,
+    pub priority_fee: U256,
+}
+
+#[async_trait]
+impl GasStrategy for Eip1559GasStrategy {
+    async fn get_gas_price(&self) -> anyhow::Result<GasPrice> {
+        let base_fee = self.oracle.read().await.next_block.base_fee;
+        Ok(GasPrice::Eip1559 {
+            max_fee_per_gas: base_fee.saturating_mul(2.into()).saturating_add(self.priority_fee),
+            max_priority_fee_per_gas: self.priority_fee,
+        })
+    }
+}
+
+/// legacy pricing for arbitrum: `eth_gasPrice` with a multiplier. The sequencer charges the
+/// current base fee only, so the margin costs nothing
+#[derive(Debug)]
+pub struct ArbitrumGasStrategy<M> {
+    pub client: Arc<M>,
+    pub multiplier_bps: u32,
+}
+
+#[async_trait]
+impl<M: Middleware + std::fmt::Debug> GasStrategy for ArbitrumGasStrategy<M> {
+    async fn get_gas_price(&self) -> anyhow::Result<GasPrice> {
+        let gas_price =
+            self.client.get_gas_price().await.map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        Ok(GasPrice::Legacy(gas_price.saturating_mul(self.multiplier_bps.into()) / 10_000))
+    }
+}
+
+/// eip-1559 pricing for the first come first served sequencers of mantle and zksync era:
+/// `eth_gasPrice` with a multiplier as max fee and no priority fee, which buys no ordering there
+#[derive(Debug)]
+pub struct SequencerGasStrategy<M> {
+    pub client: Arc<M>,
+    pub multiplier_bps: u32,
+}
+
+#[async_trait]
+impl<M: Middleware + std::fmt::Debug> GasStrategy for SequencerGasStrategy<M> {
+    async fn get_gas_price(&self) -> anyhow::Result<GasPrice> {
+        let gas_price =
+            self.client.get_gas_price().await.map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        Ok(GasPrice::Eip1559 {
+            max_fee_per_gas: gas_price.saturating_mul(self.multiplier_bps.into()) / 10_000,
+            max_priority_fee_per_gas: U256::zero(),
+        })
+    }
+}
+
+/// legacy pricing for bsc: a fixed floor, raised to the floor of the 48 club builder if one is
+/// queried
+#[derive(Debug)]
+pub struct BscGasStrategy {
+    pub floor: U256,
+    pub club48: Option<Mutex<Club48Client>>,
+}
+
+#[async_trait]
+impl GasStrategy for BscGasStrategy {
+    async fn get_gas_price(&self) -> anyhow::Result<GasPrice> {
+        let club48_floor = match self.club48 {
+            Some(ref club48) => match club48.lock().await.query_gas_price_floor().await {
+                Ok(floor) => U256::from(floor),
+                Err(e) => {
+                    warn!("error in query 48 club gas price floor, {:?}", e);
+                    U256::zero()
+                }
+            },
+            None => U256::zero(),
+        };
+        Ok(GasPrice::Legacy(self.floor.max(club48_floor)))
+    }
+}
+
+/// # Description
+/// Creates the default gas strategy of `network`; `None` leaves gas pricing to the middleware
+pub fn new_gas_strategy<M: Middleware + std::fmt::Debug + 'static>(
+    client: Arc<M>,
+    network: Network,
+    oracle: Arc<RwLock<BlockOracle>>,
+) -> Option<Arc<dyn GasStrategy>> {
+    match network {
+        Network::ETH => Some(Arc::new(Eip1559GasStrategy {
+            oracle,
+            priority_fee: U256::from(DEFAULT_PRIORITY_FEE),
+        })),
+        Network::ARBI => Some(Arc::new(ArbitrumGasStrategy {
+            client,
+            multiplier_bps: DEFAULT_ARBITRUM_GAS_PRICE_MULTIPLIER_BPS,
+        })),
+        Network::BSC => Some(Arc::new(BscGasStrategy {
+            floor: U256::from(BSC_GAS_PRICE_FLOOR),
+            club48: Some(Mutex::new(Club48Client::new(CLUB48_URL.to_string()))),
+        })),
+        Network::MANTLE | Network::ZK_SYNC_ERA | Network::ZK_SYNC_ERA_TEST => {
+            Some(Arc::new(SequencerGasStrategy {
+                client,
+                multiplier_bps: DEFAULT_SEQUENCER_GAS_PRICE_MULTIPLIER_BPS,
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// `estimated` gas limit raised by `margin_bps`
+pub fn get_gas_limit_with_margin(estimated: U256, margin_bps: u32) -> U256 {
+    estimated.saturating_mul(U256::from(10_000 + margin_bps)) / 10_000
+}
+
+impl<M: Middleware> DexService<M> {
+    /// sets the gas price of `tx` from the gas strategy, and its gas limit from `eth_estimateGas`
+    /// plus `gas_limit_margin_bps`
+    pub(crate) async fn fill_gas(&self, tx: &mut TypedTransaction) -> Result<(), OrderError<M>> {
+        if let Some(ref strategy) = self.gas_strategy {
+            let gas_price = strategy.get_gas_price().await.map_err(OrderError::GasPriceError)?;
+            gas_price.apply(tx);
+        }
+        let estimated = self
+            .client
+            .estimate_gas(tx, None)
+            .await
+            .map_err(ContractError::from_middleware_error)?;
+        tx.set_gas(get_gas_limit_with_margin(estimated, self.gas_limit_margin_bps));
+        Ok(())
+    }
+
+    /// fills the gas of `tx` and sends it
+    pub(crate) async fn send_tx(&self, mut tx: TypedTransaction) -> Result<TxHash, OrderError<M>> {
+        self.fill_gas(&mut tx).await?;
+        let pending = self
+            .client
+            .send_transaction(tx, None)
+            .await
+            .map_err(ContractError::from_middleware_error)?;
+        Ok(pending.tx_hash())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::types::transaction::eip2718::TypedTransaction;
+
+    #[tokio::test]
+    async fn test_eip1559_gas_strategy() {
+        let mut oracle = BlockOracle::default();
+        oracle.next_block.base_fee = U256::from(20_000_000_000u64);
+        let strategy = Eip1559GasStrategy {
+            oracle: Arc::new(RwLock::new(oracle)),
+            priority_fee: U256::from(DEFAULT_PRIORITY_FEE),
+        };
+        let gas_price = strategy.get_gas_price().await.unwrap();
+        assert_eq!(
+            gas_price,
+            GasPrice::Eip1559 {
+                max_fee_per_gas: U256::from(41_000_000_000u64),
+                max_priority_fee_per_gas: U256::from(DEFAULT_PRIORITY_FEE),
+            }
+        );
+
+        let mut tx = TypedTransaction::Eip1559(Eip1559TransactionRequest::new());
+        gas_price.apply(&mut tx);
+        match tx {
+            TypedTransaction::Eip1559(ref inner) => {
+                assert_eq!(inner.max_fee_per_gas, Some(U256::from(41_000_000_000u64)));
+                assert_eq!(inner.max_priority_fee_per_gas, Some(U256::from(DEFAULT_PRIORITY_FEE)));
+            }
+            _ => panic!("not an eip-1559 transaction"),
+        }
+
+        let mut tx = TypedTransaction::Legacy(TransactionRequest::new());
+        GasPrice::Legacy(U256::from(BSC_GAS_PRICE_FLOOR)).apply(&mut tx);
+        assert_eq!(tx.gas_price(), Some(U256::from(BSC_GAS_PRICE_FLOOR)));
+    }
+
+    #[tokio::test]
+    async fn test_bsc_gas_strategy() {
+        let strategy = BscGasStrategy { floor: U256::from(BSC_GAS_PRICE_FLOOR), club48: None };
+        assert_eq!(
+            strategy.get_gas_price().await.unwrap(),
+            GasPrice::Legacy(U256::from(BSC_GAS_PRICE_FLOOR))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sequencer_gas_strategy() {
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::from(250_000_000u64)).unwrap();
+        let strategy = SequencerGasStrategy {
+            client: Arc::new(provider),
+            multiplier_bps: DEFAULT_SEQUENCER_GAS_PRICE_MULTIPLIER_BPS,
+        };
+        assert_eq!(
+            strategy.get_gas_price().await.unwrap(),
+            GasPrice::Eip1559 {
+                max_fee_per_gas: U256::from(375_000_000u64),
+                max_priority_fee_per_gas: U256::zero(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_gas_limit_with_margin() {
+        assert_eq!(
+            get_gas_limit_with_margin(U256::from(150_000), DEFAULT_GAS_LIMIT_MARGIN_BPS),
+            U256::from(180_000)
+        );
+        assert_eq!(get_gas_limit_with_margin(U256::from(150_000), 0), U256::from(150_000));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{error::OrderError, quoter::encode_izumi_path, DexService};
+use ethers::prelude::*;
+use meta_address::TokenInfo;
+use meta_common::enums::ContractType;
+use meta_contracts::bindings::{
+    izumiquoter::IzumiQuoter,
+    izumiswap::{IzumiSwap, SwapAmountParams, SwapDesireParams},
+};
+use meta_util::{ether::decimal_to_wei, time::get_current_ts};
+use rust_decimal::Decimal;
+use tracing::info;
+
+impl<M: Middleware> DexService<M> {
+    /// # Description
+    /// swap `amount` of base against quote through the izumi pool of `fee`; negative `amount`
+    /// sells base with `swapAmount`, positive buys base with `swapDesire`
+    ///
+    /// A native token leg is paid with the value of the transaction, the excess refunded, or
+    /// unwrapped by the router before it is paid out to `recipient`
+    pub(crate) async fn submit_izumi_order(
+        &self,
+        base: TokenInfo,
+        quote: TokenInfo,
+        amount: Decimal,
+        fee: u32,
+        recipient: Address,
+        limit_price: Option<Decimal>,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let (base_address, quote_address) =
+            (self.get_pool_token_address(&base)?, self.get_pool_token_address(&quote)?);
+        let quoter = IzumiQuoter::new(
+            self.get_contract_address(ContractType::IzumiQuoter)?,
+            self.client.clone(),
+        );
+        let router = IzumiSwap::new(
+            self.get_contract_address(ContractType::IzumiSwapRouter)?,
+            self.client.clone(),
+        );
+
+        let (token_in, token_out) =
+            if amount.is_sign_negative() { (base, quote) } else { (quote, base) };
+        // the router keeps the wrapped native token bought, to unwrap it
+        let swap_recipient = if token_out.native { router.address() } else { recipient };
+        let (call, address_in, amount_in_max, amount_out_min) = if amount.is_sign_negative() {
+            // sell base
+            let amount_in = decimal_to_wei(amount.abs(), token_in.decimals.into());
+            if amount_in > U256::from(u128::MAX) {
+                return Err(OrderError::UnsupportedRoute);
+            }
+            let path = encode_izumi_path(base_address, fee, quote_address);
+            let (quoted_out, _) =
+                quoter.swap_amount(amount_in.as_u128(), path.clone()).call().await?;
+            let amount_out_min =
+                self.get_amount_out_min(quoted_out, amount, limit_price, &token_out)?;
+            let params = SwapAmountParams {
+                path,
+                recipient: swap_recipient,
+                amount: amount_in.as_u128(),
+                min_acquired: amount_out_min,
+                deadline: ddl.into(),
+            };
+            info!("izumi swap amount params {:?}", params);
+            (router.swap_amount(params), base_address, amount_in, amount_out_min)
+        } else {
+            // buy base
+            let amount_out = decimal_to_wei(amount, token_out.decimals.into());
+            if amount_out > U256::from(u128::MAX) {
+                return Err(OrderError::UnsupportedRoute);
+            }
+            // an exact output path starts from the token out
+            let path = encode_izumi_path(base_address, fee, quote_address);
+            let (quoted_in, _) =
+                quoter.swap_desire(amount_out.as_u128(), path.clone()).call().await?;
+            let amount_in_max =
+                self.get_amount_in_max(quoted_in, amount, limit_price, &token_in)?;
+            let params = SwapDesireParams {
+                path,
+                recipient: swap_recipient,
+                desire: amount_out.as_u128(),
+                max_payed: amount_in_max,
+                deadline: ddl.into(),
+            };
+            info!("izumi swap desire params {:?}", params);
+            (router.swap_desire(params), quote_address, amount_in_max, amount_out)
+        };
+
+        let mut calls = call.calldata().into_iter().collect::<Vec<_>>();
+        if token_in.native && !amount.is_sign_negative() {
+            calls.extend(router.refund_eth().calldata());
+        }
+        if token_out.native {
+            calls.extend(router.unwrap_weth9(amount_out_min, recipient).calldata());
+        }
+        let mut tx = match calls.len() {
+            1 => call.tx,
+            _ => router.multicall(calls).tx,
+        };
+        if token_in.native {
+            tx.set_value(amount_in_max);
+        }
+        self.send_swap_tx(tx, &token_in, address_in, router.address(), amount_in_max).await
+    }
+}
@@ -1,39 +1,64 @@
 #![allow(unused_imports)]
 
+pub mod allowance;
+pub mod balance;
+pub mod cache;
 pub mod defi;
 pub mod error;
+pub mod gas;
+pub mod izumi;
+pub mod liquidity;
+pub mod native;
 pub mod oracle;
 pub mod pool;
+pub mod quoter;
+pub mod receipt;
+pub mod route;
+pub mod router;
 pub mod sandwidth;
+pub mod sync;
+pub mod tracker;
+pub mod twap;
+pub mod uni_v3;
+pub mod zksync;
 pub mod prelude {
     pub use super::{error::*, oracle::*, pool::*, sandwidth::*};
 }
 use crate::prelude::Pool;
+use allowance::ApprovalPolicy;
 use defi::DexWrapper;
 use error::OrderError;
 use ethers::prelude::*;
 use ethers::prelude::*;
 use eyre::Result;
-use futures::future::try_join_all;
+use futures::future::{join_all, try_join_all};
 use futures_util::{SinkExt, TryStreamExt};
-use hashbrown::HashMap;
+use gas::{GasStrategy, DEFAULT_GAS_LIMIT_MARGIN_BPS};
+use hashbrown::{HashMap, HashSet};
 use meta_address::TokenInfo;
-use meta_address::{get_dex_address, Token};
+use meta_address::{get_addressed_token_info, get_dex_address, get_native_token_info, Token};
 use meta_common::{
     enums::{ContractType, DexExchange, Network, PoolVariant},
-    models::{CurrentSpread, MarcketChange},
+    models::{CurrentSpread, DepthLadder, MarcketChange},
 };
 use meta_contracts::bindings::{
     quoterv2::{QuoteExactInputSingleParams, QuoteExactOutputSingleParams, QuoterV2},
-    swaprouter::{ExactInputSingleParams, ExactOutputSingleParams, SwapRouter},
     uniswapv2factory::UniswapV2Factory,
-    uniswapv3pool::{SwapFilter, UniswapV3Pool},
+    uniswapv3factory::UniswapV3Factory,
+    uniswapv3pool::UniswapV3Pool,
 };
 use meta_util::{
-    defi::{get_swap_price_limit, get_token0_and_token1},
+    defi::{
+        get_amount_out_min, get_bounded_amount_in_max, get_bounded_amount_out_min,
+        get_swap_price_limit, get_token0_and_token1, uni_v3::V3PoolState,
+    },
     ether::{decimal_from_wei, decimal_from_wei_i256, decimal_to_wei},
     time::get_current_ts,
 };
+use quoter::{get_depth_ladder_amounts, new_dex_quoter, to_price_level};
+use receipt::{analyze_receipt, PoolSwap};
+use route::Route;
+use router::{V3Swap, V3SwapAmount};
 use rust_decimal::{
     prelude::{FromPrimitive, Signed, ToPrimitive},
     Decimal,
@@ -41,6 +66,13 @@ use rust_decimal::{
 use std::sync::{mpsc::SyncSender, Arc};
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
+use uni_v3::{apply_v3_pool_log, load_v3_pool_state, V3_SNAPSHOT_WORD_RADIUS};
+
+/// fee tiers (in hundredths of a bip) enabled on the uniswap v3 factory
+pub const V3_FEE_TIERS: [u32; 4] = [100, 500, 3000, 10000];
+
+/// default slippage tolerance (in bp) applied to the min out / max in bound of a swap
+pub const DEFAULT_SLIPPAGE_BPS: u32 = 50;
 
 #[derive(Clone, Debug)]
 pub struct DexService<M> {
@@ -49,6 +81,15 @@ pub struct DexService<M> {
     pub dex_exchange: DexExchange,
     pub pool_variant: PoolVariant,
     pub dex_contracts: DexWrapper<M>,
+    pub slippage_bps: u32,
+    /// amount approved to the router when its allowance can not cover a swap
+    pub approval_policy: ApprovalPolicy,
+    /// (token, spender) => allowance of the wallet, as last read or approved
+    approvals: Arc<RwLock<HashMap<(Address, Address), U256>>>,
+    /// prices the gas of the transactions; `None` leaves it to the middleware
+    pub gas_strategy: Option<Arc<dyn GasStrategy>>,
+    /// margin (in bp) added to the estimated gas limit of the transactions
+    pub gas_limit_margin_bps: u32,
 }
 
 #[derive(Debug)]
@@ -61,6 +102,8 @@ pub struct FeeInfo {
 pub struct TradeBalanceDiff {
     pub trade: HashMap<Token, Decimal>,
     pub fee: FeeInfo,
+    /// pool swaps of the transaction, in the order they were logged
+    pub swaps: Vec<PoolSwap>,
 }
 
 impl<M: Middleware> DexService<M> {
@@ -74,11 +117,11 @@ impl<M: Middleware> DexService<M> {
         base_token: TokenInfo,
         base_token_quote_amt: Decimal,
         quote_token: TokenInfo,
-        v3_fee: u32,
+        slippage_bps: u32,
         sender_market_change: SyncSender<MarcketChange>,
     ) -> (Self, DexBackend<Provider<Ws>>) {
         let pool_variant: PoolVariant = match dex_exchange {
-            DexExchange::UniswapV3 => PoolVariant::UniswapV3,
+            DexExchange::UniswapV3 | DexExchange::AGNI => PoolVariant::UniswapV3,
             _ => PoolVariant::UniswapV2,
         };
 
@@ -87,11 +130,11 @@ impl<M: Middleware> DexService<M> {
         let backend = DexBackend::<Provider<Ws>>::new(
             provider,
             network,
+            dex_exchange,
             sender_market_change,
             base_token,
             base_token_quote_amt,
             quote_token,
-            v3_fee,
         );
         (
             DexService {
@@ -100,6 +143,11 @@ impl<M: Middleware> DexService<M> {
                 dex_exchange,
                 pool_variant,
                 dex_contracts: contracts,
+                slippage_bps,
+                approval_policy: ApprovalPolicy::default(),
+                approvals: Arc::new(RwLock::new(HashMap::new())),
+                gas_strategy: None,
+                gas_limit_margin_bps: DEFAULT_GAS_LIMIT_MARGIN_BPS,
             },
             backend,
         )
@@ -109,66 +157,70 @@ impl<M: Middleware> DexService<M> {
         Address::default()
     }
 
-    pub async fn analyze_v3_tx(
+    /// address of the `contract_type` contract of the dex in the address book
+    pub fn get_contract_address(
+        &self,
+        contract_type: ContractType,
+    ) -> Result<Address, OrderError<M>> {
+        get_dex_address(self.dex_exchange, self.network, contract_type.clone())
+            .map(|info| info.address)
+            .ok_or(OrderError::ContractNotFound(self.dex_exchange, contract_type))
+    }
+
+    /// # Description
+    /// Net balance changes of `wallet` in the transaction `hash`, with the gas paid in the native
+    /// token of the network. Tokens missing from the address book are left out
+    pub async fn analyze_tx(
         &self,
         hash: TxHash,
-        base_token_info: TokenInfo,
-        quote_token_info: TokenInfo,
-        fee: u32,
+        wallet: Address,
     ) -> Result<TradeBalanceDiff, OrderError<M>> {
-        let receipt = self.client.get_transaction_receipt(hash).await;
-        match receipt {
-            Ok(r) => match r {
-                Some(_r) => {
-                    let (token_0, token_1) =
-                        get_token0_and_token1(&base_token_info.address, &quote_token_info.address);
-                    let (mut token_0_info, mut token_1_info) =
-                        (base_token_info.clone(), quote_token_info.clone());
-                    if token_0.eq(&quote_token_info.address) {
-                        (token_0_info, token_1_info) = (quote_token_info, base_token_info);
-                    }
-                    let gas_used = _r.gas_used.unwrap();
-                    let gas_price = _r.effective_gas_price.unwrap();
-                    let gas_fee = gas_used.checked_mul(gas_price).unwrap();
-                    let gas_fee = decimal_from_wei(gas_fee, 18);
-                    let pool = self.dex_contracts.get_v3_pool(token_0, token_1, fee).await.unwrap();
-                    let mut diff = HashMap::new();
-                    for log in _r.logs {
-                        if log.address.eq(&pool.address()) {
-                            let decoded: SwapFilter = pool
-                                .decode_event(SwapFilter::name().as_ref(), log.topics, log.data)
-                                .unwrap();
-
-                            diff.insert(
-                                token_0_info.token,
-                                decimal_from_wei_i256(
-                                    decoded.amount_0.saturating_neg(),
-                                    token_0_info.decimals.into(),
-                                ),
-                            );
-                            diff.insert(
-                                token_1_info.token,
-                                decimal_from_wei_i256(
-                                    decoded.amount_1.saturating_neg(),
-                                    token_1_info.decimals.into(),
-                                ),
-                            );
-                        }
-                    }
-                    Ok(TradeBalanceDiff {
-                        trade: diff,
-                        fee: FeeInfo {
-                            fee_token: Token::ETH,
-                            amount: gas_fee.saturating_mul(Decimal::NEGATIVE_ONE),
-                        },
-                    })
+        let (receipt, tx) = futures::try_join!(
+            self.client.get_transaction_receipt(hash),
+            self.client.get_transaction(hash)
+        )
+        .map_err(|_| OrderError::UnableFetchTxReceiptError)?;
+        let receipt = receipt.ok_or(OrderError::UnableFetchTxReceiptError)?;
+        let native = get_native_token_info(self.network)
+            .ok_or(OrderError::NativeTokenNotFound(self.network))?;
+
+        let analysis = analyze_receipt(self.network, &receipt, tx.as_ref(), wallet);
+        let mut trade = HashMap::new();
+        for (address, delta) in analysis.token_deltas.iter() {
+            match get_addressed_token_info(self.network, *address) {
+                Some(info) => {
+                    let delta = decimal_from_wei_i256(*delta, info.decimals.into());
+                    *trade.entry(info.token).or_insert(Decimal::ZERO) += delta;
                 }
-                None => Err(OrderError::UnableFetchTxReceiptError),
-            },
-            Err(_e) => Err(OrderError::UnableFetchTxReceiptError),
+                None => warn!("skip unknown token {:?} in tx {:?}", address, hash),
+            }
+        }
+        if !analysis.native_delta.is_zero() {
+            let delta = decimal_from_wei_i256(analysis.native_delta, native.decimals.into());
+            *trade.entry(native.token).or_insert(Decimal::ZERO) += delta;
         }
+
+        Ok(TradeBalanceDiff {
+            trade,
+            fee: FeeInfo {
+                fee_token: native.token,
+                amount: decimal_from_wei(analysis.gas.total, native.decimals.into())
+                    .saturating_mul(Decimal::NEGATIVE_ONE),
+            },
+            swaps: analysis.swaps,
+        })
     }
 
+    /// # Description
+    /// swap `amount` of base against quote; negative `amount` sells base, positive buys base
+    ///
+    /// A native token leg is paid with the value of the transaction, or unwrapped by the router
+    /// before it is paid out to `recipient`
+    ///
+    /// # Arguments
+    /// * `limit_price` - the price (in quote per base) that triggered the trade. When set, a fresh
+    ///   quote worse than this price beyond the slippage tolerance rejects the order, and the
+    ///   min out / max in bound is never looser than this price allows
     pub async fn submit_order(
         &self,
         base: TokenInfo,
@@ -176,408 +228,597 @@ impl<M: Middleware> DexService<M> {
         amount: Decimal,
         fee: u32,
         recipient: Address,
+        limit_price: Option<Decimal>,
     ) -> Result<TxHash, OrderError<M>> {
         let ddl = get_current_ts().as_secs() + 1000000;
+        let (base_address, quote_address) =
+            (self.get_pool_token_address(&base)?, self.get_pool_token_address(&quote)?);
+        if base_address.eq(&quote_address) {
+            // the native token against its wrapped token is a wrap or an unwrap, not a swap
+            return Err(OrderError::UnsupportedRoute);
+        }
 
         match self.dex_exchange {
-            DexExchange::UniswapV3 => {
+            DexExchange::UniswapV3 | DexExchange::AGNI => {
+                let quoter = self.dex_contracts.get_v3_quoter().await.map_err(|_| {
+                    OrderError::ContractNotFound(self.dex_exchange, ContractType::UniV3QuoterV2)
+                })?;
+                let router = self.dex_contracts.get_v3_swap_router().await.map_err(|_| {
+                    OrderError::ContractNotFound(self.dex_exchange, ContractType::UniV3SwapRouterV2)
+                })?;
+                let (token_in, token_out, tokens, swap_amount) = if amount.is_sign_negative() {
+                    // sell base
+                    let (token_in, token_out) = (base, quote);
+                    let (address_in, address_out) = (base_address, quote_address);
+                    let amount_in_wei = decimal_to_wei(amount.abs(), token_in.decimals.into());
+                    let (quoted_out, _, _, _) = quoter
+                        .quote_exact_input_single(QuoteExactInputSingleParams {
+                            token_in: address_in,
+                            token_out: address_out,
+                            amount_in: amount_in_wei,
+                            fee,
+                            sqrt_price_limit_x96: U256::zero(),
+                        })
+                        .call()
+                        .await?;
+                    let amount_out_min =
+                        self.get_amount_out_min(quoted_out, amount, limit_price, &token_out)?;
+                    let amount =
+                        V3SwapAmount::ExactInput { amount_in: amount_in_wei, amount_out_min };
+                    (token_in, token_out, vec![address_in, address_out], amount)
+                } else {
+                    // buy base
+                    let (token_in, token_out) = (quote, base);
+                    let (address_in, address_out) = (quote_address, base_address);
+                    let amount_out_wei = decimal_to_wei(amount, token_out.decimals.into());
+                    let (quoted_in, _, _, _) = quoter
+                        .quote_exact_output_single(QuoteExactOutputSingleParams {
+                            token_in: address_in,
+                            token_out: address_out,
+                            amount: amount_out_wei,
+                            fee,
+                            sqrt_price_limit_x96: U256::zero(),
+                        })
+                        .call()
+                        .await?;
+                    let amount_in_max =
+                        self.get_amount_in_max(quoted_in, amount, limit_price, &token_in)?;
+                    let amount =
+                        V3SwapAmount::ExactOutput { amount_out: amount_out_wei, amount_in_max };
+                    (token_in, token_out, vec![address_in, address_out], amount)
+                };
+                let swap = V3Swap {
+                    tokens,
+                    fees: vec![fee],
+                    amount: swap_amount,
+                    recipient,
+                    deadline: ddl.into(),
+                    native_in: token_in.native,
+                    native_out: token_out.native,
+                };
+                info!("v3 swap through {:?}, params {:?}", router.router_type(), swap);
+
+                let tx = router.get_swap_tx(&swap).ok_or(OrderError::UnsupportedRoute)?;
+                let (address_in, amount_in_max) = (swap.tokens[0], swap.get_max_amount_in());
+                if !token_in.native {
+                    self.ensure_v3_router_allowance(&router, address_in, amount_in_max).await?;
+                }
+                let hash = self.send_tx(tx).await?;
+                info!("send v3 swap transaction {:?}", hash);
+                if !token_in.native {
+                    self.consume_v3_router_allowance(&router, address_in, amount_in_max).await;
+                }
+                Ok(hash)
+            }
+            DexExchange::PANCAKE
+            | DexExchange::BISWAP
+            | DexExchange::SUSHISWAP
+            | DexExchange::UniswapV2 => {
+                let router = self.dex_contracts.get_v2_router().await.unwrap();
                 if amount.is_sign_negative() {
                     // sell base
                     let (token_in, token_out) = (base, quote);
                     let amount_in_wei = decimal_to_wei(amount.abs(), token_in.decimals.into());
-                    let swap_param = ExactInputSingleParams {
-                        token_in: token_in.address,
-                        token_out: token_out.address,
-                        fee,
-                        recipient,
-                        deadline: ddl.into(),
-                        amount_in: amount_in_wei,
-                        amount_out_minimum: U256::zero(),
-                        sqrt_price_limit_x96: get_swap_price_limit(
-                            token_in.address,
-                            token_out.address,
-                            token_in.address,
+                    let path = vec![base_address, quote_address];
+                    // the router prices the path against the pairs' current reserves, with the fork's own fee
+                    let amounts_out =
+                        router.get_amounts_out(amount_in_wei, path.clone()).call().await?;
+                    let amount_out_min =
+                        self.get_amount_out_min(amounts_out[1], amount, limit_price, &token_out)?;
+                    info!(
+                        "v2 swap exact tokens for tokens, amount_in: {:?}, amount_out_min: {:?}, path: {:?}",
+                        amount_in_wei, amount_out_min, path
+                    );
+
+                    let call = match (token_in.native, token_out.native) {
+                        (true, _) => router
+                            .swap_exact_eth_for_tokens(amount_out_min, path, recipient, ddl.into())
+                            .value(amount_in_wei),
+                        (_, true) => router.swap_exact_tokens_for_eth(
+                            amount_in_wei,
+                            amount_out_min,
+                            path,
+                            recipient,
+                            ddl.into(),
+                        ),
+                        _ => router.swap_exact_tokens_for_tokens(
+                            amount_in_wei,
+                            amount_out_min,
+                            path,
+                            recipient,
+                            ddl.into(),
                         ),
                     };
-                    info!("swap params {:?}", swap_param);
-
-                    let call = self
-                        .dex_contracts
-                        .get_v3_swap_router()
-                        .await
-                        .as_ref()
-                        .unwrap()
-                        .exact_input_single(swap_param)
-                        .gas(2_000_000)
-                        .gas_price(300_000_000);
-                    let ret = call.send().await;
-                    match ret {
-                        Ok(ref tx) => {
-                            info!("send v3 exact input transaction {:?}", tx);
-                            Ok(tx.tx_hash())
-                        }
-                        Err(e) => Err(OrderError::ContractError(e)),
+                    if !token_in.native {
+                        self.ensure_allowance(base_address, router.address(), amount_in_wei)
+                            .await?;
+                    }
+                    let hash = self.send_tx(call.tx).await?;
+                    info!("send v2 exact input transaction {:?}", hash);
+                    if !token_in.native {
+                        self.consume_allowance(base_address, router.address(), amount_in_wei).await;
                     }
+                    Ok(hash)
                 } else {
                     // buy base
                     let (token_in, token_out) = (quote, base);
                     let amount_out_wei = decimal_to_wei(amount, token_out.decimals.into());
-                    let param_output = ExactOutputSingleParams {
-                        token_in: token_in.address,
-                        token_out: token_out.address,
-                        fee,
-                        recipient,
-                        deadline: ddl.into(),
-                        amount_out: amount_out_wei,
-                        // TODO: to determin amount_in_maximum, assume no asset is more expensive than 200_000 per quote
-                        amount_in_maximum: decimal_to_wei(
-                            amount.checked_mul(Decimal::from_i32(200_000).unwrap()).unwrap(),
-                            token_in.decimals.into(),
+                    let path = vec![quote_address, base_address];
+                    let amounts_in =
+                        router.get_amounts_in(amount_out_wei, path.clone()).call().await?;
+                    let amount_in_max =
+                        self.get_amount_in_max(amounts_in[0], amount, limit_price, &token_in)?;
+                    info!(
+                        "v2 swap tokens for exact tokens, amount_out: {:?}, amount_in_max: {:?}, path: {:?}",
+                        amount_out_wei, amount_in_max, path
+                    );
+
+                    // the router refunds the native token paid beyond the amount in
+                    let call = match (token_in.native, token_out.native) {
+                        (true, _) => router
+                            .swap_eth_for_exact_tokens(amount_out_wei, path, recipient, ddl.into())
+                            .value(amount_in_max),
+                        (_, true) => router.swap_tokens_for_exact_eth(
+                            amount_out_wei,
+                            amount_in_max,
+                            path,
+                            recipient,
+                            ddl.into(),
                         ),
-                        sqrt_price_limit_x96: get_swap_price_limit(
-                            token_in.address,
-                            token_out.address,
-                            token_in.address,
+                        _ => router.swap_tokens_for_exact_tokens(
+                            amount_out_wei,
+                            amount_in_max,
+                            path,
+                            recipient,
+                            ddl.into(),
                         ),
                     };
-                    let call = self
-                        .dex_contracts
-                        .get_v3_swap_router()
-                        .await
-                        .as_ref()
-                        .unwrap()
-                        .exact_output_single(param_output)
-                        .gas(1_800_000)
-                        .gas_price(300_000_000);
-                    let ret = call.send().await;
-                    match ret {
-                        Ok(ref tx) => {
-                            info!("send v3 exact out single transaction {:?}", tx);
-                            Ok(tx.tx_hash())
-                        }
-                        Err(e) => Err(OrderError::ContractError(e)),
+                    if !token_in.native {
+                        self.ensure_allowance(quote_address, router.address(), amount_in_max)
+                            .await?;
                     }
+                    let hash = self.send_tx(call.tx).await?;
+                    info!("send v2 exact output transaction {:?}", hash);
+                    if !token_in.native {
+                        self.consume_allowance(quote_address, router.address(), amount_in_max)
+                            .await;
+                    }
+                    Ok(hash)
                 }
             }
-            _ => unimplemented!(),
+            DexExchange::IZUMI => {
+                self.submit_izumi_order(base, quote, amount, fee, recipient, limit_price).await
+            }
+            DexExchange::SyncSwap => {
+                self.submit_syncswap_order(base, quote, amount, recipient, limit_price).await
+            }
+            DexExchange::MuteSwitch => {
+                self.submit_mute_switch_order(base, quote, amount, recipient, limit_price).await
+            }
+            _ => Err(OrderError::UnsupportedDex(self.dex_exchange)),
+        }
+    }
+
+    /// # Description
+    /// swap `route.amount_in` of its first token along `route`, through the v3 router of the dex or
+    /// the v2 router depending on its pools
+    ///
+    /// The min out allows `slippage_bps` of slippage from the routed `amount_out`
+    pub async fn submit_route_order(
+        &self,
+        route: &Route,
+        recipient: Address,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let amount_out_min = get_amount_out_min(route.amount_out, self.slippage_bps);
+        let token_in = route.hops.first().ok_or(OrderError::UnsupportedRoute)?.token_in;
+        // the routers only swap through the pools of the factories of their dex
+        if !route.is_created_by(&self.get_factory_addresses()) {
+            return Err(OrderError::UnsupportedRoute);
+        }
+
+        let tx = match (self.pool_variant, route.pool_variant()) {
+            (PoolVariant::UniswapV3, Some(PoolVariant::UniswapV3)) => {
+                let swap = V3Swap {
+                    tokens: route.tokens(),
+                    fees: route.hops.iter().map(|hop| hop.pool.swap_fee.as_u32()).collect(),
+                    amount: V3SwapAmount::ExactInput { amount_in: route.amount_in, amount_out_min },
+                    recipient,
+                    deadline: ddl.into(),
+                    native_in: false,
+                    native_out: false,
+                };
+                info!("route swap params {:?}", swap);
+                let router = self.dex_contracts.get_v3_swap_router().await.map_err(|_| {
+                    OrderError::ContractNotFound(self.dex_exchange, ContractType::UniV3SwapRouterV2)
+                })?;
+                let tx = router.get_swap_tx(&swap).ok_or(OrderError::UnsupportedRoute)?;
+                self.ensure_v3_router_allowance(&router, token_in, route.amount_in).await?;
+                let hash = self.send_tx(tx).await?;
+                self.consume_v3_router_allowance(&router, token_in, route.amount_in).await;
+                hash
+            }
+            (PoolVariant::UniswapV2, Some(PoolVariant::UniswapV2)) => {
+                let path = route.v2_path().ok_or(OrderError::UnsupportedRoute)?;
+                info!(
+                    "v2 route swap, amount_in: {:?}, amount_out_min: {:?}, path: {:?}",
+                    route.amount_in, amount_out_min, path
+                );
+                let router = self.dex_contracts.get_v2_router().await.map_err(|_| {
+                    OrderError::ContractNotFound(self.dex_exchange, ContractType::UniV2RouterV2)
+                })?;
+                self.ensure_allowance(token_in, router.address(), route.amount_in).await?;
+                let call = router.swap_exact_tokens_for_tokens(
+                    route.amount_in,
+                    amount_out_min,
+                    path,
+                    recipient,
+                    ddl.into(),
+                );
+                let hash = self.send_tx(call.tx).await?;
+                self.consume_allowance(token_in, router.address(), route.amount_in).await;
+                hash
+            }
+            _ => return Err(OrderError::UnsupportedRoute),
+        };
+        info!("send route transaction {:?}", tx);
+        Ok(tx)
+    }
+
+    /// min amount of `token_out` to receive when selling `amount` of base, from a fresh quote and
+    /// the price that triggered the trade
+    fn get_amount_out_min(
+        &self,
+        quoted_out: U256,
+        amount: Decimal,
+        limit_price: Option<Decimal>,
+        token_out: &TokenInfo,
+    ) -> Result<U256, OrderError<M>> {
+        let expected_out = limit_price
+            .map(|price| decimal_to_wei(amount.abs() * price, token_out.decimals.into()));
+        match get_bounded_amount_out_min(quoted_out, expected_out, self.slippage_bps) {
+            Some(amount_out_min) => Ok(amount_out_min),
+            None => {
+                warn!(
+                    "reject order, quoted out {:?} is below expected {:?} by more than {:?} bps",
+                    quoted_out, expected_out, self.slippage_bps
+                );
+                Err(OrderError::SlippageExceeded {
+                    quoted: quoted_out,
+                    expected: expected_out.unwrap_or_default(),
+                })
+            }
         }
     }
 
-    //     /// # Description
-    //     /// fetch pool created events from factory contract
-    //     ///
-    //     /// Returns vector of pools created
-    //     ///
-    //     /// # Arguments
-    //     /// * `from_block` - start block number
-    //     /// * `to_block`   - end block number
-    //     pub async fn fetch_pair_created_event(
-    //         &self,
-    //         from_block: u64,
-    //         to_block: u64,
-    //     ) -> Result<Vec<Pool>> {
-    //         debug!("start fetch within range {:?}, {:?}", from_block, to_block);
-    //         match self.pool_variant {
-    //             PoolVariant::UniswapV2 => {
-    //                 let pools = self
-    //                     .v2_factory
-    //                     .as_ref()
-    //                     .unwrap()
-    //                     .pair_created_filter()
-    //                     .from_block(BlockNumber::Number(from_block.into()))
-    //                     .to_block(BlockNumber::Number(to_block.into()))
-    //                     .query()
-    //                     .await
-    //                     .unwrap()
-    //                     .into_iter()
-    //                     .map(|p| Pool {
-    //                         token_0: p.token_0,
-    //                         token_1: p.token_1,
-    //                         address: p.pair,
-    //                         swap_fee: p.p3,
-    //                         pool_variant: PoolVariant::UniswapV2,
-    //                     })
-    //                     .collect::<Vec<Pool>>();
-
-    //                 debug!(
-    //                     "{:?} number of pools created within block range from {:?} to {:?}",
-    //                     pools.len(),
-    //                     from_block,
-    //                     to_block
-    //                 );
-    //                 Ok(pools)
-    //             }
-    //             PoolVariant::UniswapV3 => {
-    //                 unimplemented!();
-    //             }
-    //         }
-    //     }
-    // }
-
-    // # Description
-    // get all pairs for a given dex between `start_block` and `current_block`
-    // # Args
-    // * `dexes` - .
-    // * `start_block` - if None, will set to the contract creation block
-    // * `end_block` - end block to sync
-    // pub async fn sync_dex<M: Middleware + 'static>(
-    //     dexes: Vec<Arc<Dex<M>>>,
-    //     start_block: Option<BlockNumber>,
-    //     end_block: BlockNumber,
-    // ) -> Result<Vec<Pool>, PairSyncError> {
-    //     // initialize multi progress bar
-    //     let multi_progress_bar = MultiProgress::new();
-
-    //     let mut handles = vec![];
-
-    //     // for each dex supplied, get all pair created events
-    //     for dex in dexes {
-    //         let progress_bar = multi_progress_bar.add(ProgressBar::new(0));
-
-    //         handles.push(tokio::spawn({
-    //             let dex = dex.clone();
-    //             async move {
-    //                 progress_bar.set_style(
-    //                     ProgressStyle::with_template(
-    //                         "{msg} {bar:40.green/grey} {pos:>7}/{len:7} Blocks",
-    //                     )
-    //                     .unwrap()
-    //                     .progress_chars("##-"),
-    //                 );
-
-    //                 let pools =
-    //                     get_all_pools(dex, start_block, end_block, progress_bar.clone()).await?;
-
-    //                 progress_bar.reset();
-    //                 progress_bar.set_style(
-    //                     ProgressStyle::with_template(
-    //                         "{msg} {bar:40.green/grey} {pos:>7}/{len:7} Pairs",
-    //                     )
-    //                     .unwrap()
-    //                     .progress_chars("##-"),
-    //                 );
-
-    //                 Ok::<Vec<Pool>, PairSyncError>(pools)
-    //             }
-    //         }));
-    //     }
-
-    //     // aggregate the populated pools from each thread
-    //     let mut aggregated_pools: Vec<Pool> = vec![];
-
-    //     for handle in handles {
-    //         match handle.await {
-    //             Ok(sync_result) => aggregated_pools.extend(sync_result?),
-    //             Err(join_error) => return Err(PairSyncError::JoinError(join_error)),
-    //         }
-    //     }
-
-    //     // return the populated aggregated pools vec
-    //     Ok(aggregated_pools)
-    // }
-
-    // function to get all pair created events for a given Dex factory address
-    // async fn get_all_pools<M: Middleware + 'static>(
-    //     dex: Arc<Dex<M>>,
-    //     start_block: Option<BlockNumber>,
-    //     end_block: BlockNumber,
-    //     progress_bar: ProgressBar,
-    // ) -> Result<Vec<Pool>, PairSyncError> {
-    //     // define the step for searching a range of blocks for pair created events
-    //     let step = 1000;
-
-    //     // get start block
-    //     let resolved_start_block = if let Some(block) = start_block {
-    //         block.as_number().unwrap().as_u64()
-    //     } else {
-    //         dex.factory_creation_block.unwrap().as_number().unwrap().as_u64()
-    //     };
-
-    //     let current_block = end_block.as_number().unwrap().as_u64();
-
-    //     // initialize the progress bar message
-    //     progress_bar.set_length(current_block - resolved_start_block);
-    //     // progress_bar.set_message(format!("Getting all pools from: {}", dex.factory_address));
-
-    //     // init a new vec to keep track of tasks
-    //     let mut handles = vec![];
-
-    //     // for each block within the range, get all pairs asynchronously
-    //     for from_block in (resolved_start_block..=current_block).step_by(step) {
-    //         let progress_bar = progress_bar.clone();
-
-    //         //Spawn a new task to get pair created events from the block range
-    //         handles.push(tokio::spawn({
-    //             let dex = dex.clone();
-    //             async move {
-    //                 let mut pools_all = vec![];
-
-    //                 //Get pair created event logs within the block range
-    //                 let to_block = from_block + step as u64;
-
-    //                 let mut pools = dex
-    //                     .fetch_pair_created_event(from_block, to_block)
-    //                     .await
-    //                     .expect("unable to fetch");
-
-    //                 // increment the progres bar by the step
-    //                 progress_bar.inc(step as u64);
-    //                 pools_all.append(&mut pools);
-
-    //                 Ok::<Vec<Pool>, ProviderError>(pools_all)
-    //             }
-    //         }));
-    //     }
-
-    //     // wait for each thread to finish and aggregate the pairs from each Dex into a single aggregated pairs vec
-    //     let mut aggregated_pairs: Vec<Pool> = vec![];
-    //     for handle in handles {
-    //         match handle.await {
-    //             Ok(sync_result) => aggregated_pairs.extend(sync_result?),
-    //             Err(join_error) => return Err(PairSyncError::JoinError(join_error)),
-    //         }
-    //     }
-    //     Ok(aggregated_pairs)
-    // }
+    /// max amount of `token_in` to pay when buying `amount` of base, from a fresh quote and the
+    /// price that triggered the trade
+    fn get_amount_in_max(
+        &self,
+        quoted_in: U256,
+        amount: Decimal,
+        limit_price: Option<Decimal>,
+        token_in: &TokenInfo,
+    ) -> Result<U256, OrderError<M>> {
+        let expected_in =
+            limit_price.map(|price| decimal_to_wei(amount.abs() * price, token_in.decimals.into()));
+        match get_bounded_amount_in_max(quoted_in, expected_in, self.slippage_bps) {
+            Some(amount_in_max) => Ok(amount_in_max),
+            None => {
+                warn!(
+                    "reject order, quoted in {:?} is above expected {:?} by more than {:?} bps",
+                    quoted_in, expected_in, self.slippage_bps
+                );
+                Err(OrderError::SlippageExceeded {
+                    quoted: quoted_in,
+                    expected: expected_in.unwrap_or_default(),
+                })
+            }
+        }
+    }
 }
 
 pub struct DexBackend<M> {
     client: Arc<M>,
     network: Network,
+    dex: DexExchange,
     sender_market_change: SyncSender<MarcketChange>,
     base_token: TokenInfo,
     base_token_quote_amt: Decimal,
     quote_token: TokenInfo,
-    v3_fee: u32,
 }
 
 impl DexBackend<Provider<Ws>> {
     pub fn new(
         client: Arc<Provider<Ws>>,
         network: Network,
+        dex: DexExchange,
         sender_market_change: SyncSender<MarcketChange>,
         base_token: TokenInfo,
         base_token_quote_amt: Decimal,
         quote_token: TokenInfo,
-        v3_fee: u32,
     ) -> Self {
         Self {
             client,
             network,
+            dex,
             sender_market_change,
             base_token,
             base_token_quote_amt,
             quote_token,
-            v3_fee,
         }
     }
 
-    pub async fn event_loop(&mut self) -> anyhow::Result<()> {
-        // TODO: currently it's hardcoded to v3
-        let quoter_address =
-            get_dex_address(DexExchange::UniswapV3, self.network, ContractType::UniV3QuoterV2)
+    /// pools of the base/quote pair for the fee tiers of `V3_FEE_TIERS` that are deployed
+    async fn get_existing_v3_pools(&self) -> anyhow::Result<Vec<(u32, Address)>> {
+        let factory_address =
+            get_dex_address(DexExchange::UniswapV3, self.network, ContractType::UniV3Factory)
                 .unwrap();
+        let factory = UniswapV3Factory::new(factory_address.address, Arc::clone(&self.client));
+
+        let calls = V3_FEE_TIERS
+            .iter()
+            .map(|fee| factory.get_pool(self.base_token.address, self.quote_token.address, *fee))
+            .collect::<Vec<_>>();
+        let pools = try_join_all(calls.iter().map(|call| call.call())).await?;
+        Ok(V3_FEE_TIERS
+            .iter()
+            .zip(pools)
+            .filter(|(_, pool)| !pool.is_zero())
+            .map(|(fee, pool)| (*fee, pool))
+            .collect())
+    }
 
-        let quoter = QuoterV2::new(quoter_address.address, Arc::clone(&self.client));
+    /// snapshot of the pool at the latest block, with the block number it was taken at
+    async fn load_v3_pool_snapshot(&self, address: Address) -> anyhow::Result<(u64, V3PoolState)> {
+        let block_number = self.client.get_block_number().await?;
+        let pool = UniswapV3Pool::new(address, Arc::clone(&self.client));
+        let state =
+            load_v3_pool_state(&pool, V3_SNAPSHOT_WORD_RADIUS, BlockId::from(block_number)).await?;
+        Ok((block_number.as_u64(), state))
+    }
 
-        let mut new_block_stream = self.client.subscribe_blocks().await.unwrap();
-        let mut last_block: u64 = 0;
+    /// retakes the snapshot of the pool at `address`. A pool whose snapshot fails, e.g. on a rate
+    /// limit, is left out of `pools` and kept in `stale_pools` until a retry succeeds
+    async fn reload_v3_pool_snapshot(
+        &self,
+        pools: &mut HashMap<Address, (u64, V3PoolState)>,
+        stale_pools: &mut HashSet<Address>,
+        address: Address,
+    ) {
+        match self.load_v3_pool_snapshot(address).await {
+            Ok(snapshot) => {
+                pools.insert(address, snapshot);
+                stale_pools.remove(&address);
+            }
+            Err(e) => {
+                error!(
+                    "error in load snapshot of pool {:?}, retry on next block, {:?}",
+                    address, e
+                );
+                pools.remove(&address);
+                stale_pools.insert(address);
+            }
+        }
+    }
+
+    /// quote every pool snapshot at each size of the depth ladder; a tier that can not fill a size
+    /// is skipped for it. Sends the ladder, with the best bid and ask of the quote amount, if it
+    /// changed since `last_spread`
+    fn publish_spread(
+        &self,
+        pools: &HashMap<Address, (u64, V3PoolState)>,
+        last_spread: &mut Option<CurrentSpread>,
+        block_number: u64,
+    ) {
+        // selling base is zero for one if base is token0, buying base is the opposite direction
+        let (token_0, _) =
+            get_token0_and_token1(&self.base_token.address, &self.quote_token.address);
+        let sell_zero_for_one = token_0.eq(&self.base_token.address);
+
+        let mut ladder = DepthLadder::default();
+        for amount in get_depth_ladder_amounts(self.base_token_quote_amt) {
+            let amount_in_wei = decimal_to_wei(amount, self.base_token.decimals.into());
+            // best bid: the tier paying the most quote for the base sold
+            let best_bid = pools
+                .values()
+                .filter_map(|(_, state)| {
+                    state
+                        .quote_exact_input(sell_zero_for_one, amount_in_wei)
+                        .filter(|amount_out| !amount_out.is_zero())
+                        .map(|amount_out| (state.fee, amount_out))
+                })
+                .max_by_key(|(_, amount_out)| *amount_out);
+            // best ask: the tier charging the least quote for the base bought
+            let best_ask = pools
+                .values()
+                .filter_map(|(_, state)| {
+                    state
+                        .quote_exact_output(!sell_zero_for_one, amount_in_wei)
+                        .filter(|amount_in| !amount_in.is_zero())
+                        .map(|amount_in| (state.fee, amount_in))
+                })
+                .min_by_key(|(_, amount_in)| *amount_in);
+
+            ladder.bids.extend(best_bid.and_then(|(fee, amount_out)| {
+                to_price_level(amount, amount_out, &self.quote_token, Some(fee))
+            }));
+            ladder.asks.extend(best_ask.and_then(|(fee, amount_in)| {
+                to_price_level(amount, amount_in, &self.quote_token, Some(fee))
+            }));
+        }
+
+        match ladder.spread_at(self.base_token_quote_amt) {
+            Some(spread) => self.send_spread(spread, last_spread, block_number),
+            None => {
+                error!("no v3 fee tier is quotable for block {:?}", block_number);
+            }
+        }
+    }
+
+    /// sends the spread if it changed since `last_spread`
+    fn send_spread(
+        &self,
+        spread: CurrentSpread,
+        last_spread: &mut Option<CurrentSpread>,
+        block_number: u64,
+    ) {
+        if last_spread.as_ref().eq(&Some(&spread)) {
+            return;
+        }
+        *last_spread = Some(spread.clone());
+
+        debug!("send dex price change, block number {:?}, sell price: {:?} (fee {:?}), buy price: {:?} (fee {:?})", block_number, spread.best_bid, spread.best_bid_v3_fee, spread.best_ask, spread.best_ask_v3_fee);
+        let ret =
+            self.sender_market_change.clone().send(MarcketChange { cex: None, dex: Some(spread) });
+        match ret {
+            Ok(()) => debug!("send price chagne success"),
+            Err(e) => {
+                error!("error in send dex price change, {:?}", e);
+                panic!("error in send dex price change, {:?}", e);
+            }
+        }
+    }
+
+    /// quotes the pair on every block and sends the spread whenever it changes
+    pub async fn event_loop(&mut self) -> anyhow::Result<()> {
+        match self.dex {
+            DexExchange::UniswapV3 => self.v3_snapshot_event_loop().await,
+            _ => self.quoter_event_loop().await,
+        }
+    }
+
+    /// quotes the pair through the `DexQuoter` of the dex, against the latest state
+    async fn quoter_event_loop(&mut self) -> anyhow::Result<()> {
+        let quoter = new_dex_quoter(Arc::clone(&self.client), self.network, self.dex)?;
+        info!(
+            "quote base token {:?}, quote token {:?} on {:?}",
+            self.base_token.token, self.quote_token.token, self.dex
+        );
+        let mut new_block_stream = self.client.subscribe_blocks().await?;
+        let amounts = get_depth_ladder_amounts(self.base_token_quote_amt);
+        let mut last_spread: Option<CurrentSpread> = None;
+
+        while let Some(block) = new_block_stream.next().await {
+            let block_number = block.number.unwrap_or_default().as_u64();
+            let ret = quoter.quote_depth(&self.base_token, &self.quote_token, &amounts).await;
+            match ret.map(|ladder| ladder.spread_at(self.base_token_quote_amt)) {
+                Ok(Some(spread)) => self.send_spread(spread, &mut last_spread, block_number),
+                Ok(None) => {
+                    error!("{:?} can not quote the pair at block {:?}", self.dex, block_number)
+                }
+                Err(e) => {
+                    error!("error in quote {:?} at block {:?}, {:?}", self.dex, block_number, e)
+                }
+            }
+        }
+        Err(anyhow::anyhow!("dex backend subscription closed"))
+    }
+
+    /// quotes the v3 pools of the pair on every block from local snapshots, which are kept in sync
+    /// from the `Swap`, `Mint` and `Burn` events of the pools. A snapshot is reloaded around the
+    /// current tick once the tick reaches an edge of its words, or a log can not be applied to it;
+    /// a pool whose reload fails is quoted again once a retry on a later block succeeds
+    async fn v3_snapshot_event_loop(&mut self) -> anyhow::Result<()> {
+        let v3_pools = self.get_existing_v3_pools().await?;
+        info!(
+            "quote base token {:?}, quote token {:?} on v3 pools {:?}",
+            self.base_token.token, self.quote_token.token, v3_pools
+        );
+
+        // subscribe before taking the snapshots, so no event after a snapshot is missed
+        let pool_addresses = v3_pools.iter().map(|(_, address)| *address).collect::<Vec<_>>();
+        let filter = Filter::new().address(pool_addresses);
+        let mut log_stream = self.client.subscribe_logs(&filter).await?;
+        let mut new_block_stream = self.client.subscribe_blocks().await?;
 
-        let (mut last_dex_sell_price, mut last_dex_buy_price) = (Decimal::ZERO, Decimal::ZERO);
+        // pool address => (block number of the snapshot, snapshot)
+        let mut pools = HashMap::new();
+        for (_, address) in v3_pools {
+            pools.insert(address, self.load_v3_pool_snapshot(address).await?);
+        }
+
+        // pools to snapshot again, left out of the quotes meanwhile
+        let mut stale_pools = HashSet::new();
+        let mut last_block: u64 = 0;
+        let mut last_spread: Option<CurrentSpread> = None;
 
         loop {
-            match new_block_stream.next().await {
-                Some(block) => {
+            tokio::select! {
+                Some(log) = log_stream.next() => {
+                    let block_number = match log.block_number {
+                        Some(block_number) => block_number.as_u64(),
+                        None => continue,
+                    };
+                    if log.removed.unwrap_or(false) {
+                        // the event was reorged out; the snapshot can not be rolled back, retake it
+                        warn!(
+                            "reorged log of pool {:?} at block {:?}, reload snapshot",
+                            log.address, block_number
+                        );
+                        self.reload_v3_pool_snapshot(&mut pools, &mut stale_pools, log.address)
+                            .await;
+                        continue;
+                    }
+                    let Some((snapshot_block, state)) = pools.get_mut(&log.address) else {
+                        continue;
+                    };
+                    if block_number <= *snapshot_block {
+                        continue;
+                    }
+                    let reload = match apply_v3_pool_log(state, &log) {
+                        // recentre the words held on the tick before swaps run out of them
+                        Ok(()) => state.is_at_word_range_edge(),
+                        Err(e) => {
+                            error!("error in apply log of pool {:?}, {:?}", log.address, e);
+                            true
+                        }
+                    };
+                    if reload {
+                        info!("reload snapshot of pool {:?} at block {:?}", log.address, block_number);
+                        self.reload_v3_pool_snapshot(&mut pools, &mut stale_pools, log.address)
+                            .await;
+                    }
+                    // a log arriving after its block was quoted
+                    if block_number <= last_block {
+                        self.publish_spread(&pools, &mut last_spread, block_number);
+                    }
+                }
+                Some(block) = new_block_stream.next() => {
                     if let Some(block_number) = block.number {
                         let new_block = block_number.as_u64();
                         if new_block > last_block {
                             last_block = new_block;
-
-                            // quote for 500, 3000, all pools
-                            let base_quote_amt_in_wei = decimal_to_wei(
-                                self.base_token_quote_amt,
-                                self.base_token.decimals.into(),
-                            );
-                            debug!("amt_in_wei: {:?}, base_token_address: {:?}, quote_token_address: {:?}", base_quote_amt_in_wei, self.base_token.address, self.quote_token.address);
-                            let rets = try_join_all([
-                                quoter
-                                    .quote_exact_input_single(QuoteExactInputSingleParams {
-                                        token_in: self.base_token.address,
-                                        token_out: self.quote_token.address,
-                                        amount_in: base_quote_amt_in_wei,
-                                        fee: self.v3_fee,
-                                        sqrt_price_limit_x96: 0.into(),
-                                    })
-                                    .call(),
-                                quoter
-                                    .quote_exact_output_single(QuoteExactOutputSingleParams {
-                                        token_in: self.quote_token.address,
-                                        token_out: self.base_token.address,
-                                        amount: base_quote_amt_in_wei,
-                                        fee: self.v3_fee,
-                                        sqrt_price_limit_x96: U256::from_str_radix(
-                                            "1461446703485210103287273052203988822378723970341",
-                                            10,
-                                        )
-                                        .unwrap(),
-                                    })
-                                    .call(),
-                            ])
-                            .await;
-
-                            match rets {
-                                Ok(ret) => {
-                                    let sell = ret[0];
-                                    let buy = ret[1];
-                                    let (amount_out, _, _, _) = sell;
-                                    let sell_price = decimal_from_wei(
-                                        amount_out,
-                                        self.quote_token.decimals.into(),
-                                    )
-                                    .checked_div(self.base_token_quote_amt)
-                                    .unwrap();
-                                    let (amount_in, _, _, _) = buy;
-                                    let buy_price = decimal_from_wei(
-                                        amount_in,
-                                        self.quote_token.decimals.into(),
-                                    )
-                                    .checked_div(self.base_token_quote_amt)
-                                    .unwrap();
-
-                                    // println!("dex price, sell {:?}, buy {:?}", sell_price, buy_price);
-                                    if !sell_price.eq(&last_dex_sell_price)
-                                        || !buy_price.eq(&last_dex_buy_price)
-                                    {
-                                        last_dex_sell_price = sell_price;
-                                        last_dex_buy_price = buy_price;
-
-                                        debug!("send dex price change, block number {:?}, sell price: {:?}, buy price: {:?} ",block.number, sell_price, buy_price);
-                                        let ret =
-                                            self.sender_market_change.clone().send(MarcketChange {
-                                                cex: None,
-                                                dex: Some(CurrentSpread {
-                                                    best_bid: sell_price,
-                                                    best_ask: buy_price,
-                                                }),
-                                            });
-                                        match ret {
-                                            Ok(()) => debug!("send price chagne success"),
-                                            Err(e) => {
-                                                error!("error in send dex price change, {:?}", e);
-                                                panic!("error in send dex price change, {:?}", e);
-                                            }
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    error!("error in quote {:?}", e);
-                                }
+                            for address in stale_pools.clone() {
+                                self.reload_v3_pool_snapshot(&mut pools, &mut stale_pools, address)
+                                    .await;
                             }
+                            self.publish_spread(&pools, &mut last_spread, new_block);
                         }
                     }
                 }
-                None => {
-                    debug!("no block info");
+                else => {
+                    return Err(anyhow::anyhow!("dex backend subscription closed"));
                 }
             }
         }
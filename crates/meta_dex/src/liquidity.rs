@@ -0,0 +1,399 @@
+use crate::{error::OrderError, receipt::decode_log, DexService};
+use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
+use meta_common::enums::ContractType;
+use meta_contracts::bindings::{
+    nonfungiblepositionmanager::{
+        CollectParams, DecreaseLiquidityParams, IncreaseLiquidityFilter, IncreaseLiquidityParams,
+        MintParams, NonfungiblePositionManager,
+    },
+    uniswapv3factory::UniswapV3Factory,
+    uniswapv3pool::UniswapV3Pool,
+};
+use meta_util::{
+    defi::{
+        get_amount_out_min,
+        uni_v3::{
+            get_amounts_for_liquidity, get_liquidity_for_amounts, get_sqrt_ratio_at_tick, MAX_TICK,
+            MIN_TICK,
+        },
+    },
+    time::get_current_ts,
+};
+use std::sync::Arc;
+use tracing::info;
+
+/// concentrated liquidity position held by the nonfungible position manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V3Position {
+    pub token_id: U256,
+    pub token_0: Address,
+    pub token_1: Address,
+    pub fee: u32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    /// tokens withdrawn or fees earned, as of the last update of the position, not collected yet
+    pub tokens_owed_0: u128,
+    pub tokens_owed_1: u128,
+}
+
+/// whether `[tick_lower, tick_upper]` is a non empty range of initializable ticks
+pub fn is_valid_tick_range(tick_lower: i32, tick_upper: i32, tick_spacing: i32) -> bool {
+    tick_spacing > 0
+        && tick_lower < tick_upper
+        && tick_lower >= MIN_TICK
+        && tick_upper <= MAX_TICK
+        && tick_lower % tick_spacing == 0
+        && tick_upper % tick_spacing == 0
+}
+
+/// id of the position minted by the transaction of `receipt` on `manager`, if any
+pub fn get_minted_token_id(receipt: &TransactionReceipt, manager: Address) -> Option<U256> {
+    receipt
+        .logs
+        .iter()
+        .filter(|log| log.address.eq(&manager))
+        .find_map(decode_log::<IncreaseLiquidityFilter>)
+        .map(|event| event.token_id)
+}
+
+/// # Description
+/// Opens, adds to, removes from and collects the fees of the concentrated liquidity positions of
+/// the wallet, through the nonfungible position manager of the v3 dex of `dex_service`. Tokens
+/// are approved and gas is priced as the swaps of `dex_service`
+///
+/// Amounts are derived from the liquidity math at the current price of the pool, and the min
+/// amounts of a change allow `slippage_bps` of `dex_service` from them
+#[derive(Debug, Clone)]
+pub struct LiquidityService<M> {
+    pub dex_service: Arc<DexService<M>>,
+    pub manager: NonfungiblePositionManager<M>,
+    pub factory: UniswapV3Factory<M>,
+}
+
+impl<M: Middleware> LiquidityService<M> {
+    /// # Description
+    /// Creates the service from the `UniV3Nft` and `UniV3Factory` of the dex in the address book
+    pub fn new(dex_service: Arc<DexService<M>>) -> Result<Self, OrderError<M>> {
+        let client = dex_service.client.clone();
+        let manager = NonfungiblePositionManager::new(
+            dex_service.get_contract_address(ContractType::UniV3Nft)?,
+            client.clone(),
+        );
+        let factory = UniswapV3Factory::new(
+            dex_service.get_contract_address(ContractType::UniV3Factory)?,
+            client,
+        );
+        Ok(Self { dex_service, manager, factory })
+    }
+
+    /// state of the position `token_id`
+    pub async fn get_position(&self, token_id: U256) -> Result<V3Position, OrderError<M>> {
+        let (
+            _nonce,
+            _operator,
+            token_0,
+            token_1,
+            fee,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            _fee_growth_inside_0,
+            _fee_growth_inside_1,
+            tokens_owed_0,
+            tokens_owed_1,
+        ) = self.manager.positions(token_id).call().await?;
+        Ok(V3Position {
+            token_id,
+            token_0,
+            token_1,
+            fee,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            tokens_owed_0,
+            tokens_owed_1,
+        })
+    }
+
+    /// positions held by `owner`, closed ones included until they are burnt
+    pub async fn get_positions(&self, owner: Address) -> Result<Vec<V3Position>, OrderError<M>> {
+        let count = self.manager.balance_of(owner).call().await?;
+        let mut positions = vec![];
+        for index in 0..count.as_u64() {
+            let call = self.manager.token_of_owner_by_index(owner, index.into());
+            positions.push(self.get_position(call.call().await?).await?);
+        }
+        Ok(positions)
+    }
+
+    /// (sqrt price, tick spacing) of the pool of the pair with `fee`
+    async fn get_pool_price(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: u32,
+    ) -> Result<(U256, i32), OrderError<M>> {
+        let address = self.factory.get_pool(token_0, token_1, fee).call().await?;
+        if address.is_zero() {
+            return Err(OrderError::NoLiquidity(self.dex_service.dex_exchange));
+        }
+        let pool = UniswapV3Pool::new(address, self.dex_service.client.clone());
+        let (slot_0_call, tick_spacing_call) = (pool.slot_0(), pool.tick_spacing());
+        let ((sqrt_price_x96, ..), tick_spacing) =
+            futures::try_join!(slot_0_call.call(), tick_spacing_call.call())?;
+        Ok((sqrt_price_x96, tick_spacing))
+    }
+
+    /// (amount0, amount1) held by the liquidity of `position` at the current price, fees excluded
+    pub async fn get_position_amounts(
+        &self,
+        position: &V3Position,
+    ) -> Result<(U256, U256), OrderError<M>> {
+        let (sqrt_price_x96, _) =
+            self.get_pool_price(position.token_0, position.token_1, position.fee).await?;
+        get_amounts_for_liquidity(
+            sqrt_price_x96,
+            get_sqrt_ratio_at_tick(position.tick_lower),
+            get_sqrt_ratio_at_tick(position.tick_upper),
+            position.liquidity,
+        )
+        .ok_or(OrderError::InsufficientLiquidity)
+    }
+
+    /// # Description
+    /// (liquidity, amount0 min, amount1 min) of depositing up to `amount_0` and `amount_1` in the
+    /// range at the current price
+    fn get_deposit(
+        &self,
+        sqrt_price_x96: U256,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: U256,
+        amount_1: U256,
+    ) -> Result<(u128, U256, U256), OrderError<M>> {
+        let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) =
+            (get_sqrt_ratio_at_tick(tick_lower), get_sqrt_ratio_at_tick(tick_upper));
+        let liquidity = get_liquidity_for_amounts(
+            sqrt_price_x96,
+            sqrt_ratio_a_x96,
+            sqrt_ratio_b_x96,
+            amount_0,
+            amount_1,
+        )
+        .filter(|liquidity| *liquidity > 0)
+        .ok_or(OrderError::InsufficientLiquidity)?;
+        let (expected_0, expected_1) = get_amounts_for_liquidity(
+            sqrt_price_x96,
+            sqrt_ratio_a_x96,
+            sqrt_ratio_b_x96,
+            liquidity,
+        )
+        .ok_or(OrderError::InsufficientLiquidity)?;
+        let slippage_bps = self.dex_service.slippage_bps;
+        Ok((
+            liquidity,
+            get_amount_out_min(expected_0, slippage_bps),
+            get_amount_out_min(expected_1, slippage_bps),
+        ))
+    }
+
+    /// approves the manager to spend the amounts of a deposit, then sends `tx`
+    async fn send_deposit_tx(
+        &self,
+        tx: TypedTransaction,
+        (token_0, amount_0): (Address, U256),
+        (token_1, amount_1): (Address, U256),
+    ) -> Result<TxHash, OrderError<M>> {
+        let manager = self.manager.address();
+        for (token, amount) in [(token_0, amount_0), (token_1, amount_1)] {
+            if !amount.is_zero() {
+                self.dex_service.ensure_allowance(token, manager, amount).await?;
+            }
+        }
+        let hash = self.dex_service.send_tx(tx).await?;
+        for (token, amount) in [(token_0, amount_0), (token_1, amount_1)] {
+            self.dex_service.consume_allowance(token, manager, amount).await;
+        }
+        Ok(hash)
+    }
+
+    /// # Description
+    /// Opens a position of `recipient` in `[tick_lower, tick_upper]` of the pool of `token_a` and
+    /// `token_b` with `fee`, depositing up to `amount_a` and `amount_b`. The id of the position
+    /// is found in the receipt with `get_minted_token_id`
+    pub async fn mint(
+        &self,
+        (token_a, amount_a): (Address, U256),
+        (token_b, amount_b): (Address, U256),
+        fee: u32,
+        (tick_lower, tick_upper): (i32, i32),
+        recipient: Address,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let ((token_0, amount_0), (token_1, amount_1)) = if token_a < token_b {
+            ((token_a, amount_a), (token_b, amount_b))
+        } else {
+            ((token_b, amount_b), (token_a, amount_a))
+        };
+        let (sqrt_price_x96, tick_spacing) = self.get_pool_price(token_0, token_1, fee).await?;
+        if !is_valid_tick_range(tick_lower, tick_upper, tick_spacing) {
+            return Err(OrderError::InvalidTickRange { tick_lower, tick_upper });
+        }
+        let (liquidity, amount_0_min, amount_1_min) =
+            self.get_deposit(sqrt_price_x96, tick_lower, tick_upper, amount_0, amount_1)?;
+
+        let params = MintParams {
+            token_0,
+            token_1,
+            fee,
+            tick_lower,
+            tick_upper,
+            amount_0_desired: amount_0,
+            amount_1_desired: amount_1,
+            amount_0_min,
+            amount_1_min,
+            recipient,
+            deadline: ddl.into(),
+        };
+        info!("mint v3 position of liquidity {:?}, params {:?}", liquidity, params);
+        let call = self.manager.mint(params);
+        self.send_deposit_tx(call.tx, (token_0, amount_0), (token_1, amount_1)).await
+    }
+
+    /// # Description
+    /// Adds up to `amount_0` of token0 and `amount_1` of token1 to the liquidity of `position`
+    pub async fn increase_liquidity(
+        &self,
+        position: &V3Position,
+        amount_0: U256,
+        amount_1: U256,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let (sqrt_price_x96, _) =
+            self.get_pool_price(position.token_0, position.token_1, position.fee).await?;
+        let (liquidity, amount_0_min, amount_1_min) = self.get_deposit(
+            sqrt_price_x96,
+            position.tick_lower,
+            position.tick_upper,
+            amount_0,
+            amount_1,
+        )?;
+
+        let params = IncreaseLiquidityParams {
+            token_id: position.token_id,
+            amount_0_desired: amount_0,
+            amount_1_desired: amount_1,
+            amount_0_min,
+            amount_1_min,
+            deadline: ddl.into(),
+        };
+        info!("increase v3 position by liquidity {:?}, params {:?}", liquidity, params);
+        let call = self.manager.increase_liquidity(params);
+        self.send_deposit_tx(call.tx, (position.token_0, amount_0), (position.token_1, amount_1))
+            .await
+    }
+
+    /// # Description
+    /// Removes `liquidity` from `position` and collects the tokens withdrawn, with the fees
+    /// earned, to `recipient` in the same transaction
+    pub async fn decrease_liquidity(
+        &self,
+        position: &V3Position,
+        liquidity: u128,
+        recipient: Address,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        if liquidity == 0 || liquidity > position.liquidity {
+            return Err(OrderError::InsufficientLiquidity);
+        }
+        let (sqrt_price_x96, _) =
+            self.get_pool_price(position.token_0, position.token_1, position.fee).await?;
+        let (expected_0, expected_1) = get_amounts_for_liquidity(
+            sqrt_price_x96,
+            get_sqrt_ratio_at_tick(position.tick_lower),
+            get_sqrt_ratio_at_tick(position.tick_upper),
+            liquidity,
+        )
+        .ok_or(OrderError::InsufficientLiquidity)?;
+
+        let slippage_bps = self.dex_service.slippage_bps;
+        let params = DecreaseLiquidityParams {
+            token_id: position.token_id,
+            liquidity,
+            amount_0_min: get_amount_out_min(expected_0, slippage_bps),
+            amount_1_min: get_amount_out_min(expected_1, slippage_bps),
+            deadline: ddl.into(),
+        };
+        info!("decrease v3 position, params {:?}", params);
+        let calls = [
+            self.manager.decrease_liquidity(params).calldata(),
+            self.get_collect_call(position, recipient).calldata(),
+        ];
+        let call = self.manager.multicall(calls.into_iter().flatten().collect());
+        let hash = self.dex_service.send_tx(call.tx).await?;
+        info!("send decrease liquidity transaction {:?}", hash);
+        Ok(hash)
+    }
+
+    /// collect of all the tokens owed to `position`
+    fn get_collect_call(
+        &self,
+        position: &V3Position,
+        recipient: Address,
+    ) -> ContractCall<M, (U256, U256)> {
+        self.manager.collect(CollectParams {
+            token_id: position.token_id,
+            recipient,
+            amount_0_max: u128::MAX,
+            amount_1_max: u128::MAX,
+        })
+    }
+
+    /// # Description
+    /// Collects the fees earned by `position`, and the tokens withdrawn but not collected, to
+    /// `recipient`
+    pub async fn collect(
+        &self,
+        position: &V3Position,
+        recipient: Address,
+    ) -> Result<TxHash, OrderError<M>> {
+        let call = self.get_collect_call(position, recipient);
+        let hash = self.dex_service.send_tx(call.tx).await?;
+        info!("send collect transaction {:?} of position {:?}", hash, position.token_id);
+        Ok(hash)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{encode, Token};
+
+    #[test]
+    fn test_is_valid_tick_range() {
+        assert!(is_valid_tick_range(-120, 60, 60));
+        assert!(!is_valid_tick_range(60, 60, 60));
+        assert!(!is_valid_tick_range(-100, 60, 60));
+        assert!(!is_valid_tick_range(MIN_TICK - 8, 0, 10));
+    }
+
+    #[test]
+    fn test_get_minted_token_id() {
+        let manager = Address::from_low_u64_be(1);
+        let log = Log {
+            address: manager,
+            topics: vec![IncreaseLiquidityFilter::signature(), H256::from_low_u64_be(42)],
+            data: encode(&[
+                Token::Uint(1000.into()),
+                Token::Uint(10.into()),
+                Token::Uint(20.into()),
+            ])
+            .into(),
+            ..Default::default()
+        };
+        let receipt = TransactionReceipt { logs: vec![log], ..Default::default() };
+        assert_eq!(get_minted_token_id(&receipt, manager), Some(U256::from(42)));
+        assert_eq!(get_minted_token_id(&receipt, Address::zero()), None);
+    }
+}
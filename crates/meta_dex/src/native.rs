@@ -0,0 +1,95 @@
+use crate::{error::OrderError, DexService};
+use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
+use meta_address::{get_wrapped_native_token_info, TokenInfo};
+use meta_contracts::bindings::weth9::WETH9;
+use meta_util::ether::decimal_to_wei;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use tracing::info;
+
+impl<M: Middleware> DexService<M> {
+    /// the token wrapping the native token of the network, such as WETH or WBNB
+    pub fn get_wrapped_native_token(&self) -> Result<TokenInfo, OrderError<M>> {
+        get_wrapped_native_token_info(self.network)
+            .ok_or(OrderError::NativeTokenNotFound(self.network))
+    }
+
+    /// address of `token` in pools and swap paths; the wrapped native token stands for the native
+    /// one
+    pub fn get_pool_token_address(&self, token: &TokenInfo) -> Result<Address, OrderError<M>> {
+        match token.native {
+            true => Ok(self.get_wrapped_native_token()?.address),
+            false => Ok(token.address),
+        }
+    }
+
+    /// # Description
+    /// Wraps `amount` of the native token of the wallet, through `deposit` of the wrapped token
+    pub async fn wrap_native(&self, amount: Decimal) -> Result<TxHash, OrderError<M>> {
+        let wrapped = self.get_wrapped_native_token()?;
+        let tx = get_wrap_native_tx(self.client.clone(), &wrapped, amount);
+        let hash = self.send_tx(tx).await?;
+        info!("send wrap of {:?} to {:?} transaction {:?}", amount, wrapped.token, hash);
+        Ok(hash)
+    }
+
+    /// # Description
+    /// Unwraps `amount` of the wrapped native token of the wallet, through `withdraw`
+    pub async fn unwrap_native(&self, amount: Decimal) -> Result<TxHash, OrderError<M>> {
+        let wrapped = self.get_wrapped_native_token()?;
+        let tx = get_unwrap_native_tx(self.client.clone(), &wrapped, amount);
+        let hash = self.send_tx(tx).await?;
+        info!("send unwrap of {:?} {:?} transaction {:?}", amount, wrapped.token, hash);
+        Ok(hash)
+    }
+}
+
+/// transaction depositing `amount` of the native token into `wrapped`
+pub fn get_wrap_native_tx<M: Middleware>(
+    client: Arc<M>,
+    wrapped: &TokenInfo,
+    amount: Decimal,
+) -> TypedTransaction {
+    let weth = WETH9::new(wrapped.address, client);
+    weth.deposit().value(decimal_to_wei(amount, wrapped.decimals.into())).tx
+}
+
+/// transaction withdrawing `amount` of `wrapped` into the native token
+pub fn get_unwrap_native_tx<M: Middleware>(
+    client: Arc<M>,
+    wrapped: &TokenInfo,
+    amount: Decimal,
+) -> TypedTransaction {
+    let weth = WETH9::new(wrapped.address, client);
+    weth.withdraw(decimal_to_wei(amount, wrapped.decimals.into())).tx
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{encode, Token as AbiToken};
+    use meta_common::enums::Network;
+
+    #[test]
+    fn test_wrap_native_tx() {
+        let (provider, _) = Provider::mocked();
+        let client = Arc::new(provider);
+        let weth = get_wrapped_native_token_info(Network::ARBI).unwrap();
+        let amount = Decimal::new(15, 1);
+        let wei = U256::from(1_500_000_000_000_000_000u64);
+
+        // the native amount is the value of the deposit
+        let tx = get_wrap_native_tx(client.clone(), &weth, amount);
+        assert_eq!(tx.to_addr(), Some(&weth.address));
+        assert_eq!(tx.value(), Some(&wei));
+        assert_eq!(tx.data().unwrap().to_vec(), vec![0xd0, 0xe3, 0x0d, 0xb0]);
+
+        // the wrapped amount is the argument of the withdrawal
+        let tx = get_unwrap_native_tx(client, &weth, amount);
+        assert_eq!(tx.to_addr(), Some(&weth.address));
+        assert_eq!(tx.value().copied().unwrap_or_default(), U256::zero());
+        let mut data = vec![0x2e, 0x1a, 0x7d, 0x4d];
+        data.extend(encode(&[AbiToken::Uint(wei)]));
+        assert_eq!(tx.data().unwrap().to_vec(), data);
+    }
+}
@@ -1,7 +1,20 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 use ethers::prelude::*;
-use meta_util::ether::calculate_next_block_base_fee;
+use meta_common::enums::Network;
+use meta_util::ether::{calculate_next_block_base_fee_of, get_block_time_millis};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// number of latest block headers kept by the oracle to detect reorgs
+pub const BLOCK_HEADER_BUFFER_SIZE: usize = 64;
+
+/// seconds from a block to the next one on `network`, rounded up: block timestamps are in
+/// seconds, and a block mined sooner than that (e.g. every 250 ms on arbitrum) still gets a
+/// timestamp ahead of its parent at worst by one second
+fn get_next_block_delay_secs(network: Network) -> u64 {
+    get_block_time_millis(network).div_ceil(1000)
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct BlockInfo {
@@ -16,25 +29,46 @@ impl BlockInfo {
         Self { number, timestamp, base_fee }
     }
 
-    // Find the next block ahead of `prev_block`
-    pub fn find_next_block_info(prev_block: Block<TxHash>) -> Self {
+    // Find the next block ahead of `prev_block` on `network`
+    pub fn find_next_block_info(network: Network, prev_block: Block<TxHash>) -> Self {
         let number = prev_block.number.unwrap_or_default() + 1;
-        let timestamp = prev_block.timestamp + 12;
-        let base_fee = calculate_next_block_base_fee(prev_block);
+        let timestamp = prev_block.timestamp + get_next_block_delay_secs(network);
+        let base_fee = calculate_next_block_base_fee_of(network, prev_block);
 
         Self { number, timestamp, base_fee }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub number: U64,
+    pub hash: H256,
+    pub parent_hash: H256,
+}
+
+/// the canonical chain switched to `new_head`, replacing the blocks after `common_ancestor`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorgEvent {
+    /// number of blocks replaced
+    pub depth: u64,
+    /// latest block kept; `None` if the reorg is deeper than the header buffer
+    pub common_ancestor: Option<U64>,
+    pub new_head: BlockHeader,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct BlockOracle {
+    pub network: Network,
     pub latest_block: BlockInfo,
     pub next_block: BlockInfo,
+    /// latest headers of the canonical chain, oldest first
+    pub headers: VecDeque<BlockHeader>,
+    reorg_sender: Option<broadcast::Sender<ReorgEvent>>,
 }
 
 impl BlockOracle {
     // Create new latest block oracle
-    pub async fn new(client: &Arc<Provider<Ws>>) -> Result<Self, ProviderError> {
+    pub async fn new(client: &Arc<Provider<Ws>>, network: Network) -> Result<Self, ProviderError> {
         let latest_block = match client.get_block(BlockNumber::Latest).await {
             Ok(b) => b,
             Err(e) => return Err(e),
@@ -46,21 +80,84 @@ impl BlockOracle {
             return Err(ProviderError::CustomError("Block not found".to_string()));
         };
 
-        // latets block info
-        let number = lb.number.unwrap();
-        let timestamp = lb.timestamp;
-        let base_fee = lb.base_fee_per_gas.unwrap_or_default();
+        let mut oracle = BlockOracle { network, ..Default::default() };
+        oracle.update(lb);
+        Ok(oracle)
+    }
 
-        let latest_block = BlockInfo::new(number, timestamp, base_fee);
+    /// subscribe to the reorgs detected from now on
+    pub fn subscribe_reorgs(&mut self) -> broadcast::Receiver<ReorgEvent> {
+        match self.reorg_sender {
+            Some(ref sender) => sender.subscribe(),
+            None => {
+                let (sender, receiver) = broadcast::channel(16);
+                self.reorg_sender = Some(sender);
+                receiver
+            }
+        }
+    }
 
-        // next block info
-        let number = number + 1;
-        let timestamp = timestamp + 12;
-        let base_fee = calculate_next_block_base_fee(lb);
+    /// # Description
+    /// Update the oracle with a new head of the chain
+    ///
+    /// Returns the reorg if `block` does not extend the latest known head; it is also sent to
+    /// the subscribers of `subscribe_reorgs`
+    pub fn update(&mut self, block: Block<TxHash>) -> Option<ReorgEvent> {
+        let header = BlockHeader {
+            number: block.number.unwrap_or_default(),
+            hash: block.hash.unwrap_or_default(),
+            parent_hash: block.parent_hash,
+        };
+        let reorg = self.push_header(header);
 
-        let next_block = BlockInfo::new(number, timestamp, base_fee);
+        self.update_block_number(header.number);
+        self.update_block_timestamp(block.timestamp);
+        self.update_base_fee(block);
 
-        Ok(BlockOracle { latest_block, next_block })
+        if let (Some(reorg), Some(sender)) = (reorg, self.reorg_sender.as_ref()) {
+            // no receiver left is not an error for the oracle
+            let _ = sender.send(reorg);
+        }
+        reorg
+    }
+
+    fn push_header(&mut self, header: BlockHeader) -> Option<ReorgEvent> {
+        let reorg = match self.headers.back() {
+            None => None,
+            Some(last) if last.hash == header.hash => return None,
+            Some(last) if last.hash == header.parent_hash && last.number + 1 == header.number => {
+                None
+            }
+            Some(last) if header.number > last.number + 1 => {
+                // missed some blocks, so the parent can not be checked; start over
+                warn!("block gap from {:?} to {:?}, reset headers", last.number, header.number);
+                self.headers.clear();
+                None
+            }
+            Some(_) => {
+                // drop the replaced blocks, down to the parent of the new head
+                let mut depth = 0;
+                while let Some(last) = self.headers.back() {
+                    if last.number < header.number && last.hash == header.parent_hash {
+                        break;
+                    }
+                    self.headers.pop_back();
+                    depth += 1;
+                }
+                let common_ancestor = self.headers.back().map(|h| h.number);
+                warn!(
+                    "reorg of depth {:?} to block {:?}, common ancestor {:?}",
+                    depth, header.number, common_ancestor
+                );
+                Some(ReorgEvent { depth, common_ancestor, new_head: header })
+            }
+        };
+
+        self.headers.push_back(header);
+        while self.headers.len() > BLOCK_HEADER_BUFFER_SIZE {
+            self.headers.pop_front();
+        }
+        reorg
     }
 
     // Updates block's number
@@ -72,12 +169,78 @@ impl BlockOracle {
     // Updates block's timestamp
     pub fn update_block_timestamp(&mut self, timestamp: U256) {
         self.latest_block.timestamp = timestamp;
-        self.next_block.timestamp = timestamp + 12;
+        self.next_block.timestamp = timestamp + get_next_block_delay_secs(self.network);
     }
 
     // Updates block's base fee
     pub fn update_base_fee(&mut self, latest_block: Block<TxHash>) {
         self.latest_block.base_fee = latest_block.base_fee_per_gas.unwrap_or_default();
-        self.next_block.base_fee = calculate_next_block_base_fee(latest_block);
+        self.next_block.base_fee = calculate_next_block_base_fee_of(self.network, latest_block);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn block(number: u64, hash: u64, parent_hash: u64) -> Block<TxHash> {
+        Block {
+            number: Some(U64::from(number)),
+            hash: Some(H256::from_low_u64_be(hash)),
+            parent_hash: H256::from_low_u64_be(parent_hash),
+            gas_limit: U256::from(30_000_000u64),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_update_detects_reorg() {
+        let mut oracle = BlockOracle { network: Network::ETH, ..Default::default() };
+        let mut reorgs = oracle.subscribe_reorgs();
+        assert_eq!(oracle.update(block(100, 100, 99)), None);
+        assert_eq!(oracle.update(block(101, 101, 100)), None);
+        assert_eq!(oracle.update(block(102, 102, 101)), None);
+        // the same head again
+        assert_eq!(oracle.update(block(102, 102, 101)), None);
+
+        // 101 and 102 are replaced by 1101, then the new chain continues
+        let reorg = oracle.update(block(101, 1101, 100)).unwrap();
+        assert_eq!(reorg.depth, 2);
+        assert_eq!(reorg.common_ancestor, Some(U64::from(100)));
+        assert_eq!(reorgs.try_recv().unwrap(), reorg);
+        assert_eq!(oracle.update(block(102, 1102, 1101)), None);
+        assert_eq!(oracle.latest_block.number, U64::from(102));
+        assert_eq!(oracle.headers.len(), 3);
+
+        // a reorg deeper than the known headers
+        let reorg = oracle.update(block(102, 2102, 2101)).unwrap();
+        assert_eq!(reorg.depth, 3);
+        assert_eq!(reorg.common_ancestor, None);
+    }
+
+    #[test]
+    fn test_update_keeps_header_buffer_size() {
+        let mut oracle = BlockOracle { network: Network::BSC, ..Default::default() };
+        for number in 1..=(BLOCK_HEADER_BUFFER_SIZE as u64 + 10) {
+            assert_eq!(oracle.update(block(number, number, number - 1)), None);
+        }
+        assert_eq!(oracle.headers.len(), BLOCK_HEADER_BUFFER_SIZE);
+        assert_eq!(oracle.next_block.base_fee, U256::zero());
+
+        // blocks were missed, the parent can not be checked
+        assert_eq!(oracle.update(block(1000, 1000, 999)), None);
+        assert_eq!(oracle.headers.len(), 1);
+    }
+
+    #[test]
+    fn test_update_block_timestamp() {
+        let mut oracle = BlockOracle { network: Network::ETH, ..Default::default() };
+        oracle.update_block_timestamp(U256::from(1_700_000_000u64));
+        assert_eq!(oracle.next_block.timestamp, U256::from(1_700_000_012u64));
+
+        // blocks of 250 ms are still one second ahead at most
+        let mut oracle = BlockOracle { network: Network::ARBI, ..Default::default() };
+        oracle.update_block_timestamp(U256::from(1_700_000_000u64));
+        assert_eq!(oracle.next_block.timestamp, U256::from(1_700_000_001u64));
     }
 }
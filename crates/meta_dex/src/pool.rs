@@ -2,14 +2,18 @@ use std::hash::{Hash, Hasher};
 
 use ethers::prelude::*;
 use meta_common::enums::PoolVariant;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pool {
     pub address: Address,
     pub token_0: Address,
     pub token_1: Address,
     pub swap_fee: U256,
     pub pool_variant: PoolVariant,
+    /// factory that created the pool, `None` if unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<Address>,
 }
 
 impl Pool {
@@ -24,7 +28,17 @@ impl Pool {
         let (token_0, token_1) =
             if token_a < token_b { (token_a, token_b) } else { (token_b, token_a) };
 
-        Pool { address, token_0, token_1, swap_fee, pool_variant }
+        Pool { address, token_0, token_1, swap_fee, pool_variant, factory: None }
+    }
+
+    pub fn with_factory(mut self, factory: Address) -> Self {
+        self.factory = Some(factory);
+        self
+    }
+
+    /// whether the pool was created by one of `factories`, which their routers can swap through
+    pub fn is_created_by(&self, factories: &[Address]) -> bool {
+        self.factory.is_some_and(|factory| factories.contains(&factory))
     }
 }
 
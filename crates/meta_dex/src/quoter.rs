@@ -0,0 +1,738 @@
+use crate::sync::get_v2_swap_fee;
+use async_trait::async_trait;
+use ethers::prelude::*;
+use futures::future::join_all;
+use meta_address::{get_dex_address, TokenInfo};
+use meta_common::{
+    enums::{ContractType, DexExchange, Network},
+    models::{CurrentSpread, DepthLadder, PriceLevel},
+};
+use meta_contracts::bindings::{
+    izumiquoter::IzumiQuoter,
+    muteswitchfactory::MuteSwitchFactory,
+    muteswitchpair::MuteSwitchPair,
+    quoterv2::{QuoteExactInputSingleParams, QuoteExactOutputSingleParams, QuoterV2},
+    syncswapclassicpool::SyncSwapClassicPool,
+    syncswapclassicpoolfactory::SyncSwapClassicPoolFactory,
+    uniswapv2factory::UniswapV2Factory,
+    uniswapv2pair::UniswapV2Pair,
+};
+use meta_util::{
+    defi::{encode_v3_path, get_token0_and_token1, get_v2_amount_in, get_v2_amount_out},
+    ether::{decimal_from_wei, decimal_to_wei},
+};
+use rust_decimal::Decimal;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+/// fee tiers (in hundredths of a bip) enabled on the pancake v3 style factory of agni
+pub const AGNI_FEE_TIERS: [u32; 4] = [100, 500, 2500, 10000];
+
+/// fee tiers (in hundredths of a bip) enabled on the izumi factory
+pub const IZUMI_FEE_TIERS: [u32; 4] = [100, 400, 2000, 10000];
+
+/// sizes of the depth ladder, in bp of the configured quote amount
+pub const DEPTH_LADDER_MULTIPLIERS_BPS: [u32; 5] = [2_500, 5_000, 10_000, 20_000, 40_000];
+
+/// amount quoted by a dex, with the fee tier of the pool that quoted it (v3 style dexes only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quote {
+    pub amount: U256,
+    pub fee: Option<u32>,
+}
+
+/// # Description
+/// Quotes swaps of a dex, whatever its protocol family.
+///
+/// A quote is `Ok(None)` if the dex has no pool for the pair, or the pool can not fill the amount
+#[async_trait]
+pub trait DexQuoter: Send + Sync {
+    fn dex(&self) -> DexExchange;
+
+    /// amount of `token_out` received for `amount_in` of `token_in`
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>>;
+
+    /// amount of `token_in` paid for `amount_out` of `token_out`
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>>;
+
+    /// # Description
+    /// bid and ask (in quote per base) of selling and buying `amount` of base
+    ///
+    /// Returns `None` if either side can not be quoted
+    async fn quote_spread(
+        &self,
+        base: &TokenInfo,
+        quote: &TokenInfo,
+        amount: Decimal,
+    ) -> anyhow::Result<Option<CurrentSpread>> {
+        let amount_in_wei = decimal_to_wei(amount, base.decimals.into());
+        let (bid, ask) = futures::try_join!(
+            self.quote_exact_in(base.address, quote.address, amount_in_wei),
+            self.quote_exact_out(quote.address, base.address, amount_in_wei),
+        )?;
+        let (bid, ask) = match (bid, ask) {
+            (Some(bid), Some(ask)) if !bid.amount.is_zero() && !ask.amount.is_zero() => (bid, ask),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(CurrentSpread {
+            best_bid: decimal_from_wei(bid.amount, quote.decimals.into())
+                .checked_div(amount)
+                .unwrap_or_default(),
+            best_ask: decimal_from_wei(ask.amount, quote.decimals.into())
+                .checked_div(amount)
+                .unwrap_or_default(),
+            best_bid_v3_fee: bid.fee,
+            best_ask_v3_fee: ask.fee,
+            depth: None,
+        }))
+    }
+
+    /// # Description
+    /// bid and ask levels of selling and buying each of `amounts` of base
+    ///
+    /// A size that can not be quoted on one side has no level on that side
+    async fn quote_depth(
+        &self,
+        base: &TokenInfo,
+        quote: &TokenInfo,
+        amounts: &[Decimal],
+    ) -> anyhow::Result<DepthLadder> {
+        let quotes = amounts.iter().map(|amount| async move {
+            let amount_in_wei = decimal_to_wei(*amount, base.decimals.into());
+            let (bid, ask) = futures::try_join!(
+                self.quote_exact_in(base.address, quote.address, amount_in_wei),
+                self.quote_exact_out(quote.address, base.address, amount_in_wei),
+            )?;
+            anyhow::Ok((*amount, bid, ask))
+        });
+
+        let mut ladder = DepthLadder::default();
+        for (amount, bid, ask) in futures::future::try_join_all(quotes).await? {
+            let bid = bid.and_then(|bid| to_price_level(amount, bid.amount, quote, bid.fee));
+            let ask = ask.and_then(|ask| to_price_level(amount, ask.amount, quote, ask.fee));
+            ladder.bids.extend(bid);
+            ladder.asks.extend(ask);
+        }
+        Ok(ladder)
+    }
+}
+
+/// sizes of the depth ladder around `amount`, ascending
+pub fn get_depth_ladder_amounts(amount: Decimal) -> Vec<Decimal> {
+    DEPTH_LADDER_MULTIPLIERS_BPS
+        .iter()
+        .map(|bps| amount * Decimal::from(*bps) / Decimal::from(10_000))
+        .collect()
+}
+
+/// level of trading `amount` of base against `quote_amount_in_wei` of quote; `None` if nothing
+/// is quoted
+pub fn to_price_level(
+    amount: Decimal,
+    quote_amount_in_wei: U256,
+    quote: &TokenInfo,
+    v3_fee: Option<u32>,
+) -> Option<PriceLevel> {
+    if quote_amount_in_wei.is_zero() {
+        return None;
+    }
+    let price = decimal_from_wei(quote_amount_in_wei, quote.decimals.into()).checked_div(amount)?;
+    Some(PriceLevel { amount, price, v3_fee })
+}
+
+/// # Description
+/// Creates the quoter of `dex` on `network` from the dex address book
+pub fn new_dex_quoter<M: Middleware + 'static>(
+    client: Arc<M>,
+    network: Network,
+    dex: DexExchange,
+) -> anyhow::Result<Box<dyn DexQuoter>> {
+    let get_address = |contract_type: ContractType| {
+        get_dex_address(dex, network, contract_type.clone()).map(|info| info.address).ok_or_else(
+            || anyhow::anyhow!("no {} of {} on {} in address book", contract_type, dex, network),
+        )
+    };
+    let quoter: Box<dyn DexQuoter> = match dex {
+        DexExchange::UniswapV3 | DexExchange::AGNI => {
+            let fee_tiers = match dex {
+                DexExchange::AGNI => AGNI_FEE_TIERS.to_vec(),
+                _ => crate::V3_FEE_TIERS.to_vec(),
+            };
+            let quoter = QuoterV2::new(get_address(ContractType::UniV3QuoterV2)?, client);
+            Box::new(UniV3Quoter { dex, quoter, fee_tiers })
+        }
+        DexExchange::IZUMI => {
+            let quoter = IzumiQuoter::new(get_address(ContractType::IzumiQuoter)?, client);
+            Box::new(IzumiDexQuoter { quoter, fee_tiers: IZUMI_FEE_TIERS.to_vec() })
+        }
+        DexExchange::MuteSwitch => {
+            let factory = MuteSwitchFactory::new(get_address(ContractType::UniV2Factory)?, client);
+            Box::new(MuteSwitchQuoter::new(factory))
+        }
+        DexExchange::SyncSwap => {
+            let stable_factory = get_address(ContractType::SyncSwapStablePoolFactory)
+                .ok()
+                .map(|address| SyncSwapClassicPoolFactory::new(address, client.clone()));
+            let factory =
+                SyncSwapClassicPoolFactory::new(get_address(ContractType::UniV2Factory)?, client);
+            Box::new(SyncSwapQuoter::new(factory, stable_factory))
+        }
+        DexExchange::PANCAKE
+        | DexExchange::BISWAP
+        | DexExchange::SUSHISWAP
+        | DexExchange::UniswapV2 => {
+            let factory = UniswapV2Factory::new(get_address(ContractType::UniV2Factory)?, client);
+            Box::new(UniV2Quoter {
+                dex,
+                factory,
+                fee: get_v2_swap_fee(dex),
+                pairs: RwLock::new(HashMap::new()),
+            })
+        }
+        DexExchange::None => return Err(anyhow::anyhow!("no dex to quote")),
+    };
+    Ok(quoter)
+}
+
+/// pool of the pair, looked up from `fetch` and cached once it exists; `None` if the pair has no
+/// pool yet
+async fn get_cached_pool<F>(
+    pools: &RwLock<HashMap<(Address, Address), Address>>,
+    token_a: Address,
+    token_b: Address,
+    fetch: F,
+) -> anyhow::Result<Option<Address>>
+where
+    F: std::future::Future<Output = anyhow::Result<Address>>,
+{
+    let key = get_token0_and_token1(&token_a, &token_b);
+    if let Some(pool) = pools.read().await.get(&key) {
+        return Ok(Some(*pool));
+    }
+    let pool = fetch.await?;
+    if pool.is_zero() {
+        // the pool may be created later
+        return Ok(None);
+    }
+    pools.write().await.insert(key, pool);
+    Ok(Some(pool))
+}
+
+/// # Description
+/// Values of the quote calls that succeeded, with their keys. A call that reverted is dropped, as
+/// quoters and pools revert for a fee tier without pool or an amount beyond the reserves; any
+/// other error, such as of the rpc, is returned
+fn drop_reverted<K, T, M: Middleware>(
+    rets: impl IntoIterator<Item = (K, Result<T, ContractError<M>>)>,
+) -> anyhow::Result<Vec<(K, T)>> {
+    let mut values = vec![];
+    for (key, ret) in rets {
+        match ret {
+            Ok(value) => values.push((key, value)),
+            Err(e) if e.is_revert() => {}
+            Err(e) => return Err(anyhow::anyhow!("error in quote call, {:?}", e)),
+        }
+    }
+    Ok(values)
+}
+
+/// quotes a uniswap v2 style dex from the reserves of its pairs
+pub struct UniV2Quoter<M> {
+    dex: DexExchange,
+    factory: UniswapV2Factory<M>,
+    /// swap fee in hundredths of a bip
+    fee: u32,
+    pairs: RwLock<HashMap<(Address, Address), Address>>,
+}
+
+impl<M: Middleware + 'static> UniV2Quoter<M> {
+    /// reserves of `token_in` and `token_out` in their pair
+    async fn get_reserves(
+        &self,
+        token_in: Address,
+        token_out: Address,
+    ) -> anyhow::Result<Option<(U256, U256)>> {
+        let get_pair = self.factory.get_pair(token_in, token_out);
+        let fetch = async { Ok(get_pair.call().await?) };
+        let pair = match get_cached_pool(&self.pairs, token_in, token_out, fetch).await? {
+            Some(pair) => pair,
+            None => return Ok(None),
+        };
+        let (reserve_0, reserve_1, _) =
+            UniswapV2Pair::new(pair, self.factory.client()).get_reserves().call().await?;
+        let (reserve_0, reserve_1) = (U256::from(reserve_0), U256::from(reserve_1));
+        if token_in < token_out {
+            Ok(Some((reserve_0, reserve_1)))
+        } else {
+            Ok(Some((reserve_1, reserve_0)))
+        }
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexQuoter for UniV2Quoter<M> {
+    fn dex(&self) -> DexExchange {
+        self.dex
+    }
+
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        Ok(self.get_reserves(token_in, token_out).await?.map(|(reserve_in, reserve_out)| Quote {
+            amount: get_v2_amount_out(amount_in, reserve_in, reserve_out, self.fee),
+            fee: None,
+        }))
+    }
+
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        Ok(self.get_reserves(token_in, token_out).await?.and_then(|(reserve_in, reserve_out)| {
+            get_v2_amount_in(amount_out, reserve_in, reserve_out, self.fee)
+                .map(|amount| Quote { amount, fee: None })
+        }))
+    }
+}
+
+/// quotes the volatile pairs of mute switch, whose fee is set per pair
+pub struct MuteSwitchQuoter<M> {
+    factory: MuteSwitchFactory<M>,
+    pairs: RwLock<HashMap<(Address, Address), Address>>,
+}
+
+impl<M: Middleware> MuteSwitchQuoter<M> {
+    pub fn new(factory: MuteSwitchFactory<M>) -> Self {
+        Self { factory, pairs: RwLock::new(HashMap::new()) }
+    }
+
+    async fn get_pair(
+        &self,
+        token_in: Address,
+        token_out: Address,
+    ) -> anyhow::Result<Option<MuteSwitchPair<M>>> {
+        let get_pair = self.factory.get_pair(token_in, token_out, false);
+        let fetch = async { get_pair.call().await.map_err(|e| anyhow::anyhow!("{:?}", e)) };
+        let pair = get_cached_pool(&self.pairs, token_in, token_out, fetch).await?;
+        Ok(pair.map(|pair| MuteSwitchPair::new(pair, self.factory.client())))
+    }
+
+    /// amount of `token_out` the volatile pair pays for `amount_in` of `token_in`
+    pub async fn get_amount_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<U256>> {
+        let pair = match self.get_pair(token_in, token_out).await? {
+            Some(pair) => pair,
+            None => return Ok(None),
+        };
+        let call = pair.get_amount_out(amount_in, token_in);
+        Ok(Some(call.call().await.map_err(|e| anyhow::anyhow!("{:?}", e))?))
+    }
+
+    /// amount of `token_in` the volatile pair charges for `amount_out` of `token_out`
+    pub async fn get_amount_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<U256>> {
+        let pair = match self.get_pair(token_in, token_out).await? {
+            Some(pair) => pair,
+            None => return Ok(None),
+        };
+        let (reserves_call, fee_call) = (pair.get_reserves(), pair.pair_fee());
+        let ((reserve_0, reserve_1, _), pair_fee) =
+            futures::try_join!(reserves_call.call(), fee_call.call())
+                .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        let (reserve_in, reserve_out) =
+            if token_in < token_out { (reserve_0, reserve_1) } else { (reserve_1, reserve_0) };
+        // pair fee is in bp
+        let fee = pair_fee.as_u32() * 100;
+        Ok(get_v2_amount_in(amount_out, reserve_in, reserve_out, fee))
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexQuoter for MuteSwitchQuoter<M> {
+    fn dex(&self) -> DexExchange {
+        DexExchange::MuteSwitch
+    }
+
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let amount = self.get_amount_out(token_in, token_out, amount_in).await?;
+        Ok(amount.map(|amount| Quote { amount, fee: None }))
+    }
+
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let amount = self.get_amount_in(token_in, token_out, amount_out).await?;
+        Ok(amount.map(|amount| Quote { amount, fee: None }))
+    }
+}
+
+/// quotes the classic and stable pools of syncswap, which price swaps themselves, keeping the best
+pub struct SyncSwapQuoter<M> {
+    factory: SyncSwapClassicPoolFactory<M>,
+    /// factory of the stable pools, which share the interface of the classic ones
+    stable_factory: Option<SyncSwapClassicPoolFactory<M>>,
+    pools: RwLock<HashMap<(Address, Address), Address>>,
+    stable_pools: RwLock<HashMap<(Address, Address), Address>>,
+}
+
+impl<M: Middleware> SyncSwapQuoter<M> {
+    pub fn new(
+        factory: SyncSwapClassicPoolFactory<M>,
+        stable_factory: Option<SyncSwapClassicPoolFactory<M>>,
+    ) -> Self {
+        Self {
+            factory,
+            stable_factory,
+            pools: RwLock::new(HashMap::new()),
+            stable_pools: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// classic and stable pools of the pair
+    async fn get_pools(
+        &self,
+        token_in: Address,
+        token_out: Address,
+    ) -> anyhow::Result<Vec<SyncSwapClassicPool<M>>> {
+        let get_pool = self.factory.get_pool(token_in, token_out);
+        let fetch = async { get_pool.call().await.map_err(|e| anyhow::anyhow!("{:?}", e)) };
+        let mut pools = get_cached_pool(&self.pools, token_in, token_out, fetch)
+            .await?
+            .into_iter()
+            .collect::<Vec<_>>();
+        if let Some(ref stable_factory) = self.stable_factory {
+            let get_pool = stable_factory.get_pool(token_in, token_out);
+            let fetch = async { get_pool.call().await.map_err(|e| anyhow::anyhow!("{:?}", e)) };
+            pools.extend(get_cached_pool(&self.stable_pools, token_in, token_out, fetch).await?);
+        }
+        Ok(pools
+            .into_iter()
+            .map(|pool| SyncSwapClassicPool::new(pool, self.factory.client()))
+            .collect())
+    }
+
+    /// # Description
+    /// The pool paying the most of `token_out` for `amount_in` of `token_in`, with the amount
+    pub async fn get_best_amount_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<(Address, U256)>> {
+        let pools = self.get_pools(token_in, token_out).await?;
+        // the sender only matters to pools with a fee manager discount
+        let calls = pools
+            .iter()
+            .map(|pool| pool.get_amount_out(token_in, amount_in, Address::zero()))
+            .collect::<Vec<_>>();
+        // a pool reverts for an amount beyond its reserves
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(pools.iter().map(|pool| pool.address()).zip(rets))?
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .max_by_key(|(_, amount)| *amount))
+    }
+
+    /// # Description
+    /// The pool charging the least of `token_in` for `amount_out` of `token_out`, with the amount
+    pub async fn get_best_amount_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<(Address, U256)>> {
+        let pools = self.get_pools(token_in, token_out).await?;
+        let calls = pools
+            .iter()
+            .map(|pool| pool.get_amount_in(token_out, amount_out, Address::zero()))
+            .collect::<Vec<_>>();
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(pools.iter().map(|pool| pool.address()).zip(rets))?
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .min_by_key(|(_, amount)| *amount))
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexQuoter for SyncSwapQuoter<M> {
+    fn dex(&self) -> DexExchange {
+        DexExchange::SyncSwap
+    }
+
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let best = self.get_best_amount_out(token_in, token_out, amount_in).await?;
+        Ok(best.map(|(_, amount)| Quote { amount, fee: None }))
+    }
+
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let best = self.get_best_amount_in(token_in, token_out, amount_out).await?;
+        Ok(best.map(|(_, amount)| Quote { amount, fee: None }))
+    }
+}
+
+/// quotes every fee tier of a uniswap v3 style dex through its QuoterV2, keeping the best
+pub struct UniV3Quoter<M> {
+    dex: DexExchange,
+    quoter: QuoterV2<M>,
+    fee_tiers: Vec<u32>,
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexQuoter for UniV3Quoter<M> {
+    fn dex(&self) -> DexExchange {
+        self.dex
+    }
+
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let calls = self
+            .fee_tiers
+            .iter()
+            .map(|fee| {
+                self.quoter.quote_exact_input_single(QuoteExactInputSingleParams {
+                    token_in,
+                    token_out,
+                    amount_in,
+                    fee: *fee,
+                    sqrt_price_limit_x96: U256::zero(),
+                })
+            })
+            .collect::<Vec<_>>();
+        // the quoter reverts for a tier without pool
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(self.fee_tiers.iter().zip(rets))?
+            .into_iter()
+            .map(|(fee, (amount, ..))| Quote { amount, fee: Some(*fee) })
+            .max_by_key(|quote| quote.amount))
+    }
+
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        let calls = self
+            .fee_tiers
+            .iter()
+            .map(|fee| {
+                self.quoter.quote_exact_output_single(QuoteExactOutputSingleParams {
+                    token_in,
+                    token_out,
+                    amount: amount_out,
+                    fee: *fee,
+                    sqrt_price_limit_x96: U256::zero(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(self.fee_tiers.iter().zip(rets))?
+            .into_iter()
+            .map(|(fee, (amount, ..))| Quote { amount, fee: Some(*fee) })
+            .min_by_key(|quote| quote.amount))
+    }
+}
+
+/// quotes every fee tier of izumi through its quoter, keeping the best
+pub struct IzumiDexQuoter<M> {
+    quoter: IzumiQuoter<M>,
+    fee_tiers: Vec<u32>,
+}
+
+/// izumi swap path of a single pool, `token_a` then the 3 bytes fee then `token_b`
+pub fn encode_izumi_path(token_a: Address, fee: u32, token_b: Address) -> Bytes {
+    encode_v3_path(&[token_a, token_b], &[fee]).unwrap_or_default()
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexQuoter for IzumiDexQuoter<M> {
+    fn dex(&self) -> DexExchange {
+        DexExchange::IZUMI
+    }
+
+    async fn quote_exact_in(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        if amount_in > U256::from(u128::MAX) {
+            return Ok(None);
+        }
+        let calls = self
+            .fee_tiers
+            .iter()
+            .map(|fee| {
+                let path = encode_izumi_path(token_in, *fee, token_out);
+                self.quoter.swap_amount(amount_in.as_u128(), path)
+            })
+            .collect::<Vec<_>>();
+        // the quoter reverts for a tier without pool
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(self.fee_tiers.iter().zip(rets))?
+            .into_iter()
+            .map(|(fee, (amount, _))| Quote { amount, fee: Some(*fee) })
+            .max_by_key(|quote| quote.amount))
+    }
+
+    async fn quote_exact_out(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_out: U256,
+    ) -> anyhow::Result<Option<Quote>> {
+        if amount_out > U256::from(u128::MAX) {
+            return Ok(None);
+        }
+        let calls = self
+            .fee_tiers
+            .iter()
+            .map(|fee| {
+                // an exact output path starts from the token out
+                let path = encode_izumi_path(token_out, *fee, token_in);
+                self.quoter.swap_desire(amount_out.as_u128(), path)
+            })
+            .collect::<Vec<_>>();
+        let rets = join_all(calls.iter().map(|call| call.call())).await;
+        Ok(drop_reverted(self.fee_tiers.iter().zip(rets))?
+            .into_iter()
+            .map(|(fee, (amount, _))| Quote { amount, fee: Some(*fee) })
+            .min_by_key(|quote| quote.amount))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_encode_izumi_path() {
+        let wmnt = Address::from_str("0x78c1b0C915c4FAA5FffA6CAbf0219DA63d7f4cb8").unwrap();
+        let usdt = Address::from_str("0x201EBa5CC46D216Ce6DC03F6a759e8E766e956aE").unwrap();
+        let path = encode_izumi_path(wmnt, 2000, usdt);
+        assert_eq!(path.len(), 43);
+        assert_eq!(&path[..20], wmnt.as_bytes());
+        assert_eq!(&path[20..23], &[0x00, 0x07, 0xd0]);
+        assert_eq!(&path[23..], usdt.as_bytes());
+    }
+
+    #[test]
+    fn test_new_dex_quoter() {
+        let (provider, _) = Provider::mocked();
+        let client = Arc::new(provider);
+        let quoter = new_dex_quoter(client.clone(), Network::BSC, DexExchange::PANCAKE).unwrap();
+        assert_eq!(quoter.dex(), DexExchange::PANCAKE);
+        let quoter = new_dex_quoter(client.clone(), Network::MANTLE, DexExchange::AGNI).unwrap();
+        assert_eq!(quoter.dex(), DexExchange::AGNI);
+        let quoter =
+            new_dex_quoter(client.clone(), Network::ZK_SYNC_ERA, DexExchange::SyncSwap).unwrap();
+        assert_eq!(quoter.dex(), DexExchange::SyncSwap);
+        // not deployed on the network
+        assert!(new_dex_quoter(client, Network::ETH, DexExchange::PANCAKE).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_cached_pool() {
+        let pools = RwLock::new(HashMap::new());
+        let (token_a, token_b, pool) = (Address::random(), Address::random(), Address::random());
+        // a pair without pool is looked up again
+        let ret = get_cached_pool(&pools, token_a, token_b, async { Ok(Address::zero()) }).await;
+        assert_eq!(ret.unwrap(), None);
+        let ret = get_cached_pool(&pools, token_b, token_a, async { Ok(pool) }).await;
+        assert_eq!(ret.unwrap(), Some(pool));
+        let ret = get_cached_pool(&pools, token_a, token_b, async {
+            Err(anyhow::anyhow!("not fetched once cached"))
+        })
+        .await;
+        assert_eq!(ret.unwrap(), Some(pool));
+    }
+
+    #[test]
+    fn test_drop_reverted() {
+        type Error = ContractError<Provider<MockProvider>>;
+        let rets: Vec<(u32, Result<U256, Error>)> =
+            vec![(500, Ok(U256::from(10))), (3000, Err(ContractError::Revert(Bytes::default())))];
+        assert_eq!(drop_reverted(rets).unwrap(), vec![(500, U256::from(10))]);
+
+        let rpc_error = ProviderError::CustomError("connection reset".to_string());
+        let rets: Vec<(u32, Result<U256, Error>)> = vec![
+            (500, Ok(U256::from(10))),
+            (3000, Err(ContractError::ProviderError { e: rpc_error })),
+        ];
+        assert!(drop_reverted(rets).is_err());
+    }
+
+    #[test]
+    fn test_depth_ladder() {
+        let amount = Decimal::from_str("0.5").unwrap();
+        let amounts = get_depth_ladder_amounts(amount);
+        assert_eq!(amounts.len(), DEPTH_LADDER_MULTIPLIERS_BPS.len());
+        assert_eq!(amounts[0], Decimal::from_str("0.125").unwrap());
+        assert_eq!(amounts[2], amount);
+        assert_eq!(amounts[4], Decimal::from(2));
+
+        let usdt = TokenInfo { decimals: 6, ..Default::default() };
+        let bid = to_price_level(amount, U256::from(1_000_000_000u64), &usdt, Some(500)).unwrap();
+        assert_eq!(bid.price, Decimal::from(2000));
+        assert_eq!(to_price_level(amount, U256::zero(), &usdt, None), None);
+
+        let ask = to_price_level(amount, U256::from(1_001_000_000u64), &usdt, Some(3000)).unwrap();
+        let mut ladder = DepthLadder { bids: vec![bid], asks: vec![] };
+        // the ask side can not fill the amount
+        assert_eq!(ladder.spread_at(amount), None);
+        ladder.asks.push(ask);
+        let spread = ladder.spread_at(amount).unwrap();
+        assert_eq!(spread.best_bid, Decimal::from(2000));
+        assert_eq!(spread.best_ask, Decimal::from(2002));
+        assert_eq!(spread.best_ask_v3_fee, Some(3000));
+        assert_eq!(spread.depth, Some(ladder));
+    }
+}
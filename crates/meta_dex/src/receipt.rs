@@ -0,0 +1,373 @@
+use ethers::{abi::RawLog, contract::EthEvent, prelude::*};
+use meta_address::get_wrapped_native_token_info;
+use meta_common::enums::{Network, PoolVariant};
+use meta_contracts::bindings::{
+    erc20::TransferFilter,
+    uniswapv2pair::SwapFilter as V2SwapFilter,
+    uniswapv3pool::SwapFilter as V3SwapFilter,
+    weth9::{DepositFilter, WithdrawalFilter},
+};
+use meta_util::ether::address_from_str;
+use std::collections::{HashMap, HashSet};
+
+/// the l2 base token of zksync era; it logs a `Transfer` for every native balance change, value
+/// transfers of contracts and gas included
+pub const ZK_SYNC_BASE_TOKEN: &str = "0x000000000000000000000000000000000000800A";
+
+/// the bootloader of zksync era, charging and refunding the gas of transactions
+pub const ZK_SYNC_BOOTLOADER: &str = "0x0000000000000000000000000000000000008001";
+
+/// swap of a v2 or v3 style pool found in a receipt. Amounts are seen from the pool: positive
+/// amounts were paid to the pool, negative ones were paid by it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolSwap {
+    pub pool: Address,
+    pub variant: PoolVariant,
+    pub amount_0: I256,
+    pub amount_1: I256,
+}
+
+/// gas paid by a transaction, in wei of the native token of its network
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasCost {
+    pub total: U256,
+    /// part of `total` paying for the l1 data of a rollup
+    pub l1_fee: U256,
+}
+
+/// token movements of a transaction, from the point of view of one wallet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReceiptAnalysis {
+    /// net balance change of the wallet per erc20 token address
+    pub token_deltas: HashMap<Address, I256>,
+    /// net balance change of the wallet in the native token, gas excluded. Native transfers made
+    /// by contracts are not logged, so only the value sent by the wallet, its own unwraps and the
+    /// unwraps of the contract it called count, except on zksync era where the base token logs
+    /// them all
+    pub native_delta: I256,
+    pub swaps: Vec<PoolSwap>,
+    pub gas: GasCost,
+}
+
+/// decodes `log` as `E` if its first topic is the signature of `E`
+pub(crate) fn decode_log<E: EthEvent>(log: &Log) -> Option<E> {
+    if log.topics.first() != Some(&E::signature()) {
+        return None;
+    }
+    E::decode_log(&RawLog { topics: log.topics.clone(), data: log.data.to_vec() }).ok()
+}
+
+/// a field of the receipt that only some networks return, such as the l1 fee of rollups
+fn get_receipt_field(receipt: &TransactionReceipt, key: &str) -> U256 {
+    receipt.other.get_deserialized::<U256>(key).and_then(Result::ok).unwrap_or_default()
+}
+
+/// # Description
+/// Gas paid by the transaction of `receipt`, with the l1 data fee of rollups
+///
+/// arbitrum includes the l1 data fee in `gas_used` and reports its share as `gasUsedForL1`; op
+/// stack rollups such as mantle charge `l1Fee` on top of `gas_used`
+pub fn get_gas_cost(
+    network: Network,
+    receipt: &TransactionReceipt,
+    tx: Option<&Transaction>,
+) -> GasCost {
+    let gas_price =
+        receipt.effective_gas_price.or_else(|| tx.and_then(|tx| tx.gas_price)).unwrap_or_default();
+    let execution_fee = receipt.gas_used.unwrap_or_default().saturating_mul(gas_price);
+    match network {
+        Network::ARBI => {
+            let l1_fee = get_receipt_field(receipt, "gasUsedForL1").saturating_mul(gas_price);
+            GasCost { total: execution_fee, l1_fee }
+        }
+        Network::MANTLE => {
+            let l1_fee = get_receipt_field(receipt, "l1Fee");
+            GasCost { total: execution_fee.saturating_add(l1_fee), l1_fee }
+        }
+        _ => GasCost { total: execution_fee, l1_fee: U256::zero() },
+    }
+}
+
+/// token logging the native balance changes of `network` as erc20 `Transfer`s, if any
+fn get_logged_native_token(network: Network) -> Option<Address> {
+    match network {
+        Network::ZK_SYNC_ERA | Network::ZK_SYNC_ERA_TEST => {
+            Some(address_from_str(ZK_SYNC_BASE_TOKEN))
+        }
+        _ => None,
+    }
+}
+
+/// # Description
+/// Decodes the erc20 `Transfer`, wrapped native `Deposit`/`Withdrawal` and v2/v3 `Swap` logs of
+/// `receipt` into the net balance changes of `wallet`
+///
+/// # Arguments
+/// * `tx` - the transaction of the receipt; its value counts if `wallet` sent it, and so do the
+///   unwraps of the contract it called, as a router unwraps the native out of a swap to pay it to
+///   the wallet
+pub fn analyze_receipt(
+    network: Network,
+    receipt: &TransactionReceipt,
+    tx: Option<&Transaction>,
+    wallet: Address,
+) -> ReceiptAnalysis {
+    let mut analysis =
+        ReceiptAnalysis { gas: get_gas_cost(network, receipt, tx), ..Default::default() };
+    // the value sent is logged by the base token where there is one
+    let logged_native = get_logged_native_token(network);
+    if let Some(tx) = tx.filter(|tx| tx.from.eq(&wallet) && logged_native.is_none()) {
+        analysis.native_delta -= I256::from_raw(tx.value);
+    }
+    let router = tx.filter(|tx| tx.from.eq(&wallet)).and_then(|tx| tx.to);
+    let bootloader = address_from_str(ZK_SYNC_BOOTLOADER);
+
+    // some wrapped native tokens log a mint or burn `Transfer` along their `Deposit` or
+    // `Withdrawal`; (token, account, amount) of those, so they are not counted twice
+    let mut mints = HashSet::new();
+    let mut burns = HashSet::new();
+    for log in receipt.logs.iter() {
+        if let Some(transfer) = decode_log::<TransferFilter>(log) {
+            let value = I256::from_raw(transfer.value);
+            if logged_native.is_some_and(|native| native.eq(&log.address)) {
+                // gas paid to and refunded by the bootloader is counted in `gas`
+                if transfer.from.eq(&bootloader) || transfer.to.eq(&bootloader) {
+                    continue;
+                }
+                if transfer.from.eq(&wallet) {
+                    analysis.native_delta -= value;
+                }
+                if transfer.to.eq(&wallet) {
+                    analysis.native_delta += value;
+                }
+                continue;
+            }
+            if transfer.from.eq(&wallet) {
+                *analysis.token_deltas.entry(log.address).or_default() -= value;
+            }
+            if transfer.to.eq(&wallet) {
+                *analysis.token_deltas.entry(log.address).or_default() += value;
+            }
+            if transfer.from.is_zero() {
+                mints.insert((log.address, transfer.to, transfer.value));
+            }
+            if transfer.to.is_zero() {
+                burns.insert((log.address, transfer.from, transfer.value));
+            }
+        } else if let Some(swap) = decode_log::<V2SwapFilter>(log) {
+            analysis.swaps.push(PoolSwap {
+                pool: log.address,
+                variant: PoolVariant::UniswapV2,
+                amount_0: I256::from_raw(swap.amount_0_in) - I256::from_raw(swap.amount_0_out),
+                amount_1: I256::from_raw(swap.amount_1_in) - I256::from_raw(swap.amount_1_out),
+            });
+        } else if let Some(swap) = decode_log::<V3SwapFilter>(log) {
+            analysis.swaps.push(PoolSwap {
+                pool: log.address,
+                variant: PoolVariant::UniswapV3,
+                amount_0: swap.amount_0,
+                amount_1: swap.amount_1,
+            });
+        }
+    }
+
+    // other contracts may log events of the same signature, so only the wrapped native is trusted
+    let wrapped_native = match get_wrapped_native_token_info(network) {
+        Some(info) => info.address,
+        None => return analysis,
+    };
+    for log in receipt.logs.iter().filter(|log| log.address.eq(&wrapped_native)) {
+        if let Some(deposit) = decode_log::<DepositFilter>(log) {
+            let logged = mints.contains(&(log.address, deposit.dst, deposit.wad));
+            if deposit.dst.eq(&wallet) && !logged {
+                *analysis.token_deltas.entry(log.address).or_default() +=
+                    I256::from_raw(deposit.wad);
+            }
+        } else if let Some(withdrawal) = decode_log::<WithdrawalFilter>(log) {
+            if withdrawal.src.eq(&wallet) {
+                if !burns.contains(&(log.address, withdrawal.src, withdrawal.wad)) {
+                    *analysis.token_deltas.entry(log.address).or_default() -=
+                        I256::from_raw(withdrawal.wad);
+                }
+                // the native token paid out is logged by the base token where there is one
+                if logged_native.is_none() {
+                    analysis.native_delta += I256::from_raw(withdrawal.wad);
+                }
+            } else if logged_native.is_none() && router.is_some_and(|r| r.eq(&withdrawal.src)) {
+                // the native out of a swap, unwrapped by the router and paid to the wallet
+                analysis.native_delta += I256::from_raw(withdrawal.wad);
+            }
+        }
+    }
+    analysis.token_deltas.retain(|_, delta| !delta.is_zero());
+    analysis
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{encode, Token as AbiToken};
+    use meta_address::get_token_info;
+
+    fn transfer_log(token: Address, from: Address, to: Address, value: u64) -> Log {
+        Log {
+            address: token,
+            topics: vec![TransferFilter::signature(), H256::from(from), H256::from(to)],
+            data: encode(&[AbiToken::Uint(U256::from(value))]).into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_analyze_receipt() {
+        let weth = get_token_info("WETH", Network::ARBI).unwrap().address;
+        let usdc = get_token_info("USDC", Network::ARBI).unwrap().address;
+        let wallet = address_from_str("0x0000000000000000000000000000000000000aaa");
+        let router = address_from_str("0x0000000000000000000000000000000000000bbb");
+        let pool = address_from_str("0x0000000000000000000000000000000000000ccc");
+
+        // sell 1000 usdc for weth through a v3 pool, the router unwraps 300 of the weth to the
+        // wallet and the wallet wraps 50 of its own eth
+        let swap = Log {
+            address: pool,
+            topics: vec![V3SwapFilter::signature(), H256::from(router), H256::from(router)],
+            data: encode(&[
+                AbiToken::Int(I256::from(-500).into_raw()),
+                AbiToken::Int(U256::from(1000)),
+                AbiToken::Uint(U256::one() << 96),
+                AbiToken::Uint(U256::from(1_000_000)),
+                AbiToken::Int(U256::zero()),
+            ])
+            .into(),
+            ..Default::default()
+        };
+        let withdrawal = Log {
+            address: weth,
+            topics: vec![WithdrawalFilter::signature(), H256::from(wallet)],
+            data: encode(&[AbiToken::Uint(U256::from(300))]).into(),
+            ..Default::default()
+        };
+        let deposit = Log {
+            address: weth,
+            topics: vec![DepositFilter::signature(), H256::from(wallet)],
+            data: encode(&[AbiToken::Uint(U256::from(50))]).into(),
+            ..Default::default()
+        };
+        let mut receipt = TransactionReceipt {
+            gas_used: Some(U256::from(100_000)),
+            effective_gas_price: Some(U256::from(10)),
+            logs: vec![
+                transfer_log(usdc, wallet, pool, 1000),
+                transfer_log(weth, pool, wallet, 500),
+                swap,
+                withdrawal,
+                transfer_log(weth, Address::zero(), wallet, 50),
+                deposit,
+            ],
+            ..Default::default()
+        };
+        receipt.other.insert("gasUsedForL1".to_string(), serde_json::json!("0x3e8"));
+        let tx = Transaction { from: wallet, value: U256::from(50), ..Default::default() };
+
+        let analysis = analyze_receipt(Network::ARBI, &receipt, Some(&tx), wallet);
+        assert_eq!(analysis.token_deltas.get(&usdc), Some(&I256::from(-1000)));
+        // 500 bought, 300 unwrapped, 50 wrapped and logged by a mint only once
+        assert_eq!(analysis.token_deltas.get(&weth), Some(&I256::from(250)));
+        assert_eq!(analysis.native_delta, I256::from(250));
+        assert_eq!(
+            analysis.swaps,
+            vec![PoolSwap {
+                pool,
+                variant: PoolVariant::UniswapV3,
+                amount_0: I256::from(-500),
+                amount_1: I256::from(1000),
+            }]
+        );
+        assert_eq!(
+            analysis.gas,
+            GasCost { total: U256::from(1_000_000), l1_fee: U256::from(10_000) }
+        );
+
+        // the l1 fee is charged on top on mantle
+        receipt.other.insert("l1Fee".to_string(), serde_json::json!("0x64"));
+        let gas = get_gas_cost(Network::MANTLE, &receipt, None);
+        assert_eq!(gas, GasCost { total: U256::from(1_000_100), l1_fee: U256::from(100) });
+    }
+
+    #[test]
+    fn test_analyze_native_out_receipt() {
+        let weth = get_token_info("WETH", Network::ARBI).unwrap().address;
+        let usdc = get_token_info("USDC", Network::ARBI).unwrap().address;
+        let wallet = address_from_str("0x0000000000000000000000000000000000000aaa");
+        let router = address_from_str("0x0000000000000000000000000000000000000bbb");
+        let pool = address_from_str("0x0000000000000000000000000000000000000ccc");
+
+        // sell 1000 usdc for eth, the pool pays 500 weth to the router which unwraps it
+        let withdrawal = |src: Address| Log {
+            address: weth,
+            topics: vec![WithdrawalFilter::signature(), H256::from(src)],
+            data: encode(&[AbiToken::Uint(U256::from(500))]).into(),
+            ..Default::default()
+        };
+        let receipt = TransactionReceipt {
+            logs: vec![
+                transfer_log(usdc, wallet, pool, 1000),
+                transfer_log(weth, pool, router, 500),
+                withdrawal(router),
+            ],
+            ..Default::default()
+        };
+        let tx = Transaction { from: wallet, to: Some(router), ..Default::default() };
+
+        let analysis = analyze_receipt(Network::ARBI, &receipt, Some(&tx), wallet);
+        assert_eq!(analysis.token_deltas.get(&usdc), Some(&I256::from(-1000)));
+        assert_eq!(analysis.token_deltas.get(&weth), None);
+        assert_eq!(analysis.native_delta, I256::from(500));
+
+        // the router unwraps for someone else in a transaction the wallet did not send
+        let tx = Transaction { from: router, to: Some(router), ..Default::default() };
+        let analysis = analyze_receipt(Network::ARBI, &receipt, Some(&tx), wallet);
+        assert_eq!(analysis.native_delta, I256::zero());
+    }
+
+    #[test]
+    fn test_analyze_zk_sync_receipt() {
+        let weth = get_token_info("WETH", Network::ZK_SYNC_ERA).unwrap().address;
+        let usdc = get_token_info("USDC", Network::ZK_SYNC_ERA).unwrap().address;
+        let (eth, bootloader) =
+            (address_from_str(ZK_SYNC_BASE_TOKEN), address_from_str(ZK_SYNC_BOOTLOADER));
+        let wallet = address_from_str("0x0000000000000000000000000000000000000aaa");
+        let pool = address_from_str("0x0000000000000000000000000000000000000ccc");
+
+        // sell 100 eth for usdc through a pool and unwrap 300 weth of the wallet, paying 70 of gas
+        // and refunded 20 of it
+        let withdrawal = Log {
+            address: weth,
+            topics: vec![WithdrawalFilter::signature(), H256::from(wallet)],
+            data: encode(&[AbiToken::Uint(U256::from(300))]).into(),
+            ..Default::default()
+        };
+        let receipt = TransactionReceipt {
+            gas_used: Some(U256::from(5)),
+            effective_gas_price: Some(U256::from(10)),
+            logs: vec![
+                transfer_log(eth, wallet, bootloader, 70),
+                transfer_log(eth, wallet, pool, 100),
+                transfer_log(usdc, pool, wallet, 1000),
+                transfer_log(weth, wallet, Address::zero(), 300),
+                withdrawal,
+                transfer_log(eth, weth, wallet, 300),
+                transfer_log(eth, bootloader, wallet, 20),
+            ],
+            ..Default::default()
+        };
+        let tx = Transaction { from: wallet, value: U256::from(100), ..Default::default() };
+
+        let analysis = analyze_receipt(Network::ZK_SYNC_ERA, &receipt, Some(&tx), wallet);
+        assert_eq!(analysis.token_deltas.get(&usdc), Some(&I256::from(1000)));
+        assert_eq!(analysis.token_deltas.get(&weth), Some(&I256::from(-300)));
+        assert_eq!(analysis.token_deltas.get(&eth), None);
+        assert_eq!(analysis.native_delta, I256::from(200));
+        assert_eq!(analysis.gas, GasCost { total: U256::from(50), l1_fee: U256::zero() });
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{pool::Pool, sync::DexFactory, uni_v3::load_v3_pool_state, DexService};
+use ethers::prelude::*;
+use futures::{stream, StreamExt};
+use meta_common::enums::PoolVariant;
+use meta_contracts::bindings::{uniswapv2pair::UniswapV2Pair, uniswapv3pool::UniswapV3Pool};
+use meta_util::defi::{encode_v3_path, get_v2_amount_out, uni_v3::V3PoolState};
+use std::{collections::HashMap, sync::Arc};
+use tracing::warn;
+
+/// max number of pools a route goes through
+pub const MAX_ROUTE_HOPS: usize = 3;
+
+/// number of pool states loaded concurrently
+const POOL_STATE_CONCURRENCY: usize = 16;
+
+/// tick bitmap words on each side of the current tick loaded for a v3 pool of a route; routes
+/// are sized for arbitrage, so a narrower window than the quoted pair's own pools is enough
+const ROUTE_V3_WORD_RADIUS: i16 = 1;
+
+/// state of a pool needed to quote it locally
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolState {
+    V2 { reserve_0: U256, reserve_1: U256 },
+    V3(V3PoolState),
+}
+
+impl PoolState {
+    /// amount out of swapping `amount_in` of `token_in` through `pool`; `None` if the pool can
+    /// not be simulated for that amount
+    pub fn quote_exact_input(
+        &self,
+        pool: &Pool,
+        token_in: Address,
+        amount_in: U256,
+    ) -> Option<U256> {
+        let zero_for_one = token_in.eq(&pool.token_0);
+        match self {
+            PoolState::V2 { reserve_0, reserve_1 } => {
+                let (reserve_in, reserve_out) =
+                    if zero_for_one { (*reserve_0, *reserve_1) } else { (*reserve_1, *reserve_0) };
+                Some(get_v2_amount_out(amount_in, reserve_in, reserve_out, pool.swap_fee.as_u32()))
+            }
+            PoolState::V3(state) => state.quote_exact_input(zero_for_one, amount_in),
+        }
+    }
+}
+
+/// # Description
+/// load the states of the pools as of `block`; a pool whose state can not be loaded is skipped
+pub async fn load_pool_states<M: Middleware + 'static>(
+    client: Arc<M>,
+    pools: &[Pool],
+    block: BlockId,
+) -> HashMap<Address, PoolState> {
+    stream::iter(pools.iter().copied())
+        .map(|pool| {
+            let client = client.clone();
+            async move {
+                let state = match pool.pool_variant {
+                    PoolVariant::UniswapV2 => {
+                        let pair = UniswapV2Pair::new(pool.address, client);
+                        let call = pair.get_reserves().block(block);
+                        call.call().await.map_err(anyhow::Error::from).map(
+                            |(reserve_0, reserve_1, _)| PoolState::V2 {
+                                reserve_0: U256::from(reserve_0),
+                                reserve_1: U256::from(reserve_1),
+                            },
+                        )
+                    }
+                    PoolVariant::UniswapV3 => {
+                        let v3_pool = UniswapV3Pool::new(pool.address, client);
+                        load_v3_pool_state(&v3_pool, ROUTE_V3_WORD_RADIUS, block)
+                            .await
+                            .map(PoolState::V3)
+                    }
+                };
+                match state {
+                    Ok(state) => Some((pool.address, state)),
+                    Err(e) => {
+                        warn!("skip pool {:?}, error in load state {:?}", pool.address, e);
+                        None
+                    }
+                }
+            }
+        })
+        .buffer_unordered(POOL_STATE_CONCURRENCY)
+        .filter_map(|state| async move { state })
+        .collect()
+        .await
+}
+
+/// a swap through one pool of a route
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hop {
+    pub pool: Pool,
+    pub token_in: Address,
+    pub token_out: Address,
+}
+
+/// a path of pools from the first token in to the last token out, quoted for `amount_in`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub hops: Vec<Hop>,
+    pub amount_in: U256,
+    pub amount_out: U256,
+}
+
+impl Route {
+    /// tokens along the route, from the token in to the token out
+    pub fn tokens(&self) -> Vec<Address> {
+        let mut tokens = self.hops.iter().map(|hop| hop.token_in).collect::<Vec<_>>();
+        tokens.extend(self.hops.last().map(|hop| hop.token_out));
+        tokens
+    }
+
+    /// variant shared by every pool of the route; `None` if it mixes v2 and v3 pools
+    pub fn pool_variant(&self) -> Option<PoolVariant> {
+        let variant = self.hops.first()?.pool.pool_variant;
+        self.hops.iter().all(|hop| hop.pool.pool_variant == variant).then_some(variant)
+    }
+
+    /// path of the v3 swap router `exactInput`; `None` if the route is not only v3 pools
+    pub fn encode_v3_path(&self) -> Option<Bytes> {
+        if self.pool_variant()? != PoolVariant::UniswapV3 {
+            return None;
+        }
+        let fees = self.hops.iter().map(|hop| hop.pool.swap_fee.as_u32()).collect::<Vec<_>>();
+        encode_v3_path(&self.tokens(), &fees)
+    }
+
+    /// path of the v2 router `swapExactTokensForTokens`; `None` if the route is not only v2 pools
+    pub fn v2_path(&self) -> Option<Vec<Address>> {
+        (self.pool_variant()? == PoolVariant::UniswapV2).then(|| self.tokens())
+    }
+
+    /// whether every pool of the route was created by one of `factories`
+    pub fn is_created_by(&self, factories: &[Address]) -> bool {
+        self.hops.iter().all(|hop| hop.pool.is_created_by(factories))
+    }
+}
+
+/// # Description
+/// Searches routes of up to `MAX_ROUTE_HOPS` pools over a graph of pools whose tokens are the
+/// nodes, and quotes them from the pool states
+#[derive(Debug, Default)]
+pub struct RouteFinder {
+    pools: HashMap<Address, (Pool, PoolState)>,
+    /// token => pools holding the token
+    edges: HashMap<Address, Vec<Address>>,
+}
+
+impl RouteFinder {
+    /// graph of the pools that have a state; the others are ignored
+    pub fn new(pools: &[Pool], states: HashMap<Address, PoolState>) -> Self {
+        let mut finder = Self::default();
+        let mut states = states;
+        for pool in pools {
+            if let Some(state) = states.remove(&pool.address) {
+                finder.insert(*pool, state);
+            }
+        }
+        finder
+    }
+
+    /// add a pool to the graph, or update its state
+    pub fn insert(&mut self, pool: Pool, state: PoolState) {
+        if self.pools.insert(pool.address, (pool, state)).is_none() {
+            self.edges.entry(pool.token_0).or_default().push(pool.address);
+            self.edges.entry(pool.token_1).or_default().push(pool.address);
+        }
+    }
+
+    /// # Description
+    /// every path from `token_in` to `token_out` through at most `max_hops` pools, which never
+    /// goes through the same token twice
+    ///
+    /// # Arguments
+    /// * `variant` - only use pools of this variant, e.g. to get routes that a single router can
+    ///   execute; `None` allows mixing v2 and v3 pools
+    pub fn find_paths(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        max_hops: usize,
+        variant: Option<PoolVariant>,
+    ) -> Vec<Vec<Hop>> {
+        let mut paths = vec![];
+        let mut path = vec![];
+        self.visit(
+            token_in,
+            token_out,
+            max_hops.min(MAX_ROUTE_HOPS),
+            variant,
+            &mut path,
+            &mut paths,
+        );
+        paths
+    }
+
+    fn visit(
+        &self,
+        token: Address,
+        token_out: Address,
+        max_hops: usize,
+        variant: Option<PoolVariant>,
+        path: &mut Vec<Hop>,
+        paths: &mut Vec<Vec<Hop>>,
+    ) {
+        if path.len() >= max_hops {
+            return;
+        }
+        for address in self.edges.get(&token).into_iter().flatten() {
+            let (pool, _) = &self.pools[address];
+            if variant.is_some_and(|variant| variant != pool.pool_variant) {
+                continue;
+            }
+            let next = if pool.token_0.eq(&token) { pool.token_1 } else { pool.token_0 };
+            // a token already on the path, including the token in
+            if path.iter().any(|hop| hop.token_in.eq(&next)) {
+                continue;
+            }
+            path.push(Hop { pool: *pool, token_in: token, token_out: next });
+            if next.eq(&token_out) {
+                paths.push(path.clone());
+            } else {
+                self.visit(next, token_out, max_hops, variant, path, paths);
+            }
+            path.pop();
+        }
+    }
+
+    /// amount out of swapping `amount_in` along the hops; `None` if a pool can not be simulated
+    pub fn quote_path(&self, hops: &[Hop], amount_in: U256) -> Option<U256> {
+        hops.iter().try_fold(amount_in, |amount, hop| {
+            let (pool, state) = self.pools.get(&hop.pool.address)?;
+            state.quote_exact_input(pool, hop.token_in, amount)
+        })
+    }
+
+    /// # Description
+    /// route with the most `token_out` for `amount_in` of `token_in`
+    ///
+    /// # Arguments
+    /// * `max_hops` - max number of pools of the route, capped by `MAX_ROUTE_HOPS`
+    /// * `variant` - see `find_paths`
+    pub fn best_route(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        max_hops: usize,
+        variant: Option<PoolVariant>,
+    ) -> Option<Route> {
+        self.find_paths(token_in, token_out, max_hops, variant)
+            .into_iter()
+            .filter_map(|hops| {
+                let amount_out = self.quote_path(&hops, amount_in)?;
+                Some(Route { hops, amount_in, amount_out })
+            })
+            .filter(|route| !route.amount_out.is_zero())
+            .max_by_key(|route| route.amount_out)
+    }
+}
+
+impl<M: Middleware> DexService<M> {
+    /// addresses of the factories of the dex, whose pools are the only ones its routers swap
+    pub fn get_factory_addresses(&self) -> Vec<Address> {
+        DexFactory::from_dex(self.network, self.dex_exchange)
+            .into_iter()
+            .map(|factory| factory.address)
+            .collect()
+    }
+}
+
+impl<M: Middleware + 'static> DexService<M> {
+    /// # Description
+    /// graph of the pools of `pools` created by the factories of the dex, with their states as of
+    /// `block`, so that the routes found can be sent with `submit_route_order`
+    pub async fn get_route_finder(&self, pools: &[Pool], block: BlockId) -> RouteFinder {
+        let factories = self.get_factory_addresses();
+        let pools =
+            pools.iter().filter(|pool| pool.is_created_by(&factories)).copied().collect::<Vec<_>>();
+        let states = load_pool_states(self.client.clone(), &pools, block).await;
+        RouteFinder::new(&pools, states)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use meta_util::defi::uni_v3::{MAX_TICK, MIN_TICK};
+
+    fn token(n: u64) -> Address {
+        Address::from_low_u64_be(n)
+    }
+
+    fn v2_pool(
+        n: u64,
+        token_a: Address,
+        token_b: Address,
+        reserve_a: u64,
+        reserve_b: u64,
+    ) -> (Pool, PoolState) {
+        let pool = Pool::new(token(n), token_a, token_b, U256::from(3000), PoolVariant::UniswapV2);
+        let (reserve_0, reserve_1) = if pool.token_0.eq(&token_a) {
+            (U256::from(reserve_a), U256::from(reserve_b))
+        } else {
+            (U256::from(reserve_b), U256::from(reserve_a))
+        };
+        (pool, PoolState::V2 { reserve_0, reserve_1 })
+    }
+
+    /// full range v3 pool at price 1
+    fn v3_pool(
+        n: u64,
+        token_a: Address,
+        token_b: Address,
+        fee: u32,
+        liquidity: i128,
+    ) -> (Pool, PoolState) {
+        let pool = Pool::new(token(n), token_a, token_b, U256::from(fee), PoolVariant::UniswapV3);
+        let mut state = V3PoolState::new(U256::one() << 96, 0, 0, fee, 60);
+        state.apply_liquidity_delta(MIN_TICK / 60 * 60, MAX_TICK / 60 * 60, liquidity);
+        (pool, PoolState::V3(state))
+    }
+
+    fn finder(pools: Vec<(Pool, PoolState)>) -> RouteFinder {
+        let mut finder = RouteFinder::default();
+        pools.into_iter().for_each(|(pool, state)| finder.insert(pool, state));
+        finder
+    }
+
+    #[test]
+    fn test_best_route() {
+        let (arb, weth, usdc, dai) = (token(1), token(2), token(3), token(4));
+        let finder = finder(vec![
+            // a shallow direct pool
+            v2_pool(100, arb, usdc, 10_000, 10_000),
+            v2_pool(101, arb, weth, 1_000_000_000, 1_000_000_000),
+            v3_pool(102, weth, usdc, 500, 1_000_000_000_000),
+            v2_pool(103, usdc, dai, 1_000_000, 1_000_000),
+        ]);
+
+        assert_eq!(finder.find_paths(arb, usdc, 3, None).len(), 2);
+        assert_eq!(finder.find_paths(arb, usdc, 1, None).len(), 1);
+        assert_eq!(finder.find_paths(arb, dai, 3, None).len(), 2);
+        assert_eq!(finder.find_paths(arb, dai, 2, None).len(), 1);
+
+        let route = finder.best_route(arb, usdc, U256::from(1_000), 3, None).unwrap();
+        assert_eq!(route.tokens(), vec![arb, weth, usdc]);
+        assert_eq!(route.pool_variant(), None);
+        assert_eq!(route.encode_v3_path(), None);
+        assert_eq!(route.v2_path(), None);
+        assert!(route.amount_out > U256::from(990));
+
+        // the only v2 route is the shallow direct pool
+        let route = finder
+            .best_route(arb, usdc, U256::from(1_000), 3, Some(PoolVariant::UniswapV2))
+            .unwrap();
+        assert_eq!(route.v2_path(), Some(vec![arb, usdc]));
+        assert_eq!(route.amount_out, U256::from(906));
+    }
+
+    #[test]
+    fn test_encode_v3_route() {
+        let (arb, weth, usdc) = (token(1), token(2), token(3));
+        let finder = finder(vec![
+            v3_pool(100, arb, weth, 3000, 1_000_000_000_000),
+            v3_pool(101, weth, usdc, 500, 1_000_000_000_000),
+        ]);
+        let route = finder.best_route(arb, usdc, U256::from(1_000_000), 3, None).unwrap();
+        assert_eq!(route.pool_variant(), Some(PoolVariant::UniswapV3));
+        assert_eq!(route.encode_v3_path(), encode_v3_path(&[arb, weth, usdc], &[3000, 500]));
+        assert_eq!(finder.best_route(usdc, arb, U256::from(1_000_000), 1, None), None);
+    }
+
+    #[test]
+    fn test_route_is_created_by() {
+        let (arb, weth, usdc) = (token(1), token(2), token(3));
+        let (factory, other_factory) = (token(1000), token(1001));
+        let (arb_weth, arb_weth_state) = v3_pool(100, arb, weth, 3000, 1_000_000_000_000);
+        let (weth_usdc, weth_usdc_state) = v3_pool(101, weth, usdc, 500, 1_000_000_000_000);
+        let finder = finder(vec![
+            (arb_weth.with_factory(factory), arb_weth_state),
+            (weth_usdc.with_factory(other_factory), weth_usdc_state),
+        ]);
+        let route = finder.best_route(arb, usdc, U256::from(1_000_000), 3, None).unwrap();
+        assert!(!route.is_created_by(&[factory]));
+        assert!(route.is_created_by(&[factory, other_factory]));
+        // a pool of an unknown factory is never routed
+        assert!(!arb_weth.is_created_by(&[factory]));
+    }
+}
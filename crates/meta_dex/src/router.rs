@@ -0,0 +1,559 @@
+use ethers::{
+    abi::{encode, Detokenize, Token},
+    prelude::*,
+    types::transaction::eip2718::TypedTransaction,
+};
+use meta_address::get_dex_address;
+use meta_common::enums::{ContractType, DexExchange, Network};
+use meta_contracts::bindings::{
+    permit2::Permit2, swaprouter, swaprouter::SwapRouter, swaprouter02, swaprouter02::SwapRouter02,
+    universalrouter::UniversalRouter,
+};
+use meta_util::defi::{encode_v3_path, get_swap_price_limit};
+use std::sync::Arc;
+
+/// `recipient` standing for the caller in `SwapRouter02` and universal router calls
+pub const MSG_SENDER: Address = H160([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+/// `recipient` standing for the router itself in `SwapRouter02` and universal router calls
+pub const ADDRESS_THIS: Address =
+    H160([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+
+/// universal router commands
+pub const V3_SWAP_EXACT_IN: u8 = 0x00;
+pub const V3_SWAP_EXACT_OUT: u8 = 0x01;
+pub const WRAP_ETH: u8 = 0x0b;
+pub const UNWRAP_WETH: u8 = 0x0c;
+
+/// routers able to swap through uniswap v3 style pools
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum V3RouterType {
+    /// the legacy `SwapRouter`, with a deadline in each swap
+    SwapRouter,
+    /// `SwapRouter02`, with a deadline on its `multicall`
+    SwapRouter02,
+    /// the universal router, pulling tokens through `Permit2`
+    UniversalRouter,
+}
+
+impl V3RouterType {
+    /// router types from the most to the least preferred
+    pub const PREFERENCE: [V3RouterType; 3] =
+        [Self::SwapRouter, Self::SwapRouter02, Self::UniversalRouter];
+
+    pub fn contract_type(&self) -> ContractType {
+        match self {
+            Self::SwapRouter => ContractType::UniV3SwapRouterV2,
+            Self::SwapRouter02 => ContractType::UniV3SwapRouter02,
+            Self::UniversalRouter => ContractType::UniversalRouter,
+        }
+    }
+}
+
+/// amounts of a v3 swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum V3SwapAmount {
+    ExactInput { amount_in: U256, amount_out_min: U256 },
+    ExactOutput { amount_out: U256, amount_in_max: U256 },
+}
+
+/// # Description
+/// A swap along v3 pools, encoded by each router type in its own way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct V3Swap {
+    /// pool tokens from the token in to the token out; the wrapped native token for a native leg
+    pub tokens: Vec<Address>,
+    /// fee of each pool, between `tokens[i]` and `tokens[i + 1]`
+    pub fees: Vec<u32>,
+    pub amount: V3SwapAmount,
+    pub recipient: Address,
+    pub deadline: U256,
+    /// the token in is paid in the native token, with the value of the transaction
+    pub native_in: bool,
+    /// the token out is unwrapped to the native token before it is paid to `recipient`
+    pub native_out: bool,
+}
+
+impl V3Swap {
+    /// native token sent with the swap
+    pub fn get_value(&self) -> Option<U256> {
+        match (self.native_in, self.amount) {
+            (false, _) => None,
+            (true, V3SwapAmount::ExactInput { amount_in, .. }) => Some(amount_in),
+            (true, V3SwapAmount::ExactOutput { amount_in_max, .. }) => Some(amount_in_max),
+        }
+    }
+
+    /// max amount of the token in the swap may spend
+    pub fn get_max_amount_in(&self) -> U256 {
+        match self.amount {
+            V3SwapAmount::ExactInput { amount_in, .. } => amount_in,
+            V3SwapAmount::ExactOutput { amount_in_max, .. } => amount_in_max,
+        }
+    }
+
+    /// min amount of the token out the swap pays
+    fn get_min_amount_out(&self) -> U256 {
+        match self.amount {
+            V3SwapAmount::ExactInput { amount_out_min, .. } => amount_out_min,
+            V3SwapAmount::ExactOutput { amount_out, .. } => amount_out,
+        }
+    }
+
+    /// packed path, reversed for an exact output swap as the routers expect it
+    fn encode_path(&self) -> Option<Bytes> {
+        match self.amount {
+            V3SwapAmount::ExactInput { .. } => encode_v3_path(&self.tokens, &self.fees),
+            V3SwapAmount::ExactOutput { .. } => {
+                let tokens = self.tokens.iter().rev().copied().collect::<Vec<_>>();
+                let fees = self.fees.iter().rev().copied().collect::<Vec<_>>();
+                encode_v3_path(&tokens, &fees)
+            }
+        }
+    }
+
+    /// price limit of a single pool swap, none crossed
+    fn get_price_limit(&self) -> U256 {
+        get_swap_price_limit(self.tokens[0], self.tokens[1], self.tokens[0])
+    }
+}
+
+/// # Description
+/// The router a `DexWrapper` swaps v3 pools through, as deployed on its network
+#[derive(Debug)]
+pub enum V3Router<M> {
+    SwapRouter(SwapRouter<M>),
+    SwapRouter02(SwapRouter02<M>),
+    UniversalRouter { router: UniversalRouter<M>, permit2: Permit2<M> },
+}
+
+// the contracts are clone for any middleware, which a derive would require
+impl<M> Clone for V3Router<M> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::SwapRouter(router) => Self::SwapRouter(router.clone()),
+            Self::SwapRouter02(router) => Self::SwapRouter02(router.clone()),
+            Self::UniversalRouter { router, permit2 } => {
+                Self::UniversalRouter { router: router.clone(), permit2: permit2.clone() }
+            }
+        }
+    }
+}
+
+impl<M: Middleware> V3Router<M> {
+    /// # Description
+    /// The router of `router_type` in the address book of `dex` on `network`; `None` if it is not
+    /// deployed there, or if the universal router misses its `Permit2`
+    pub fn new(
+        client: Arc<M>,
+        dex: DexExchange,
+        network: Network,
+        router_type: V3RouterType,
+    ) -> Option<Self> {
+        let address = get_dex_address(dex, network, router_type.contract_type())?.address;
+        let router = match router_type {
+            V3RouterType::SwapRouter => Self::SwapRouter(SwapRouter::new(address, client)),
+            V3RouterType::SwapRouter02 => Self::SwapRouter02(SwapRouter02::new(address, client)),
+            V3RouterType::UniversalRouter => {
+                let permit2 = get_dex_address(dex, network, ContractType::Permit2)?.address;
+                Self::UniversalRouter {
+                    router: UniversalRouter::new(address, client.clone()),
+                    permit2: Permit2::new(permit2, client),
+                }
+            }
+        };
+        Some(router)
+    }
+
+    /// the most preferred router deployed for `dex` on `network`
+    pub fn from_address_book(client: Arc<M>, dex: DexExchange, network: Network) -> Option<Self> {
+        V3RouterType::PREFERENCE
+            .iter()
+            .find_map(|router_type| Self::new(client.clone(), dex, network, *router_type))
+    }
+
+    pub fn router_type(&self) -> V3RouterType {
+        match self {
+            Self::SwapRouter(_) => V3RouterType::SwapRouter,
+            Self::SwapRouter02(_) => V3RouterType::SwapRouter02,
+            Self::UniversalRouter { .. } => V3RouterType::UniversalRouter,
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        match self {
+            Self::SwapRouter(router) => router.address(),
+            Self::SwapRouter02(router) => router.address(),
+            Self::UniversalRouter { router, .. } => router.address(),
+        }
+    }
+
+    /// # Description
+    /// The router transaction of `swap`; `None` if its tokens and fees do not make a path
+    pub fn get_swap_tx(&self, swap: &V3Swap) -> Option<TypedTransaction> {
+        let path = swap.encode_path()?;
+        let tx = match self {
+            Self::SwapRouter(router) => get_swap_router_tx(router, swap, path),
+            Self::SwapRouter02(router) => get_swap_router02_tx(router, swap, path),
+            Self::UniversalRouter { router, .. } => get_universal_router_tx(router, swap, path),
+        };
+        Some(tx)
+    }
+}
+
+/// # Description
+/// The v3 router transaction of `swap`, taking its native token legs through a `multicall` of the
+/// router if needed
+///
+/// # Arguments
+/// * `value` - native token paid for the swap, wrapped by the router; the excess is refunded with
+///   `refundETH` if `refund`
+/// * `unwrap` - (min amount, recipient) of the wrapped native token bought, unwrapped with
+///   `unwrapWETH9`; `swap` must send it to the router
+pub fn get_v3_router_tx<M: Middleware, D: Detokenize>(
+    router: &SwapRouter<M>,
+    swap: ContractCall<M, D>,
+    value: Option<U256>,
+    refund: bool,
+    unwrap: Option<(U256, Address)>,
+) -> TypedTransaction {
+    let mut calls = swap.calldata().into_iter().collect::<Vec<_>>();
+    if refund {
+        calls.extend(router.refund_eth().calldata());
+    }
+    if let Some((amount_minimum, recipient)) = unwrap {
+        calls.extend(router.unwrap_weth9(amount_minimum, recipient).calldata());
+    }
+    let mut tx = match calls.len() {
+        1 => swap.tx,
+        _ => router.multicall(calls).tx,
+    };
+    if let Some(value) = value {
+        tx.set_value(value);
+    }
+    tx
+}
+
+fn get_swap_router_tx<M: Middleware>(
+    router: &SwapRouter<M>,
+    swap: &V3Swap,
+    path: Bytes,
+) -> TypedTransaction {
+    // the router keeps the wrapped native token bought, to unwrap it
+    let recipient = if swap.native_out { router.address() } else { swap.recipient };
+    let call =
+        match (swap.amount, swap.fees.len()) {
+            (V3SwapAmount::ExactInput { amount_in, amount_out_min }, 1) => router
+                .exact_input_single(swaprouter::ExactInputSingleParams {
+                    token_in: swap.tokens[0],
+                    token_out: swap.tokens[1],
+                    fee: swap.fees[0],
+                    recipient,
+                    deadline: swap.deadline,
+                    amount_in,
+                    amount_out_minimum: amount_out_min,
+                    sqrt_price_limit_x96: swap.get_price_limit(),
+                }),
+            (V3SwapAmount::ExactInput { amount_in, amount_out_min }, _) => {
+                router.exact_input(swaprouter::ExactInputParams {
+                    path,
+                    recipient,
+                    deadline: swap.deadline,
+                    amount_in,
+                    amount_out_minimum: amount_out_min,
+                })
+            }
+            (V3SwapAmount::ExactOutput { amount_out, amount_in_max }, 1) => router
+                .exact_output_single(swaprouter::ExactOutputSingleParams {
+                    token_in: swap.tokens[0],
+                    token_out: swap.tokens[1],
+                    fee: swap.fees[0],
+                    recipient,
+                    deadline: swap.deadline,
+                    amount_out,
+                    amount_in_maximum: amount_in_max,
+                    sqrt_price_limit_x96: swap.get_price_limit(),
+                }),
+            (V3SwapAmount::ExactOutput { amount_out, amount_in_max }, _) => {
+                router.exact_output(swaprouter::ExactOutputParams {
+                    path,
+                    recipient,
+                    deadline: swap.deadline,
+                    amount_out,
+                    amount_in_maximum: amount_in_max,
+                })
+            }
+        };
+    // the native token paid beyond the amount in of an exact output is refunded
+    let refund = swap.native_in && matches!(swap.amount, V3SwapAmount::ExactOutput { .. });
+    get_v3_router_tx(
+        router,
+        call,
+        swap.get_value(),
+        refund,
+        swap.native_out.then_some((swap.get_min_amount_out(), swap.recipient)),
+    )
+}
+
+/// `SwapRouter02` swaps have no deadline, so they are always sent through `multicall` with one
+fn get_swap_router02_tx<M: Middleware>(
+    router: &SwapRouter02<M>,
+    swap: &V3Swap,
+    path: Bytes,
+) -> TypedTransaction {
+    let recipient = if swap.native_out { ADDRESS_THIS } else { swap.recipient };
+    let call =
+        match (swap.amount, swap.fees.len()) {
+            (V3SwapAmount::ExactInput { amount_in, amount_out_min }, 1) => router
+                .exact_input_single(swaprouter02::ExactInputSingleParams {
+                    token_in: swap.tokens[0],
+                    token_out: swap.tokens[1],
+                    fee: swap.fees[0],
+                    recipient,
+                    amount_in,
+                    amount_out_minimum: amount_out_min,
+                    sqrt_price_limit_x96: swap.get_price_limit(),
+                }),
+            (V3SwapAmount::ExactInput { amount_in, amount_out_min }, _) => {
+                router.exact_input(swaprouter02::ExactInputParams {
+                    path,
+                    recipient,
+                    amount_in,
+                    amount_out_minimum: amount_out_min,
+                })
+            }
+            (V3SwapAmount::ExactOutput { amount_out, amount_in_max }, 1) => router
+                .exact_output_single(swaprouter02::ExactOutputSingleParams {
+                    token_in: swap.tokens[0],
+                    token_out: swap.tokens[1],
+                    fee: swap.fees[0],
+                    recipient,
+                    amount_out,
+                    amount_in_maximum: amount_in_max,
+                    sqrt_price_limit_x96: swap.get_price_limit(),
+                }),
+            (V3SwapAmount::ExactOutput { amount_out, amount_in_max }, _) => {
+                router.exact_output(swaprouter02::ExactOutputParams {
+                    path,
+                    recipient,
+                    amount_out,
+                    amount_in_maximum: amount_in_max,
+                })
+            }
+        };
+
+    let mut calls = call.calldata().into_iter().collect::<Vec<_>>();
+    if swap.native_in && matches!(swap.amount, V3SwapAmount::ExactOutput { .. }) {
+        calls.extend(router.refund_eth().calldata());
+    }
+    if swap.native_out {
+        let unwrap = router.unwrap_weth_9_with_recipient(swap.get_min_amount_out(), swap.recipient);
+        calls.extend(unwrap.calldata());
+    }
+    let mut tx = router.multicall_with_deadline(swap.deadline, calls).tx;
+    if let Some(value) = swap.get_value() {
+        tx.set_value(value);
+    }
+    tx
+}
+
+/// # Description
+/// The universal router `execute` of `swap`: the native token paid is wrapped by `WRAP_ETH`
+/// first, the wrapped native token bought or left over is unwrapped by `UNWRAP_WETH` last. Tokens
+/// of the wallet are pulled through `Permit2`
+fn get_universal_router_tx<M: Middleware>(
+    router: &UniversalRouter<M>,
+    swap: &V3Swap,
+    path: Bytes,
+) -> TypedTransaction {
+    let mut commands = vec![];
+    let mut inputs: Vec<Bytes> = vec![];
+    if let Some(value) = swap.get_value() {
+        commands.push(WRAP_ETH);
+        inputs.push(encode(&[Token::Address(ADDRESS_THIS), Token::Uint(value)]).into());
+    }
+
+    let recipient = if swap.native_out { ADDRESS_THIS } else { swap.recipient };
+    let (command, amount, amount_limit) = match swap.amount {
+        V3SwapAmount::ExactInput { amount_in, amount_out_min } => {
+            (V3_SWAP_EXACT_IN, amount_in, amount_out_min)
+        }
+        V3SwapAmount::ExactOutput { amount_out, amount_in_max } => {
+            (V3_SWAP_EXACT_OUT, amount_out, amount_in_max)
+        }
+    };
+    commands.push(command);
+    inputs.push(
+        encode(&[
+            Token::Address(recipient),
+            Token::Uint(amount),
+            Token::Uint(amount_limit),
+            Token::Bytes(path.to_vec()),
+            // the router pays the native token it wrapped itself
+            Token::Bool(!swap.native_in),
+        ])
+        .into(),
+    );
+
+    if swap.native_out {
+        commands.push(UNWRAP_WETH);
+        inputs.push(
+            encode(&[Token::Address(swap.recipient), Token::Uint(swap.get_min_amount_out())])
+                .into(),
+        );
+    } else if swap.native_in && matches!(swap.amount, V3SwapAmount::ExactOutput { .. }) {
+        // refund the wrapped native token not spent
+        commands.push(UNWRAP_WETH);
+        inputs.push(encode(&[Token::Address(MSG_SENDER), Token::Uint(U256::zero())]).into());
+    }
+
+    let mut tx = router.execute_with_commands_and_inputs(commands.into(), inputs, swap.deadline).tx;
+    if let Some(value) = swap.get_value() {
+        tx.set_value(value);
+    }
+    tx
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{decode, ParamType};
+    use meta_contracts::bindings::swaprouter::ExactInputSingleParams;
+
+    fn get_swap(amount: V3SwapAmount, native_in: bool, native_out: bool) -> V3Swap {
+        V3Swap {
+            tokens: vec![Address::from_low_u64_be(10), Address::from_low_u64_be(11)],
+            fees: vec![500],
+            amount,
+            recipient: Address::from_low_u64_be(3),
+            deadline: U256::from(1_000),
+            native_in,
+            native_out,
+        }
+    }
+
+    #[test]
+    fn test_router_from_address_book() {
+        let (provider, _) = Provider::mocked();
+        let client = Arc::new(provider);
+        let router =
+            V3Router::from_address_book(client.clone(), DexExchange::UniswapV3, Network::ETH);
+        assert_eq!(router.unwrap().router_type(), V3RouterType::SwapRouter);
+        // the legacy router is not deployed on bsc
+        let router =
+            V3Router::from_address_book(client.clone(), DexExchange::UniswapV3, Network::BSC);
+        assert_eq!(router.unwrap().router_type(), V3RouterType::SwapRouter02);
+        let router = V3Router::new(
+            client.clone(),
+            DexExchange::UniswapV3,
+            Network::ARBI,
+            V3RouterType::UniversalRouter,
+        );
+        assert_eq!(router.unwrap().router_type(), V3RouterType::UniversalRouter);
+        assert!(V3Router::from_address_book(client, DexExchange::PANCAKE, Network::BSC).is_none());
+    }
+
+    #[test]
+    fn test_get_v3_router_tx() {
+        let (provider, _) = Provider::mocked();
+        let router = SwapRouter::new(Address::from_low_u64_be(1), Arc::new(provider));
+        let recipient = Address::from_low_u64_be(2);
+        let swap = router.exact_input_single(ExactInputSingleParams::default());
+
+        // a single swap is sent as is
+        let tx = get_v3_router_tx(&router, swap.clone(), Some(U256::from(100)), false, None);
+        assert_eq!(tx.data(), swap.tx.data());
+        assert_eq!(tx.value(), Some(&U256::from(100)));
+
+        let tx = get_v3_router_tx(
+            &router,
+            swap.clone(),
+            Some(U256::from(100)),
+            true,
+            Some((U256::from(50), recipient)),
+        );
+        let expected = router.multicall(vec![
+            swap.calldata().unwrap(),
+            router.refund_eth().calldata().unwrap(),
+            router.unwrap_weth9(U256::from(50), recipient).calldata().unwrap(),
+        ]);
+        assert_eq!(tx.data(), expected.tx.data());
+        assert_eq!(tx.value(), Some(&U256::from(100)));
+        assert_eq!(tx.to_addr(), Some(&router.address()));
+    }
+
+    #[test]
+    fn test_swap_router02_tx() {
+        let (provider, _) = Provider::mocked();
+        let router = SwapRouter02::new(Address::from_low_u64_be(1), Arc::new(provider));
+        let amount =
+            V3SwapAmount::ExactInput { amount_in: U256::from(100), amount_out_min: U256::from(90) };
+        let swap = get_swap(amount, false, true);
+        let tx = V3Router::SwapRouter02(router.clone()).get_swap_tx(&swap).unwrap();
+
+        let expected = router.multicall_with_deadline(
+            swap.deadline,
+            vec![
+                router
+                    .exact_input_single(swaprouter02::ExactInputSingleParams {
+                        token_in: swap.tokens[0],
+                        token_out: swap.tokens[1],
+                        fee: 500,
+                        recipient: ADDRESS_THIS,
+                        amount_in: U256::from(100),
+                        amount_out_minimum: U256::from(90),
+                        sqrt_price_limit_x96: swap.get_price_limit(),
+                    })
+                    .calldata()
+                    .unwrap(),
+                router
+                    .unwrap_weth_9_with_recipient(U256::from(90), swap.recipient)
+                    .calldata()
+                    .unwrap(),
+            ],
+        );
+        assert_eq!(tx.data(), expected.tx.data());
+        assert_eq!(tx.value(), None);
+    }
+
+    #[test]
+    fn test_universal_router_tx() {
+        let (provider, _) = Provider::mocked();
+        let client = Arc::new(provider);
+        let router = V3Router::UniversalRouter {
+            router: UniversalRouter::new(Address::from_low_u64_be(1), client.clone()),
+            permit2: Permit2::new(Address::from_low_u64_be(2), client),
+        };
+        let amount = V3SwapAmount::ExactOutput {
+            amount_out: U256::from(100),
+            amount_in_max: U256::from(120),
+        };
+        let swap = get_swap(amount, true, false);
+        let tx = router.get_swap_tx(&swap).unwrap();
+        assert_eq!(tx.value(), Some(&U256::from(120)));
+
+        let data = tx.data().unwrap();
+        assert_eq!(data[..4], [0x35, 0x93, 0x56, 0x4c]);
+        let params =
+            [ParamType::Bytes, ParamType::Array(Box::new(ParamType::Bytes)), ParamType::Uint(256)];
+        let tokens = decode(&params, &data[4..]).unwrap();
+        assert_eq!(
+            tokens[0].clone().into_bytes().unwrap(),
+            vec![WRAP_ETH, V3_SWAP_EXACT_OUT, UNWRAP_WETH]
+        );
+        let inputs = tokens[1].clone().into_array().unwrap();
+        let swap_params = [
+            ParamType::Address,
+            ParamType::Uint(256),
+            ParamType::Uint(256),
+            ParamType::Bytes,
+            ParamType::Bool,
+        ];
+        let swap_input = decode(&swap_params, &inputs[1].clone().into_bytes().unwrap()).unwrap();
+        assert_eq!(swap_input[0], Token::Address(swap.recipient));
+        // the path of an exact output starts from the token out
+        let path = encode_v3_path(&[swap.tokens[1], swap.tokens[0]], &[500]).unwrap();
+        assert_eq!(swap_input[3], Token::Bytes(path.to_vec()));
+        assert_eq!(swap_input[4], Token::Bool(false));
+        assert_eq!(tokens[2], Token::Uint(swap.deadline));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::pool::Pool;
+use ethers::prelude::*;
+use futures::{stream, StreamExt};
+use meta_address::get_dex_factories;
+use meta_common::enums::{ContractType, DexExchange, Network, PoolVariant};
+use meta_contracts::bindings::{
+    muteswitchfactory::MuteSwitchFactory, uniswapv2factory::UniswapV2Factory,
+    uniswapv3factory::UniswapV3Factory,
+};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// number of blocks scanned by a single `eth_getLogs` request
+pub const SYNC_BLOCK_STEP: u64 = 2000;
+
+/// number of block ranges of a factory scanned concurrently
+const SYNC_CONCURRENCY: usize = 8;
+
+/// a factory of the dex address book, whose pool created events are indexed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DexFactory {
+    pub network: Network,
+    pub dex: DexExchange,
+    pub pool_variant: PoolVariant,
+    pub address: Address,
+    pub created_blk_num: u64,
+}
+
+impl DexFactory {
+    fn from_contract_info(
+        network: Network,
+        dex: DexExchange,
+        contract_type: ContractType,
+        address: Address,
+        created_blk_num: u64,
+    ) -> Option<Self> {
+        let pool_variant = match (dex, contract_type) {
+            // syncswap pools are created by per pool type factories, not indexed yet
+            (DexExchange::SyncSwap, _) => return None,
+            (_, ContractType::UniV2Factory) => PoolVariant::UniswapV2,
+            (_, ContractType::UniV3Factory) => PoolVariant::UniswapV3,
+            _ => return None,
+        };
+        Some(Self { network, dex, pool_variant, address, created_blk_num })
+    }
+
+    /// every indexable factory of `dex` on `network`, such as both the v2 and v3 factories of a
+    /// dex deploying the two
+    pub fn from_dex(network: Network, dex: DexExchange) -> Vec<Self> {
+        Self::from_address_book(network).into_iter().filter(|factory| factory.dex == dex).collect()
+    }
+
+    /// every indexable factory of the address book on `network`
+    pub fn from_address_book(network: Network) -> Vec<Self> {
+        get_dex_factories(network)
+            .into_iter()
+            .filter_map(|(dex, contract_type, info)| {
+                Self::from_contract_info(
+                    network,
+                    dex,
+                    contract_type,
+                    info.address,
+                    info.created_blk_num,
+                )
+            })
+            .collect()
+    }
+
+    /// # Description
+    /// fetch pool created events from factory contract
+    ///
+    /// Returns vector of pools created
+    ///
+    /// # Arguments
+    /// * `from_block` - start block number, inclusive
+    /// * `to_block`   - end block number, inclusive
+    pub async fn fetch_pair_created_event<M: Middleware + 'static>(
+        &self,
+        client: Arc<M>,
+        from_block: u64,
+        to_block: u64,
+    ) -> anyhow::Result<Vec<Pool>> {
+        debug!("start fetch {:?} within range {:?}, {:?}", self.dex, from_block, to_block);
+        let pools = match (self.dex, self.pool_variant) {
+            (DexExchange::MuteSwitch, _) => MuteSwitchFactory::new(self.address, client)
+                .pair_created_filter()
+                .from_block(from_block)
+                .to_block(to_block)
+                .query()
+                .await?
+                .into_iter()
+                .map(|p| {
+                    // fee is in bp
+                    let swap_fee = p.fee * 100;
+                    Pool::new(p.pair, p.token_0, p.token_1, swap_fee, PoolVariant::UniswapV2)
+                        .with_factory(self.address)
+                })
+                .collect::<Vec<_>>(),
+            (_, PoolVariant::UniswapV2) => {
+                let swap_fee = U256::from(get_v2_swap_fee(self.dex));
+                UniswapV2Factory::new(self.address, client)
+                    .pair_created_filter()
+                    .from_block(from_block)
+                    .to_block(to_block)
+                    .query()
+                    .await?
+                    .into_iter()
+                    .map(|p| {
+                        Pool::new(p.pair, p.token_0, p.token_1, swap_fee, self.pool_variant)
+                            .with_factory(self.address)
+                    })
+                    .collect::<Vec<_>>()
+            }
+            (_, PoolVariant::UniswapV3) => UniswapV3Factory::new(self.address, client)
+                .pool_created_filter()
+                .from_block(from_block)
+                .to_block(to_block)
+                .query()
+                .await?
+                .into_iter()
+                .map(|p| {
+                    Pool::new(p.pool, p.token_0, p.token_1, U256::from(p.fee), self.pool_variant)
+                        .with_factory(self.address)
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        debug!(
+            "{:?} number of pools created within block range from {:?} to {:?}",
+            pools.len(),
+            from_block,
+            to_block
+        );
+        Ok(pools)
+    }
+}
+
+/// swap fee (in hundredths of a bip) charged by a uniswap v2 style dex
+pub fn get_v2_swap_fee(dex: DexExchange) -> u32 {
+    match dex {
+        DexExchange::PANCAKE => 2500,
+        // default fee of biswap pairs, it can be changed per pair
+        DexExchange::BISWAP => 1000,
+        _ => 3000,
+    }
+}
+
+/// # Description
+/// get all pools created by the given factories between `start_block` and `end_block`
+/// # Arguments
+/// * `factories` - factories to scan
+/// * `start_block` - if None, will set to the factory creation block
+/// * `end_block` - end block to sync, inclusive
+pub async fn sync_dex<M: Middleware + 'static>(
+    client: Arc<M>,
+    factories: Vec<DexFactory>,
+    start_block: Option<u64>,
+    end_block: u64,
+) -> anyhow::Result<Vec<Pool>> {
+    let mut aggregated_pools = vec![];
+    for factory in factories {
+        let from_block = start_block.unwrap_or(factory.created_blk_num);
+        let pools = get_all_pools(client.clone(), factory, from_block, end_block).await?;
+        info!(
+            "synced {:?} pools of {:?} from block {:?} to {:?}",
+            pools.len(),
+            factory.dex,
+            from_block,
+            end_block
+        );
+        aggregated_pools.extend(pools);
+    }
+    Ok(aggregated_pools)
+}
+
+/// get all pools created by `factory` between `from_block` and `to_block`, scanning block ranges
+/// of `SYNC_BLOCK_STEP` concurrently
+pub async fn get_all_pools<M: Middleware + 'static>(
+    client: Arc<M>,
+    factory: DexFactory,
+    from_block: u64,
+    to_block: u64,
+) -> anyhow::Result<Vec<Pool>> {
+    let ranges = (from_block..=to_block)
+        .step_by(SYNC_BLOCK_STEP as usize)
+        .map(|from| (from, (from + SYNC_BLOCK_STEP - 1).min(to_block)));
+
+    let results = stream::iter(ranges)
+        .map(|(from, to)| {
+            let client = client.clone();
+            async move { factory.fetch_pair_created_event(client, from, to).await }
+        })
+        .buffered(SYNC_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut pools = vec![];
+    for ret in results {
+        pools.extend(ret?);
+    }
+    Ok(pools)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{encode, Token};
+    use meta_contracts::bindings::{
+        uniswapv2factory::PairCreatedFilter, uniswapv3factory::PoolCreatedFilter,
+    };
+
+    fn factory(dex: DexExchange, pool_variant: PoolVariant) -> DexFactory {
+        DexFactory {
+            network: Network::ETH,
+            dex,
+            pool_variant,
+            address: Address::random(),
+            created_blk_num: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pair_created_event() {
+        let factory = factory(DexExchange::UniswapV2, PoolVariant::UniswapV2);
+        let (token_0, token_1, pair) = (Address::random(), Address::random(), Address::random());
+        let log = Log {
+            address: factory.address,
+            topics: vec![PairCreatedFilter::signature(), token_0.into(), token_1.into()],
+            data: encode(&[Token::Address(pair), Token::Uint(U256::one())]).into(),
+            ..Default::default()
+        };
+        let (provider, mock) = Provider::mocked();
+        mock.push::<Vec<Log>, _>(vec![log]).unwrap();
+
+        let pools = factory.fetch_pair_created_event(Arc::new(provider), 0, 100).await.unwrap();
+        assert_eq!(
+            pools,
+            vec![Pool::new(pair, token_0, token_1, U256::from(3000), PoolVariant::UniswapV2)
+                .with_factory(factory.address)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pool_created_event() {
+        let factory = factory(DexExchange::UniswapV3, PoolVariant::UniswapV3);
+        let (token_0, token_1, pool) = (Address::random(), Address::random(), Address::random());
+        let log = Log {
+            address: factory.address,
+            topics: vec![
+                PoolCreatedFilter::signature(),
+                token_0.into(),
+                token_1.into(),
+                H256::from_low_u64_be(500),
+            ],
+            data: encode(&[Token::Int(U256::from(10)), Token::Address(pool)]).into(),
+            ..Default::default()
+        };
+        let (provider, mock) = Provider::mocked();
+        mock.push::<Vec<Log>, _>(vec![log]).unwrap();
+
+        let pools = factory.fetch_pair_created_event(Arc::new(provider), 0, 100).await.unwrap();
+        assert_eq!(
+            pools,
+            vec![Pool::new(pool, token_0, token_1, U256::from(500), PoolVariant::UniswapV3)
+                .with_factory(factory.address)]
+        );
+    }
+}
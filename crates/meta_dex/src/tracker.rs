@@ -0,0 +1,427 @@
+use crate::error::OrderError;
+use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
+use meta_common::enums::Network;
+use meta_util::ether::get_block_time_millis;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::{broadcast, RwLock};
+use tracing::{info, warn};
+
+/// default number of blocks a transaction may stay pending before it is replaced
+pub const DEFAULT_PENDING_TIMEOUT_BLOCKS: u64 = 10;
+
+/// default number of speed ups of an order before it is cancelled
+pub const DEFAULT_MAX_SPEED_UPS: u32 = 2;
+
+/// default fee bump (in bp) of a replacement; nodes reject replacements bumping less than 10%
+pub const DEFAULT_REPLACEMENT_FEE_BUMP_BPS: u32 = 1_250;
+
+/// gas limit of a plain native transfer, such as a cancellation
+pub const NATIVE_TRANSFER_GAS_LIMIT: u64 = 21_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackerConfig {
+    /// interval between two polls of the pending transactions
+    pub poll_interval: Duration,
+    /// time a transaction may stay pending before it is replaced
+    pub timeout: Duration,
+    /// speed ups of an order before it is cancelled
+    pub max_speed_ups: u32,
+    /// fee bump (in bp) of a replacement over the transaction it replaces
+    pub fee_bump_bps: u32,
+    /// estimates the gas limit of a cancellation, where a native transfer costs more than
+    /// `NATIVE_TRANSFER_GAS_LIMIT` as rollups charge l1 data in gas
+    pub estimate_cancel_gas: bool,
+}
+
+impl TrackerConfig {
+    /// default config of `network`, timing out after `DEFAULT_PENDING_TIMEOUT_BLOCKS` blocks
+    pub fn new(network: Network) -> Self {
+        let block_time = get_block_time_millis(network);
+        Self {
+            poll_interval: Duration::from_millis(block_time),
+            timeout: Duration::from_millis(block_time * DEFAULT_PENDING_TIMEOUT_BLOCKS),
+            max_speed_ups: DEFAULT_MAX_SPEED_UPS,
+            fee_bump_bps: DEFAULT_REPLACEMENT_FEE_BUMP_BPS,
+            estimate_cancel_gas: matches!(
+                network,
+                Network::ARBI | Network::MANTLE | Network::ZK_SYNC_ERA | Network::ZK_SYNC_ERA_TEST
+            ),
+        }
+    }
+}
+
+/// how a timed out transaction is replaced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Replacement {
+    /// the same transaction with bumped fees
+    SpeedUp,
+    /// a 0-value transfer to the sender with bumped fees, so the order is not executed
+    Cancel,
+}
+
+/// outcome of an order tracked by `TxTracker`; `order` is the hash it was tracked with
+#[derive(Debug, Clone, PartialEq)]
+pub enum TxEvent {
+    /// the order was mined in `hash`, itself or one of its speed ups
+    Confirmed { order: TxHash, hash: TxHash, receipt: TransactionReceipt },
+    /// the order was mined in `hash` but reverted
+    Reverted { order: TxHash, hash: TxHash, receipt: TransactionReceipt },
+    /// `previous` timed out and `replacement` was sent at the same nonce
+    Replaced { order: TxHash, previous: TxHash, replacement: TxHash, kind: Replacement },
+    /// the order will never be mined: its cancellation was, another transaction used its nonce,
+    /// or the node never knew of it
+    Dropped { order: TxHash },
+}
+
+impl TxEvent {
+    pub fn order(&self) -> TxHash {
+        match self {
+            Self::Confirmed { order, .. }
+            | Self::Reverted { order, .. }
+            | Self::Replaced { order, .. }
+            | Self::Dropped { order } => *order,
+        }
+    }
+}
+
+/// a transaction sent for an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SentTx {
+    hash: TxHash,
+    cancel: bool,
+}
+
+#[derive(Debug, Clone)]
+struct PendingTx {
+    order: TxHash,
+    /// transactions sent at the nonce of the order, oldest first
+    sent: Vec<SentTx>,
+    /// latest transaction sent; `None` until the node returns the order
+    request: Option<TypedTransaction>,
+    speed_ups: u32,
+    /// when the latest transaction was sent
+    sent_at: Instant,
+}
+
+impl PendingTx {
+    fn is_cancelled(&self) -> bool {
+        self.sent.last().is_some_and(|sent| sent.cancel)
+    }
+}
+
+/// raises the fees of `tx` by `bump_bps`, as a replacement of it must
+pub fn bump_fees(tx: &mut TypedTransaction, bump_bps: u32) {
+    let bump = |fee: U256| fee.saturating_mul(U256::from(10_000 + bump_bps)) / 10_000;
+    match tx {
+        TypedTransaction::Eip1559(inner) => {
+            inner.max_fee_per_gas = inner.max_fee_per_gas.map(bump);
+            inner.max_priority_fee_per_gas = inner.max_priority_fee_per_gas.map(bump);
+        }
+        _ => {
+            if let Some(gas_price) = tx.gas_price() {
+                tx.set_gas_price(bump(gas_price));
+            }
+        }
+    }
+}
+
+/// # Description
+/// A 0-value transfer from the sender of `tx` to itself at the nonce of `tx`, with the fees of
+/// `tx`; once mined, `tx` can not be
+pub fn get_cancel_request(tx: &TypedTransaction) -> Option<TypedTransaction> {
+    let from = *tx.from()?;
+    let mut cancel = tx.clone();
+    cancel
+        .set_to(from)
+        .set_value(U256::zero())
+        .set_data(Bytes::default())
+        .set_access_list(Default::default())
+        .set_gas(NATIVE_TRANSFER_GAS_LIMIT);
+    Some(cancel)
+}
+
+/// # Description
+/// Tracks the transactions sent for orders until they are mined or dropped. A transaction pending
+/// for longer than the timeout is sped up, then cancelled once `max_speed_ups` is reached. The
+/// outcome of each order is sent to the subscribers of `subscribe`
+#[derive(Debug)]
+pub struct TxTracker<M> {
+    client: Arc<M>,
+    pub config: TrackerConfig,
+    /// order => its pending transactions
+    pending: RwLock<HashMap<TxHash, PendingTx>>,
+    sender: broadcast::Sender<TxEvent>,
+}
+
+impl<M: Middleware> TxTracker<M> {
+    pub fn new(client: Arc<M>, config: TrackerConfig) -> Self {
+        let (sender, _) = broadcast::channel(64);
+        Self { client, config, pending: RwLock::new(HashMap::new()), sender }
+    }
+
+    /// subscribe to the events of the orders from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<TxEvent> {
+        self.sender.subscribe()
+    }
+
+    /// track the order sent in transaction `hash`
+    pub async fn track(&self, hash: TxHash) {
+        let pending = PendingTx {
+            order: hash,
+            sent: vec![SentTx { hash, cancel: false }],
+            request: None,
+            speed_ups: 0,
+            sent_at: Instant::now(),
+        };
+        self.pending.write().await.insert(hash, pending);
+    }
+
+    /// orders not mined or dropped yet
+    pub async fn get_pending_orders(&self) -> Vec<TxHash> {
+        self.pending.read().await.keys().copied().collect()
+    }
+
+    /// polls the pending orders every `poll_interval`, forever
+    pub async fn run(&self) {
+        let mut interval = tokio::time::interval(self.config.poll_interval);
+        loop {
+            interval.tick().await;
+            self.poll().await;
+        }
+    }
+
+    /// # Description
+    /// Checks each pending order once, sending the events of those mined, replaced or dropped
+    ///
+    /// Returns the events sent
+    pub async fn poll(&self) -> Vec<TxEvent> {
+        let orders = self.get_pending_orders().await;
+        let mut events = vec![];
+        for order in orders {
+            let mut pending = match self.pending.read().await.get(&order) {
+                Some(pending) => pending.clone(),
+                None => continue,
+            };
+            let (event, done) = match self.poll_order(&mut pending).await {
+                Ok(ret) => ret,
+                Err(e) => {
+                    warn!("error in poll order {:?}, {:?}", order, e);
+                    continue;
+                }
+            };
+            if done {
+                self.pending.write().await.remove(&order);
+            } else {
+                self.pending.write().await.insert(order, pending);
+            }
+            if let Some(event) = event {
+                info!("order event {:?}", event);
+                // no receiver left is not an error for the tracker
+                let _ = self.sender.send(event.clone());
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// returns the event of the order, if any, and whether it is no longer pending
+    async fn poll_order(
+        &self,
+        pending: &mut PendingTx,
+    ) -> Result<(Option<TxEvent>, bool), OrderError<M>> {
+        let order = pending.order;
+        let request = match pending.request {
+            Some(ref request) => request.clone(),
+            None => match self
+                .client
+                .get_transaction(order)
+                .await
+                .map_err(ContractError::from_middleware_error)?
+            {
+                Some(tx) => {
+                    let request: TypedTransaction = (&tx).into();
+                    pending.request = Some(request.clone());
+                    request
+                }
+                None if pending.sent_at.elapsed() >= self.config.timeout => {
+                    return Ok((Some(TxEvent::Dropped { order }), true));
+                }
+                None => return Ok((None, false)),
+            },
+        };
+        let (from, nonce) = match (request.from(), request.nonce()) {
+            (Some(from), Some(nonce)) => (*from, *nonce),
+            _ => return Ok((Some(TxEvent::Dropped { order }), true)),
+        };
+
+        // the nonce is read before the receipts, so a transaction mined in between is not taken
+        // for one of another sender
+        let mined_nonce = self
+            .client
+            .get_transaction_count(from, Some(BlockNumber::Latest.into()))
+            .await
+            .map_err(ContractError::from_middleware_error)?;
+        for sent in pending.sent.iter().rev() {
+            let receipt = self
+                .client
+                .get_transaction_receipt(sent.hash)
+                .await
+                .map_err(ContractError::from_middleware_error)?;
+            if let Some(receipt) = receipt {
+                let event = if sent.cancel {
+                    TxEvent::Dropped { order }
+                } else if receipt.status.eq(&Some(U64::one())) {
+                    TxEvent::Confirmed { order, hash: sent.hash, receipt }
+                } else {
+                    TxEvent::Reverted { order, hash: sent.hash, receipt }
+                };
+                return Ok((Some(event), true));
+            }
+        }
+        if mined_nonce > nonce {
+            return Ok((Some(TxEvent::Dropped { order }), true));
+        }
+
+        if pending.sent_at.elapsed() < self.config.timeout {
+            return Ok((None, false));
+        }
+        let kind = if pending.is_cancelled() || pending.speed_ups >= self.config.max_speed_ups {
+            Replacement::Cancel
+        } else {
+            Replacement::SpeedUp
+        };
+        let mut replacement = match kind {
+            Replacement::Cancel if !pending.is_cancelled() => {
+                let mut cancel = get_cancel_request(&request).ok_or(OrderError::SenderNotFound)?;
+                if self.config.estimate_cancel_gas {
+                    let gas = self
+                        .client
+                        .estimate_gas(&cancel, None)
+                        .await
+                        .map_err(ContractError::from_middleware_error)?;
+                    cancel.set_gas(gas);
+                }
+                cancel
+            }
+            _ => request,
+        };
+        bump_fees(&mut replacement, self.config.fee_bump_bps);
+        let previous = pending.sent.last().map(|sent| sent.hash).unwrap_or(order);
+        let hash = match self.client.send_transaction(replacement.clone(), None).await {
+            Ok(tx) => tx.tx_hash(),
+            Err(e) => {
+                // the pending transaction may have been mined meanwhile, the next poll tells
+                warn!("error in replace {:?} of order {:?}, {:?}", previous, order, e);
+                return Ok((None, false));
+            }
+        };
+        pending.sent.push(SentTx { hash, cancel: kind.eq(&Replacement::Cancel) });
+        pending.request = Some(replacement);
+        pending.sent_at = Instant::now();
+        if kind.eq(&Replacement::SpeedUp) {
+            pending.speed_ups += 1;
+        }
+        Ok((Some(TxEvent::Replaced { order, previous, replacement: hash, kind }), false))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_request() -> TypedTransaction {
+        let mut tx = TypedTransaction::Eip1559(
+            Eip1559TransactionRequest::new()
+                .from(Address::from_low_u64_be(1))
+                .to(Address::from_low_u64_be(2))
+                .value(100)
+                .data(vec![1, 2, 3])
+                .nonce(7)
+                .gas(200_000)
+                .max_fee_per_gas(40_000_000_000u64)
+                .max_priority_fee_per_gas(1_000_000_000u64),
+        );
+        tx.set_chain_id(1);
+        tx
+    }
+
+    #[test]
+    fn test_bump_fees() {
+        let mut tx = get_request();
+        bump_fees(&mut tx, DEFAULT_REPLACEMENT_FEE_BUMP_BPS);
+        match tx {
+            TypedTransaction::Eip1559(ref inner) => {
+                assert_eq!(inner.max_fee_per_gas, Some(U256::from(45_000_000_000u64)));
+                assert_eq!(inner.max_priority_fee_per_gas, Some(U256::from(1_125_000_000u64)));
+            }
+            _ => panic!("not an eip-1559 transaction"),
+        }
+
+        let mut tx =
+            TypedTransaction::Legacy(TransactionRequest::new().gas_price(3_000_000_000u64));
+        bump_fees(&mut tx, 1_000);
+        assert_eq!(tx.gas_price(), Some(U256::from(3_300_000_000u64)));
+    }
+
+    #[test]
+    fn test_get_cancel_request() {
+        let tx = get_request();
+        let cancel = get_cancel_request(&tx).unwrap();
+        assert_eq!(cancel.to_addr(), Some(&Address::from_low_u64_be(1)));
+        assert_eq!(cancel.value(), Some(&U256::zero()));
+        assert_eq!(cancel.data(), Some(&Bytes::default()));
+        assert_eq!(cancel.nonce(), Some(&U256::from(7)));
+        assert_eq!(cancel.gas(), Some(&U256::from(NATIVE_TRANSFER_GAS_LIMIT)));
+        assert_eq!(cancel.gas_price(), tx.gas_price());
+
+        assert!(get_cancel_request(&TypedTransaction::Legacy(TransactionRequest::new())).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_poll() {
+        let (provider, mock) = Provider::mocked();
+        let tracker = TxTracker::new(Arc::new(provider), TrackerConfig::new(Network::ETH));
+        let mut events = tracker.subscribe();
+        let order = H256::from_low_u64_be(1);
+        tracker.track(order).await;
+
+        // responses are popped from the back: the transaction, the nonce, then the receipt
+        let receipt = TransactionReceipt {
+            transaction_hash: order,
+            status: Some(U64::one()),
+            ..Default::default()
+        };
+        mock.push(receipt.clone()).unwrap();
+        mock.push(U256::from(8)).unwrap();
+        let tx = Transaction {
+            hash: order,
+            from: Address::from_low_u64_be(1),
+            nonce: U256::from(7),
+            ..Default::default()
+        };
+        mock.push(tx).unwrap();
+
+        let expected = TxEvent::Confirmed { order, hash: order, receipt };
+        assert_eq!(tracker.poll().await, vec![expected.clone()]);
+        assert_eq!(events.recv().await.unwrap(), expected);
+        assert!(tracker.get_pending_orders().await.is_empty());
+
+        // the nonce was used by another transaction
+        tracker.track(order).await;
+        mock.push::<Option<TransactionReceipt>, _>(None).unwrap();
+        mock.push(U256::from(8)).unwrap();
+        mock.push(Transaction {
+            hash: order,
+            from: Address::from_low_u64_be(1),
+            nonce: U256::from(7),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(tracker.poll().await, vec![TxEvent::Dropped { order }]);
+        assert!(tracker.get_pending_orders().await.is_empty());
+    }
+}
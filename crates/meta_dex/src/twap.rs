@@ -0,0 +1,172 @@
+use crate::DexService;
+use ethers::prelude::*;
+use meta_contracts::bindings::uniswapv3pool::UniswapV3Pool;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+
+/// default window of the twap guarding trades: 30 minutes
+pub const DEFAULT_TWAP_WINDOW_SECS: u32 = 1_800;
+
+/// default max deviation (in bp) of the spot price from the twap before a trade is vetoed
+pub const DEFAULT_MAX_TWAP_DEVIATION_BPS: u32 = 200;
+
+/// default number of intervals the realized volatility of a window is sampled over
+pub const DEFAULT_VOLATILITY_INTERVALS: u32 = 12;
+
+/// ln(1.0001), the log price step between two adjacent ticks
+const LN_TICK_BASE: f64 = 0.000_099_995_000_333_308;
+
+/// time weighted average tick of a v3 pool over `window` seconds up to the latest block, with
+/// the current tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V3Twap {
+    pub window: u32,
+    /// arithmetic mean tick, rounded to negative infinity as `OracleLibrary.consult` does
+    pub tick: i32,
+    pub spot_tick: i32,
+}
+
+impl V3Twap {
+    /// twap price of token 0 in token 1, in units of the tokens
+    pub fn get_price(&self, decimals_0: u8, decimals_1: u8) -> Option<Decimal> {
+        get_price_at_tick(self.tick, decimals_0, decimals_1)
+    }
+
+    /// spot price of token 0 in token 1, in units of the tokens
+    pub fn get_spot_price(&self, decimals_0: u8, decimals_1: u8) -> Option<Decimal> {
+        get_price_at_tick(self.spot_tick, decimals_0, decimals_1)
+    }
+
+    /// deviation (in bp) of the spot price from the twap price, either way
+    pub fn get_deviation_bps(&self) -> Decimal {
+        get_tick_deviation_bps(self.spot_tick, self.tick)
+    }
+
+    /// whether the spot price deviates from the twap price by more than `max_deviation_bps`
+    pub fn is_deviated(&self, max_deviation_bps: u32) -> bool {
+        self.get_deviation_bps() > Decimal::from(max_deviation_bps)
+    }
+}
+
+/// mean tick between two tick cumulatives observed `window` seconds apart, rounded to negative
+/// infinity
+pub fn get_mean_tick(tick_cumulative_start: i64, tick_cumulative_end: i64, window: u32) -> i32 {
+    (tick_cumulative_end - tick_cumulative_start).div_euclid(window.max(1) as i64) as i32
+}
+
+/// price of token 0 in token 1 at `tick`, in units of the tokens
+pub fn get_price_at_tick(tick: i32, decimals_0: u8, decimals_1: u8) -> Option<Decimal> {
+    let scale = 10f64.powi(decimals_0 as i32 - decimals_1 as i32);
+    Decimal::from_f64((tick as f64 * LN_TICK_BASE).exp() * scale)
+}
+
+/// deviation (in bp) of the price at `tick` from the price at `reference_tick`, either way
+pub fn get_tick_deviation_bps(tick: i32, reference_tick: i32) -> Decimal {
+    let ratio = ((tick as f64 - reference_tick as f64) * LN_TICK_BASE).exp();
+    Decimal::from_f64((ratio - 1.0).abs() * 10_000.0).unwrap_or(Decimal::MAX)
+}
+
+/// # Description
+/// Realized volatility of the log price from tick cumulatives observed `interval` seconds apart,
+/// oldest first: the square root of the summed squared log returns between the mean prices of
+/// consecutive intervals. `None` if there are less than two intervals
+pub fn get_realized_volatility(tick_cumulatives: &[i64], interval: u32) -> Option<Decimal> {
+    if tick_cumulatives.len() < 3 || interval == 0 {
+        return None;
+    }
+    let mean_ticks = tick_cumulatives
+        .windows(2)
+        .map(|w| (w[1] - w[0]) as f64 / interval as f64)
+        .collect::<Vec<_>>();
+    let variance =
+        mean_ticks.windows(2).map(|w| ((w[1] - w[0]) * LN_TICK_BASE).powi(2)).sum::<f64>();
+    Decimal::from_f64(variance.sqrt())
+}
+
+/// # Description
+/// Reads the twap of `pool` over the last `window` seconds with `observe`, along its current
+/// tick. Fails if the pool has no observation as old as `window`, so the window must fit in the
+/// observations the cardinality of the pool already keeps
+pub async fn get_v3_twap<M: Middleware + 'static>(
+    pool: &UniswapV3Pool<M>,
+    window: u32,
+) -> anyhow::Result<V3Twap> {
+    anyhow::ensure!(window > 0, "twap window must be positive");
+    let (observe_call, slot_0_call) = (pool.observe(vec![window, 0]), pool.slot_0());
+    let ((tick_cumulatives, _), (_, spot_tick, ..)) =
+        futures::try_join!(observe_call.call(), slot_0_call.call())?;
+    match tick_cumulatives[..] {
+        [start, end] => Ok(V3Twap { window, tick: get_mean_tick(start, end, window), spot_tick }),
+        _ => anyhow::bail!("unexpected observations {:?}", tick_cumulatives),
+    }
+}
+
+/// # Description
+/// Realized volatility of the price of `pool` over the last `window` seconds, sampled in
+/// `intervals` intervals, see `get_realized_volatility`
+pub async fn get_v3_realized_volatility<M: Middleware + 'static>(
+    pool: &UniswapV3Pool<M>,
+    window: u32,
+    intervals: u32,
+) -> anyhow::Result<Decimal> {
+    anyhow::ensure!(
+        intervals >= 2 && window >= intervals,
+        "window too short for {} intervals",
+        intervals
+    );
+    let interval = window / intervals;
+    let seconds_agos = (0..=intervals).rev().map(|i| i * interval).collect::<Vec<_>>();
+    let (tick_cumulatives, _) = pool.observe(seconds_agos).call().await?;
+    get_realized_volatility(&tick_cumulatives, interval)
+        .ok_or_else(|| anyhow::anyhow!("not enough observations {:?}", tick_cumulatives))
+}
+
+impl<M: Middleware + 'static> DexService<M> {
+    /// twap over the last `window` seconds of the v3 pool of `token_a` and `token_b` with `fee`
+    pub async fn get_v3_twap(
+        &self,
+        token_a: Address,
+        token_b: Address,
+        fee: u32,
+        window: u32,
+    ) -> anyhow::Result<V3Twap> {
+        let pool = self.dex_contracts.get_v3_pool(token_a, token_b, fee).await?;
+        anyhow::ensure!(!pool.address().is_zero(), "no v3 pool with fee {}", fee);
+        get_v3_twap(&pool, window).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_mean_tick() {
+        assert_eq!(get_mean_tick(1_000, 1_600, 60), 10);
+        // rounded to negative infinity
+        assert_eq!(get_mean_tick(0, -601, 60), -11);
+        assert_eq!(get_mean_tick(0, -600, 60), -10);
+    }
+
+    #[test]
+    fn test_twap_deviation() {
+        // 1.0001^100 - 1 is about 100.5 bp
+        let twap = V3Twap { window: DEFAULT_TWAP_WINDOW_SECS, tick: 0, spot_tick: 100 };
+        assert_eq!(twap.get_deviation_bps().round_dp(1), Decimal::new(1005, 1));
+        assert!(twap.is_deviated(100));
+        assert!(!twap.is_deviated(DEFAULT_MAX_TWAP_DEVIATION_BPS));
+
+        // weth (18 decimals) in usdc (6 decimals) around tick -200000
+        let price = get_price_at_tick(-200_000, 18, 6).unwrap();
+        assert_eq!(price.round(), Decimal::from(2_063));
+    }
+
+    #[test]
+    fn test_get_realized_volatility() {
+        // flat price
+        assert_eq!(get_realized_volatility(&[0, 600, 1_200], 60), Some(Decimal::ZERO));
+        // mean ticks 10 then 20
+        let volatility = get_realized_volatility(&[0, 600, 1_800], 60).unwrap();
+        assert_eq!(volatility.round_dp(8), Decimal::new(99995, 8));
+        assert_eq!(get_realized_volatility(&[0, 600], 60), None);
+    }
+}
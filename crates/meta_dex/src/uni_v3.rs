@@ -0,0 +1,79 @@
+use ethers::{abi::RawLog, prelude::*};
+use futures::future::try_join_all;
+use meta_contracts::bindings::uniswapv3pool::{UniswapV3Pool, UniswapV3PoolEvents};
+use meta_util::defi::uni_v3::{TickInfo, V3PoolState, MAX_TICK, MIN_TICK};
+
+/// number of tick bitmap words on each side of the current tick held by a pool snapshot
+pub const V3_SNAPSHOT_WORD_RADIUS: i16 = 2;
+
+/// # Description
+/// Loads the state of a uniswap v3 pool needed to simulate swaps locally, as of `block`
+/// # Arguments
+/// * `word_radius` - tick bitmap words on each side of the current tick to load, see `V3_SNAPSHOT_WORD_RADIUS`
+pub async fn load_v3_pool_state<M: Middleware + 'static>(
+    pool: &UniswapV3Pool<M>,
+    word_radius: i16,
+    block: BlockId,
+) -> anyhow::Result<V3PoolState> {
+    let (slot_0_call, liquidity_call, fee_call, tick_spacing_call) = (
+        pool.slot_0().block(block),
+        pool.liquidity().block(block),
+        pool.fee().block(block),
+        pool.tick_spacing().block(block),
+    );
+    let (slot_0, liquidity, fee, tick_spacing) = futures::try_join!(
+        slot_0_call.call(),
+        liquidity_call.call(),
+        fee_call.call(),
+        tick_spacing_call.call(),
+    )?;
+    let (sqrt_price_x96, tick, ..) = slot_0;
+    let mut state = V3PoolState::new(sqrt_price_x96, tick, liquidity, fee, tick_spacing);
+
+    let min_word = (MIN_TICK.div_euclid(tick_spacing) >> 8) as i16;
+    let max_word = (MAX_TICK.div_euclid(tick_spacing) >> 8) as i16;
+    let current_word = (tick.div_euclid(tick_spacing) >> 8) as i16;
+    let (low, high) = (
+        current_word.saturating_sub(word_radius).max(min_word),
+        current_word.saturating_add(word_radius).min(max_word),
+    );
+    state.word_range = Some((low, high));
+
+    let bitmap_calls =
+        (low..=high).map(|word_pos| pool.tick_bitmap(word_pos).block(block)).collect::<Vec<_>>();
+    let words = try_join_all(bitmap_calls.iter().map(|call| call.call())).await?;
+
+    let mut initialized_ticks = vec![];
+    for (word_pos, word) in (low..=high).zip(words) {
+        if !word.is_zero() {
+            state.tick_bitmap.insert(word_pos, word);
+        }
+        for bit_pos in (0..256).filter(|bit_pos| word.bit(*bit_pos)) {
+            initialized_ticks.push(((word_pos as i32) * 256 + bit_pos as i32) * tick_spacing);
+        }
+    }
+    let tick_calls =
+        initialized_ticks.iter().map(|tick| pool.ticks(*tick).block(block)).collect::<Vec<_>>();
+    let infos = try_join_all(tick_calls.iter().map(|call| call.call())).await?;
+    for (tick, (liquidity_gross, liquidity_net, ..)) in initialized_ticks.into_iter().zip(infos) {
+        state.ticks.insert(tick, TickInfo { liquidity_gross, liquidity_net });
+    }
+    Ok(state)
+}
+
+/// apply a `Swap`, `Mint` or `Burn` log of the pool to its snapshot; other events are ignored
+pub fn apply_v3_pool_log(state: &mut V3PoolState, log: &Log) -> anyhow::Result<()> {
+    match UniswapV3PoolEvents::decode_log(&RawLog::from(log.clone()))? {
+        UniswapV3PoolEvents::SwapFilter(swap) => {
+            state.apply_swap(swap.sqrt_price_x96, swap.liquidity, swap.tick)
+        }
+        UniswapV3PoolEvents::MintFilter(mint) => {
+            state.apply_liquidity_delta(mint.tick_lower, mint.tick_upper, mint.amount as i128)
+        }
+        UniswapV3PoolEvents::BurnFilter(burn) => {
+            state.apply_liquidity_delta(burn.tick_lower, burn.tick_upper, -(burn.amount as i128))
+        }
+        _ => {}
+    }
+    Ok(())
+}
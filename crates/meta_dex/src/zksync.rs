@@ -0,0 +1,223 @@
+use crate::{
+    error::OrderError,
+    quoter::{MuteSwitchQuoter, SyncSwapQuoter},
+    DexService,
+};
+use ethers::{
+    abi::{encode, Token},
+    prelude::*,
+};
+use meta_address::TokenInfo;
+use meta_common::enums::ContractType;
+use meta_contracts::bindings::{
+    muteswitchfactory::MuteSwitchFactory,
+    muteswitchrouter::MuteSwitchRouter,
+    syncswapclassicpoolfactory::SyncSwapClassicPoolFactory,
+    syncswaprouter::{SwapPath, SwapStep, SyncSwapRouter},
+};
+use meta_util::{defi::get_amount_out_min, ether::decimal_to_wei, time::get_current_ts};
+use rust_decimal::Decimal;
+use tracing::info;
+
+/// withdraw mode of the syncswap vault paying the token out as the native token
+pub const SYNCSWAP_WITHDRAW_UNWRAPPED: u8 = 1;
+
+/// withdraw mode of the syncswap vault paying the token out as is
+pub const SYNCSWAP_WITHDRAW_WRAPPED: u8 = 2;
+
+/// `data` of a syncswap pool step: the token in, the recipient and the withdraw mode of the
+/// token out
+pub fn encode_syncswap_step_data(
+    token_in: Address,
+    recipient: Address,
+    withdraw_mode: u8,
+) -> Bytes {
+    encode(&[
+        Token::Address(token_in),
+        Token::Address(recipient),
+        Token::Uint(withdraw_mode.into()),
+    ])
+    .into()
+}
+
+impl<M: Middleware> DexService<M> {
+    /// # Description
+    /// (amount in, min amount out) of an order on a dex whose router only swaps exact inputs. A
+    /// buy spends the amount in quoted for `amount` of base, so the base received may fall short
+    /// of it by `slippage_bps`
+    ///
+    /// # Arguments
+    /// * `quoted` - amount out of the base sold, or amount in of the base bought
+    fn get_exact_input_amounts(
+        &self,
+        quoted: U256,
+        amount: Decimal,
+        limit_price: Option<Decimal>,
+        token_in: &TokenInfo,
+        token_out: &TokenInfo,
+    ) -> Result<(U256, U256), OrderError<M>> {
+        if amount.is_sign_negative() {
+            let amount_in = decimal_to_wei(amount.abs(), token_in.decimals.into());
+            Ok((amount_in, self.get_amount_out_min(quoted, amount, limit_price, token_out)?))
+        } else {
+            // only rejects a quote worse than the limit price, the quoted amount in is spent
+            self.get_amount_in_max(quoted, amount, limit_price, token_in)?;
+            let amount_out = decimal_to_wei(amount, token_out.decimals.into());
+            Ok((quoted, get_amount_out_min(amount_out, self.slippage_bps)))
+        }
+    }
+
+    /// # Description
+    /// swap `amount` of base against quote through the syncswap classic or stable pool quoting
+    /// best; negative `amount` sells base, positive buys base. See `get_exact_input_amounts`
+    pub(crate) async fn submit_syncswap_order(
+        &self,
+        base: TokenInfo,
+        quote: TokenInfo,
+        amount: Decimal,
+        recipient: Address,
+        limit_price: Option<Decimal>,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let (token_in, token_out) =
+            if amount.is_sign_negative() { (base, quote) } else { (quote, base) };
+        let (address_in, address_out) =
+            (self.get_pool_token_address(&token_in)?, self.get_pool_token_address(&token_out)?);
+
+        let factory = SyncSwapClassicPoolFactory::new(
+            self.get_contract_address(ContractType::UniV2Factory)?,
+            self.client.clone(),
+        );
+        let stable_factory = self
+            .get_contract_address(ContractType::SyncSwapStablePoolFactory)
+            .ok()
+            .map(|address| SyncSwapClassicPoolFactory::new(address, self.client.clone()));
+        let quoter = SyncSwapQuoter::new(factory, stable_factory);
+        let best = if amount.is_sign_negative() {
+            let amount_in = decimal_to_wei(amount.abs(), token_in.decimals.into());
+            quoter.get_best_amount_out(address_in, address_out, amount_in).await
+        } else {
+            let amount_out = decimal_to_wei(amount, token_out.decimals.into());
+            quoter.get_best_amount_in(address_in, address_out, amount_out).await
+        };
+        let (pool, quoted) = best
+            .map_err(OrderError::QuoteError)?
+            .ok_or(OrderError::NoLiquidity(self.dex_exchange))?;
+        let (amount_in, amount_out_min) =
+            self.get_exact_input_amounts(quoted, amount, limit_price, &token_in, &token_out)?;
+
+        let withdraw_mode =
+            if token_out.native { SYNCSWAP_WITHDRAW_UNWRAPPED } else { SYNCSWAP_WITHDRAW_WRAPPED };
+        let path = SwapPath {
+            steps: vec![SwapStep {
+                pool,
+                data: encode_syncswap_step_data(address_in, recipient, withdraw_mode),
+                callback: Address::zero(),
+                callback_data: Bytes::default(),
+            }],
+            // the router wraps the native token paid, which it takes as the zero address
+            token_in: if token_in.native { Address::zero() } else { address_in },
+            amount_in,
+        };
+        info!(
+            "syncswap swap through pool {:?}, amount_in: {:?}, amount_out_min: {:?}",
+            pool, amount_in, amount_out_min
+        );
+
+        let router = SyncSwapRouter::new(
+            self.get_contract_address(ContractType::UniV2RouterV2)?,
+            self.client.clone(),
+        );
+        let mut call = router.swap(vec![path], amount_out_min, ddl.into());
+        if token_in.native {
+            call = call.value(amount_in);
+        }
+        self.send_swap_tx(call.tx, &token_in, address_in, router.address(), amount_in).await
+    }
+
+    /// # Description
+    /// swap `amount` of base against quote through the mute switch volatile pair; negative
+    /// `amount` sells base, positive buys base. See `get_exact_input_amounts`
+    pub(crate) async fn submit_mute_switch_order(
+        &self,
+        base: TokenInfo,
+        quote: TokenInfo,
+        amount: Decimal,
+        recipient: Address,
+        limit_price: Option<Decimal>,
+    ) -> Result<TxHash, OrderError<M>> {
+        let ddl = get_current_ts().as_secs() + 1000000;
+        let (token_in, token_out) =
+            if amount.is_sign_negative() { (base, quote) } else { (quote, base) };
+        let (address_in, address_out) =
+            (self.get_pool_token_address(&token_in)?, self.get_pool_token_address(&token_out)?);
+
+        let quoter = MuteSwitchQuoter::new(MuteSwitchFactory::new(
+            self.get_contract_address(ContractType::UniV2Factory)?,
+            self.client.clone(),
+        ));
+        let quoted = if amount.is_sign_negative() {
+            let amount_in = decimal_to_wei(amount.abs(), token_in.decimals.into());
+            quoter.get_amount_out(address_in, address_out, amount_in).await
+        } else {
+            let amount_out = decimal_to_wei(amount, token_out.decimals.into());
+            quoter.get_amount_in(address_in, address_out, amount_out).await
+        };
+        let quoted = quoted
+            .map_err(OrderError::QuoteError)?
+            .ok_or(OrderError::NoLiquidity(self.dex_exchange))?;
+        let (amount_in, amount_out_min) =
+            self.get_exact_input_amounts(quoted, amount, limit_price, &token_in, &token_out)?;
+
+        // the pair quoted is the volatile one
+        let (path, stable) = (vec![address_in, address_out], vec![false]);
+        info!(
+            "mute switch swap, amount_in: {:?}, amount_out_min: {:?}, path: {:?}",
+            amount_in, amount_out_min, path
+        );
+        let router = MuteSwitchRouter::new(
+            self.get_contract_address(ContractType::UniV2RouterV2)?,
+            self.client.clone(),
+        );
+        let call = match (token_in.native, token_out.native) {
+            (true, _) => router
+                .swap_exact_eth_for_tokens(amount_out_min, path, recipient, ddl.into(), stable)
+                .value(amount_in),
+            (_, true) => router.swap_exact_tokens_for_eth(
+                amount_in,
+                amount_out_min,
+                path,
+                recipient,
+                ddl.into(),
+                stable,
+            ),
+            _ => router.swap_exact_tokens_for_tokens(
+                amount_in,
+                amount_out_min,
+                path,
+                recipient,
+                ddl.into(),
+                stable,
+            ),
+        };
+        self.send_swap_tx(call.tx, &token_in, address_in, router.address(), amount_in).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::{decode, ParamType};
+
+    #[test]
+    fn test_encode_syncswap_step_data() {
+        let (token_in, recipient) = (Address::from_low_u64_be(1), Address::from_low_u64_be(2));
+        let data = encode_syncswap_step_data(token_in, recipient, SYNCSWAP_WITHDRAW_UNWRAPPED);
+        let tokens =
+            decode(&[ParamType::Address, ParamType::Address, ParamType::Uint(8)], &data).unwrap();
+        assert_eq!(
+            tokens,
+            vec![Token::Address(token_in), Token::Address(recipient), Token::Uint(U256::one())]
+        );
+    }
+}
@@ -9,13 +9,17 @@ struct CommonClub48Result {
     result: String,
 }
 
-struct Club48Client {
+/// rpc endpoint of the 48 club puissant builder on bsc
+pub const CLUB48_URL: &str = "https://puissant-bsc.48.club";
+
+#[derive(Debug)]
+pub struct Club48Client {
     url: String,
     id: u64,
     client: reqwest::Client,
 }
 impl Club48Client {
-    fn new(url: String) -> Club48Client {
+    pub fn new(url: String) -> Club48Client {
         let mut default_header = header::HeaderMap::new();
         default_header.insert("Content-Type", header::HeaderValue::from_static("application/json"));
         let client = reqwest::ClientBuilder::new()
@@ -25,7 +29,8 @@ impl Club48Client {
             .expect("unable to build http client");
         Club48Client { url, client, id: 0 }
     }
-    async fn query_gas_price_floor(&mut self) -> Result<u64, reqwest::Error> {
+    /// min gas price (in wei) accepted by the builder
+    pub async fn query_gas_price_floor(&mut self) -> Result<u64, reqwest::Error> {
         self.id += 1;
         let response = self
             .client
@@ -43,7 +48,7 @@ impl Club48Client {
 
     /// send private tx to club 48
     /// return txHash
-    async fn send_private_transaction(&mut self, tx: &str) -> Result<String, reqwest::Error> {
+    pub async fn send_private_transaction(&mut self, tx: &str) -> Result<String, reqwest::Error> {
         self.id += 1;
         let response = self
             .client
@@ -62,7 +67,7 @@ impl Club48Client {
         Ok(info.result)
     }
 
-    async fn send_bundled_transaction(
+    pub async fn send_bundled_transaction(
         &mut self,
         txs: Vec<&str>,
         max_timestamp: u64,
@@ -117,7 +122,7 @@ mod test_client {
     }
 
     #[tokio::test]
-    async fn send_bundled_transaction() {
+    pub async fn send_bundled_transaction() {
         let current_ts = get_current_ts().as_secs();
         let inputs = vec![
             "0xf86c0485104c533c0082520894dc8a5fc5222cb86b47bdbd8d5d45633b8d9ccff787038d7ea4c68000808194a06e14e6290eb35ba8c0ec9c29fcb52734a627a69ceaa8f2952c99b0b362db1999a0217eca68fb8bd59457ca4297ab9a29bc3a9a57443a88991984c253b4013348c7", 
@@ -1 +1 @@
-mod club48;
+pub mod club48;
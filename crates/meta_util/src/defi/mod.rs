@@ -1,3 +1,5 @@
+pub mod uni_v3;
+
 use ethers::prelude::*;
 
 use lazy_static::lazy_static;
@@ -26,6 +28,106 @@ pub fn get_swap_price_limit(token_a: Address, token_b: Address, token_in: Addres
     }
 }
 
+/// lower bound of a quoted output amount, allowing `slippage_bps` basis points of slippage
+pub fn get_amount_out_min(amount_out: U256, slippage_bps: u32) -> U256 {
+    let slippage_bps = slippage_bps.min(10_000);
+    amount_out * U256::from(10_000 - slippage_bps) / U256::from(10_000)
+}
+
+/// upper bound of a quoted input amount, allowing `slippage_bps` basis points of slippage; rounded up
+pub fn get_amount_in_max(amount_in: U256, slippage_bps: u32) -> U256 {
+    let scaled = amount_in * U256::from(10_000 + slippage_bps);
+    (scaled + U256::from(9_999)) / U256::from(10_000)
+}
+
+/// min amount out of a swap, given a fresh quote and optionally the amount expected at the price that
+/// triggered the trade. Returns `None` if the quote is already worse than the expected amount allows
+pub fn get_bounded_amount_out_min(
+    quoted_out: U256,
+    expected_out: Option<U256>,
+    slippage_bps: u32,
+) -> Option<U256> {
+    let amount_out_min = get_amount_out_min(quoted_out, slippage_bps);
+    match expected_out {
+        Some(expected) => {
+            let floor = get_amount_out_min(expected, slippage_bps);
+            if quoted_out < floor {
+                None
+            } else {
+                Some(amount_out_min.max(floor))
+            }
+        }
+        None => Some(amount_out_min),
+    }
+}
+
+/// max amount in of a swap, given a fresh quote and optionally the amount expected at the price that
+/// triggered the trade. Returns `None` if the quote is already worse than the expected amount allows
+pub fn get_bounded_amount_in_max(
+    quoted_in: U256,
+    expected_in: Option<U256>,
+    slippage_bps: u32,
+) -> Option<U256> {
+    let amount_in_max = get_amount_in_max(quoted_in, slippage_bps);
+    match expected_in {
+        Some(expected) => {
+            let ceiling = get_amount_in_max(expected, slippage_bps);
+            if quoted_in > ceiling {
+                None
+            } else {
+                Some(amount_in_max.min(ceiling))
+            }
+        }
+        None => Some(amount_in_max),
+    }
+}
+
+/// amount out of a constant product (uniswap v2 style) pool; `fee` is in hundredths of a bip
+pub fn get_v2_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256, fee: u32) -> U256 {
+    if amount_in.is_zero() || reserve_in.is_zero() || reserve_out.is_zero() {
+        return U256::zero();
+    }
+    let amount_in_with_fee = amount_in * U256::from(1_000_000 - fee.min(1_000_000));
+    let numerator = amount_in_with_fee * reserve_out;
+    let denominator = reserve_in * U256::from(1_000_000) + amount_in_with_fee;
+    numerator / denominator
+}
+
+/// amount in of a constant product (uniswap v2 style) pool; `fee` is in hundredths of a bip.
+/// Returns `None` if the pool can not pay `amount_out`
+pub fn get_v2_amount_in(
+    amount_out: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee: u32,
+) -> Option<U256> {
+    if amount_out.is_zero() || reserve_in.is_zero() || amount_out >= reserve_out || fee >= 1_000_000
+    {
+        return None;
+    }
+    let numerator = reserve_in * amount_out * U256::from(1_000_000);
+    let denominator = (reserve_out - amount_out) * U256::from(1_000_000 - fee);
+    Some(numerator / denominator + 1)
+}
+
+/// # Description
+/// packed path of a uniswap v3 style multi hop swap: `tokens[0]`, then the 3 bytes fee of the
+/// first pool and `tokens[1]`, and so on
+///
+/// Returns `None` if there is not exactly one fee per hop
+pub fn encode_v3_path(tokens: &[Address], fees: &[u32]) -> Option<Bytes> {
+    if tokens.len() < 2 || fees.len() + 1 != tokens.len() {
+        return None;
+    }
+    let mut path = Vec::with_capacity(20 + 23 * fees.len());
+    path.extend_from_slice(tokens[0].as_bytes());
+    for (fee, token) in fees.iter().zip(&tokens[1..]) {
+        path.extend_from_slice(&fee.to_be_bytes()[1..]);
+        path.extend_from_slice(token.as_bytes());
+    }
+    Some(path.into())
+}
+
 pub fn get_tick_from_slot_value(hash: H256) -> i32 {
     let bytes = hash.0;
     let u8_array = &bytes[9..12];
@@ -60,6 +162,85 @@ mod test {
         assert_eq!(get_token0_and_token1(&token_b, &token_c), (token_c, token_b));
     }
 
+    #[test]
+    fn test_get_amount_out_min() {
+        assert_eq!(get_amount_out_min(U256::from(1_000_000), 50), U256::from(995_000));
+        assert_eq!(get_amount_out_min(U256::from(1_999), 1), U256::from(1_998));
+        assert_eq!(get_amount_out_min(U256::from(1_000_000), 0), U256::from(1_000_000));
+        assert_eq!(get_amount_out_min(U256::from(1_000_000), 20_000), U256::zero());
+    }
+
+    #[test]
+    fn test_get_amount_in_max() {
+        assert_eq!(get_amount_in_max(U256::from(1_000_000), 50), U256::from(1_005_000));
+        assert_eq!(get_amount_in_max(U256::from(1_999), 1), U256::from(2_000));
+        assert_eq!(get_amount_in_max(U256::from(1_000_000), 0), U256::from(1_000_000));
+    }
+
+    #[test]
+    fn test_get_bounded_amount_out_min() {
+        let quoted = U256::from(1_000_000);
+        assert_eq!(get_bounded_amount_out_min(quoted, None, 50), Some(U256::from(995_000)));
+        // quote better than expected, the quote based bound is tighter
+        assert_eq!(
+            get_bounded_amount_out_min(quoted, Some(U256::from(990_000)), 50),
+            Some(U256::from(995_000))
+        );
+        // quote slightly worse than expected, but within tolerance
+        assert_eq!(
+            get_bounded_amount_out_min(quoted, Some(U256::from(1_004_000)), 50),
+            Some(U256::from(998_980))
+        );
+        // quote beyond tolerance
+        assert_eq!(get_bounded_amount_out_min(quoted, Some(U256::from(1_010_000)), 50), None);
+    }
+
+    #[test]
+    fn test_get_bounded_amount_in_max() {
+        let quoted = U256::from(1_000_000);
+        assert_eq!(get_bounded_amount_in_max(quoted, None, 50), Some(U256::from(1_005_000)));
+        assert_eq!(
+            get_bounded_amount_in_max(quoted, Some(U256::from(1_010_000)), 50),
+            Some(U256::from(1_005_000))
+        );
+        assert_eq!(
+            get_bounded_amount_in_max(quoted, Some(U256::from(996_000)), 50),
+            Some(U256::from(1_000_980))
+        );
+        assert_eq!(get_bounded_amount_in_max(quoted, Some(U256::from(990_000)), 50), None);
+    }
+
+    #[test]
+    fn test_get_v2_amount_out_and_in() {
+        // UniswapV2Library test vectors, with the 0.3% fee
+        let (reserve, fee) = (U256::from(100), 3000);
+        assert_eq!(get_v2_amount_out(U256::from(2), reserve, reserve, fee), U256::from(1));
+        assert_eq!(get_v2_amount_in(U256::from(1), reserve, reserve, fee), Some(U256::from(2)));
+        assert_eq!(get_v2_amount_out(U256::zero(), reserve, reserve, fee), U256::zero());
+        assert_eq!(get_v2_amount_in(U256::from(100), reserve, reserve, fee), None);
+
+        // pancake charges 0.25%
+        let (reserve_in, reserve_out) = (U256::exp10(21), U256::exp10(24));
+        assert_eq!(
+            get_v2_amount_out(U256::exp10(18), reserve_in, reserve_out, 2500),
+            U256::from_dec_str("996505985279683515693").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_v3_path() {
+        let usdc = address_from_str("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let weth = address_from_str("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let arb = address_from_str("0xB50721BCf8d664c30412Cfbc6cf7a15145234ad1");
+        let path = encode_v3_path(&[arb, weth, usdc], &[3000, 500]).unwrap();
+        assert_eq!(
+            path.to_string(),
+            "0xb50721bcf8d664c30412cfbc6cf7a15145234ad1000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20001f4a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        );
+        assert_eq!(encode_v3_path(&[arb, weth], &[3000, 500]), None);
+        assert_eq!(encode_v3_path(&[arb], &[]), None);
+    }
+
     #[test]
     fn test_get_tick_from_slot_value() {
         let hash =
@@ -0,0 +1,830 @@
+//! uniswap v3 swap math, ported from `v3-core` (`TickMath`, `SqrtPriceMath`, `SwapMath`, `TickBitmap`)
+//! so that a swap can be quoted from a local pool snapshot instead of an `eth_call` to `QuoterV2`,
+//! and the position math of `v3-periphery` (`LiquidityAmounts`)
+use ethers::prelude::*;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+pub const MIN_TICK: i32 = -887272;
+pub const MAX_TICK: i32 = -MIN_TICK;
+
+/// fee is expressed in hundredths of a bip
+const FEE_DENOMINATOR: u32 = 1_000_000;
+
+lazy_static! {
+    /// sqrt ratio at `MIN_TICK`
+    pub static ref MIN_SQRT_RATIO: U256 = U256::from(4295128739u64);
+    /// sqrt ratio at `MAX_TICK`
+    pub static ref MAX_SQRT_RATIO: U256 =
+        U256::from_dec_str("1461446703485210103287273052203988822378723970342").unwrap();
+    static ref Q96: U256 = U256::one() << 96;
+    static ref MAX_U160: U256 = (U256::one() << 160) - 1;
+    static ref TICK_RATIO_FACTORS: Vec<U256> = [
+        "fff97272373d413259a46990580e213a",
+        "fff2e50f5f656932ef12357cf3c7fdcc",
+        "ffe5caca7e10e4e61c3624eaa0941cd0",
+        "ffcb9843d60f6159c9db58835c926644",
+        "ff973b41fa98c081472e6896dfb254c0",
+        "ff2ea16466c96a3843ec78b326b52861",
+        "fe5dee046a99a2a811c461f1969c3053",
+        "fcbe86c7900a88aedcffc83b479aa3a4",
+        "f987a7253ac413176f2b074cf7815e54",
+        "f3392b0822b70005940c7a398e4b70f3",
+        "e7159475a2c29b7443b29c7fa6e889d9",
+        "d097f3bdfd2022b8845ad8f792aa5825",
+        "a9f746462d870fdf8a65dc1f90e061e5",
+        "70d869a156d2a1b890bb3df62baf32f7",
+        "31be135f97d08fd981231505542fcfa6",
+        "9aa508b5b7a84e1c677de54f3e99bc9",
+        "5d6af8dedb81196699c329225ee604",
+        "2216e584f5fa1ea926041bedfe98",
+        "48a170391f7dc42444e8fa2",
+    ]
+    .iter()
+    .map(|hex| U256::from_str_radix(hex, 16).unwrap())
+    .collect();
+}
+
+/// floor(a * b / denominator) with a 512 bit intermediate product; `None` if the result overflows
+pub fn mul_div(a: U256, b: U256, denominator: U256) -> Option<U256> {
+    if denominator.is_zero() {
+        return None;
+    }
+    U256::try_from(a.full_mul(b) / U512::from(denominator)).ok()
+}
+
+/// ceil(a * b / denominator) with a 512 bit intermediate product; `None` if the result overflows
+pub fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> Option<U256> {
+    let result = mul_div(a, b, denominator)?;
+    if (a.full_mul(b) % U512::from(denominator)).is_zero() {
+        Some(result)
+    } else {
+        result.checked_add(U256::one())
+    }
+}
+
+fn div_rounding_up(a: U256, b: U256) -> U256 {
+    let (quotient, remainder) = a.div_mod(b);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        quotient + 1
+    }
+}
+
+/// sqrt(1.0001^tick) * 2^96
+pub fn get_sqrt_ratio_at_tick(tick: i32) -> U256 {
+    assert!((MIN_TICK..=MAX_TICK).contains(&tick), "tick {} out of range", tick);
+    let abs_tick = tick.unsigned_abs();
+
+    let mut ratio = if abs_tick & 0x1 != 0 {
+        U256::from_str_radix("fffcb933bd6fad37aa2d162d1a594001", 16).unwrap()
+    } else {
+        U256::one() << 128
+    };
+    for (i, factor) in TICK_RATIO_FACTORS.iter().enumerate() {
+        if abs_tick & (0x2 << i) != 0 {
+            ratio = (ratio * factor) >> 128;
+        }
+    }
+    if tick > 0 {
+        ratio = U256::MAX / ratio;
+    }
+
+    // round up, so that get_tick_at_sqrt_ratio of the result is consistent
+    let rounding = if (ratio & U256::from(u32::MAX)).is_zero() { 0 } else { 1 };
+    (ratio >> 32) + rounding
+}
+
+/// greatest tick whose sqrt ratio is less than or equal to `sqrt_price_x96`
+pub fn get_tick_at_sqrt_ratio(sqrt_price_x96: U256) -> i32 {
+    assert!(
+        sqrt_price_x96 >= *MIN_SQRT_RATIO && sqrt_price_x96 < *MAX_SQRT_RATIO,
+        "sqrt price {} out of range",
+        sqrt_price_x96
+    );
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if get_sqrt_ratio_at_tick(mid) <= sqrt_price_x96 {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
+
+fn get_next_sqrt_price_from_amount0_rounding_up(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> Option<U256> {
+    if amount.is_zero() {
+        return Some(sqrt_price_x96);
+    }
+    let numerator1 = U256::from(liquidity) << 96;
+    let product = amount.checked_mul(sqrt_price_x96);
+
+    if add {
+        if let Some(denominator) = product.and_then(|product| numerator1.checked_add(product)) {
+            return mul_div_rounding_up(numerator1, sqrt_price_x96, denominator);
+        }
+        Some(div_rounding_up(numerator1, (numerator1 / sqrt_price_x96).checked_add(amount)?))
+    } else {
+        let product = product?;
+        if numerator1 <= product {
+            return None;
+        }
+        mul_div_rounding_up(numerator1, sqrt_price_x96, numerator1 - product)
+            .filter(|price| *price <= *MAX_U160)
+    }
+}
+
+fn get_next_sqrt_price_from_amount1_rounding_down(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> Option<U256> {
+    let liquidity = U256::from(liquidity);
+    if add {
+        let quotient = if amount <= *MAX_U160 {
+            (amount << 96) / liquidity
+        } else {
+            mul_div(amount, *Q96, liquidity)?
+        };
+        sqrt_price_x96.checked_add(quotient).filter(|price| *price <= *MAX_U160)
+    } else {
+        let quotient = if amount <= *MAX_U160 {
+            div_rounding_up(amount << 96, liquidity)
+        } else {
+            mul_div_rounding_up(amount, *Q96, liquidity)?
+        };
+        sqrt_price_x96.checked_sub(quotient).filter(|price| !price.is_zero())
+    }
+}
+
+fn get_next_sqrt_price_from_input(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_in: U256,
+    zero_for_one: bool,
+) -> Option<U256> {
+    if zero_for_one {
+        get_next_sqrt_price_from_amount0_rounding_up(sqrt_price_x96, liquidity, amount_in, true)
+    } else {
+        get_next_sqrt_price_from_amount1_rounding_down(sqrt_price_x96, liquidity, amount_in, true)
+    }
+}
+
+fn get_next_sqrt_price_from_output(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_out: U256,
+    zero_for_one: bool,
+) -> Option<U256> {
+    if zero_for_one {
+        get_next_sqrt_price_from_amount1_rounding_down(sqrt_price_x96, liquidity, amount_out, false)
+    } else {
+        get_next_sqrt_price_from_amount0_rounding_up(sqrt_price_x96, liquidity, amount_out, false)
+    }
+}
+
+/// amount of token0 between two prices for the given liquidity
+pub fn get_amount0_delta(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> Option<U256> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        (sqrt_ratio_b_x96, sqrt_ratio_a_x96)
+    } else {
+        (sqrt_ratio_a_x96, sqrt_ratio_b_x96)
+    };
+    if sqrt_ratio_a_x96.is_zero() {
+        return None;
+    }
+    let numerator1 = U256::from(liquidity) << 96;
+    let numerator2 = sqrt_ratio_b_x96 - sqrt_ratio_a_x96;
+
+    if round_up {
+        let value = mul_div_rounding_up(numerator1, numerator2, sqrt_ratio_b_x96)?;
+        Some(div_rounding_up(value, sqrt_ratio_a_x96))
+    } else {
+        Some(mul_div(numerator1, numerator2, sqrt_ratio_b_x96)? / sqrt_ratio_a_x96)
+    }
+}
+
+/// amount of token1 between two prices for the given liquidity
+pub fn get_amount1_delta(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> Option<U256> {
+    let diff = if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        sqrt_ratio_a_x96 - sqrt_ratio_b_x96
+    } else {
+        sqrt_ratio_b_x96 - sqrt_ratio_a_x96
+    };
+    if round_up {
+        mul_div_rounding_up(U256::from(liquidity), diff, *Q96)
+    } else {
+        mul_div(U256::from(liquidity), diff, *Q96)
+    }
+}
+
+/// sorts two sqrt ratios, lowest first
+fn sort_sqrt_ratios(sqrt_ratio_a_x96: U256, sqrt_ratio_b_x96: U256) -> (U256, U256) {
+    if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        (sqrt_ratio_b_x96, sqrt_ratio_a_x96)
+    } else {
+        (sqrt_ratio_a_x96, sqrt_ratio_b_x96)
+    }
+}
+
+/// liquidity received for `amount_0` of token0 between two prices, rounded down
+pub fn get_liquidity_for_amount0(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    amount_0: U256,
+) -> Option<u128> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort_sqrt_ratios(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    let intermediate = mul_div(sqrt_ratio_a_x96, sqrt_ratio_b_x96, *Q96)?;
+    let liquidity = mul_div(amount_0, intermediate, sqrt_ratio_b_x96 - sqrt_ratio_a_x96)?;
+    u128::try_from(liquidity).ok()
+}
+
+/// liquidity received for `amount_1` of token1 between two prices, rounded down
+pub fn get_liquidity_for_amount1(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    amount_1: U256,
+) -> Option<u128> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort_sqrt_ratios(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    let liquidity = mul_div(amount_1, *Q96, sqrt_ratio_b_x96 - sqrt_ratio_a_x96)?;
+    u128::try_from(liquidity).ok()
+}
+
+/// max liquidity received for `amount_0` and `amount_1` between two prices, at the current
+/// price `sqrt_price_x96`
+pub fn get_liquidity_for_amounts(
+    sqrt_price_x96: U256,
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    amount_0: U256,
+    amount_1: U256,
+) -> Option<u128> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort_sqrt_ratios(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    if sqrt_price_x96 <= sqrt_ratio_a_x96 {
+        get_liquidity_for_amount0(sqrt_ratio_a_x96, sqrt_ratio_b_x96, amount_0)
+    } else if sqrt_price_x96 < sqrt_ratio_b_x96 {
+        let liquidity_0 = get_liquidity_for_amount0(sqrt_price_x96, sqrt_ratio_b_x96, amount_0)?;
+        let liquidity_1 = get_liquidity_for_amount1(sqrt_ratio_a_x96, sqrt_price_x96, amount_1)?;
+        Some(liquidity_0.min(liquidity_1))
+    } else {
+        get_liquidity_for_amount1(sqrt_ratio_a_x96, sqrt_ratio_b_x96, amount_1)
+    }
+}
+
+/// (amount0, amount1) held by `liquidity` between two prices at the current price
+/// `sqrt_price_x96`, rounded down
+pub fn get_amounts_for_liquidity(
+    sqrt_price_x96: U256,
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: u128,
+) -> Option<(U256, U256)> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort_sqrt_ratios(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    if sqrt_price_x96 <= sqrt_ratio_a_x96 {
+        let amount_0 = get_amount0_delta(sqrt_ratio_a_x96, sqrt_ratio_b_x96, liquidity, false)?;
+        Some((amount_0, U256::zero()))
+    } else if sqrt_price_x96 < sqrt_ratio_b_x96 {
+        let amount_0 = get_amount0_delta(sqrt_price_x96, sqrt_ratio_b_x96, liquidity, false)?;
+        let amount_1 = get_amount1_delta(sqrt_ratio_a_x96, sqrt_price_x96, liquidity, false)?;
+        Some((amount_0, amount_1))
+    } else {
+        let amount_1 = get_amount1_delta(sqrt_ratio_a_x96, sqrt_ratio_b_x96, liquidity, false)?;
+        Some((U256::zero(), amount_1))
+    }
+}
+
+/// result of swapping within a single tick range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapStep {
+    pub sqrt_ratio_next_x96: U256,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub fee_amount: U256,
+}
+
+/// # Description
+/// Computes the result of swapping some amount in, or out, given the parameters of the swap
+/// # Arguments
+/// * `amount_remaining` - positive for exact input, negative for exact output
+/// * `fee_pips` - fee in hundredths of a bip
+pub fn compute_swap_step(
+    sqrt_ratio_current_x96: U256,
+    sqrt_ratio_target_x96: U256,
+    liquidity: u128,
+    amount_remaining: I256,
+    fee_pips: u32,
+) -> Option<SwapStep> {
+    let zero_for_one = sqrt_ratio_current_x96 >= sqrt_ratio_target_x96;
+    let exact_in = !amount_remaining.is_negative();
+    let amount_remaining_abs = amount_remaining.unsigned_abs();
+
+    let mut amount_in = U256::zero();
+    let mut amount_out = U256::zero();
+    let sqrt_ratio_next_x96 = if exact_in {
+        let amount_remaining_less_fee = mul_div(
+            amount_remaining_abs,
+            U256::from(FEE_DENOMINATOR - fee_pips),
+            U256::from(FEE_DENOMINATOR),
+        )?;
+        amount_in = if zero_for_one {
+            get_amount0_delta(sqrt_ratio_target_x96, sqrt_ratio_current_x96, liquidity, true)?
+        } else {
+            get_amount1_delta(sqrt_ratio_current_x96, sqrt_ratio_target_x96, liquidity, true)?
+        };
+        if amount_remaining_less_fee >= amount_in {
+            sqrt_ratio_target_x96
+        } else {
+            get_next_sqrt_price_from_input(
+                sqrt_ratio_current_x96,
+                liquidity,
+                amount_remaining_less_fee,
+                zero_for_one,
+            )?
+        }
+    } else {
+        amount_out = if zero_for_one {
+            get_amount1_delta(sqrt_ratio_target_x96, sqrt_ratio_current_x96, liquidity, false)?
+        } else {
+            get_amount0_delta(sqrt_ratio_current_x96, sqrt_ratio_target_x96, liquidity, false)?
+        };
+        if amount_remaining_abs >= amount_out {
+            sqrt_ratio_target_x96
+        } else {
+            get_next_sqrt_price_from_output(
+                sqrt_ratio_current_x96,
+                liquidity,
+                amount_remaining_abs,
+                zero_for_one,
+            )?
+        }
+    };
+
+    let max = sqrt_ratio_target_x96 == sqrt_ratio_next_x96;
+    if zero_for_one {
+        if !(max && exact_in) {
+            amount_in =
+                get_amount0_delta(sqrt_ratio_next_x96, sqrt_ratio_current_x96, liquidity, true)?;
+        }
+        if !max || exact_in {
+            amount_out =
+                get_amount1_delta(sqrt_ratio_next_x96, sqrt_ratio_current_x96, liquidity, false)?;
+        }
+    } else {
+        if !(max && exact_in) {
+            amount_in =
+                get_amount1_delta(sqrt_ratio_current_x96, sqrt_ratio_next_x96, liquidity, true)?;
+        }
+        if !max || exact_in {
+            amount_out =
+                get_amount0_delta(sqrt_ratio_current_x96, sqrt_ratio_next_x96, liquidity, false)?;
+        }
+    }
+
+    // cap the output amount to not exceed the remaining output amount
+    if !exact_in && amount_out > amount_remaining_abs {
+        amount_out = amount_remaining_abs;
+    }
+
+    let fee_amount = if exact_in && sqrt_ratio_next_x96 != sqrt_ratio_target_x96 {
+        // we didn't reach the target, so take the remainder of the maximum input as fee
+        amount_remaining_abs - amount_in
+    } else {
+        mul_div_rounding_up(
+            amount_in,
+            U256::from(fee_pips),
+            U256::from(FEE_DENOMINATOR - fee_pips),
+        )?
+    };
+
+    Some(SwapStep { sqrt_ratio_next_x96, amount_in, amount_out, fee_amount })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TickInfo {
+    pub liquidity_gross: u128,
+    pub liquidity_net: i128,
+}
+
+/// result of a simulated swap, signed from the pool's perspective like `UniswapV3Pool.swap`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V3SwapResult {
+    pub amount_0: I256,
+    pub amount_1: I256,
+    pub sqrt_price_x96: U256,
+    pub tick: i32,
+    pub liquidity: u128,
+    pub initialized_ticks_crossed: u32,
+}
+
+/// local snapshot of the uniswap v3 pool state that is needed to simulate a swap
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct V3PoolState {
+    pub sqrt_price_x96: U256,
+    pub tick: i32,
+    pub liquidity: u128,
+    pub fee: u32,
+    pub tick_spacing: i32,
+    pub tick_bitmap: HashMap<i16, U256>,
+    pub ticks: HashMap<i32, TickInfo>,
+    /// inclusive range of bitmap words held by the snapshot; `None` if it holds all of them.
+    /// a swap that needs a word outside of the range can not be simulated
+    pub word_range: Option<(i16, i16)>,
+}
+
+impl V3PoolState {
+    pub fn new(
+        sqrt_price_x96: U256,
+        tick: i32,
+        liquidity: u128,
+        fee: u32,
+        tick_spacing: i32,
+    ) -> Self {
+        Self { sqrt_price_x96, tick, liquidity, fee, tick_spacing, ..Default::default() }
+    }
+
+    fn compress(&self, tick: i32) -> i32 {
+        tick.div_euclid(self.tick_spacing)
+    }
+
+    fn word(&self, word_pos: i16) -> Option<U256> {
+        if let Some((low, high)) = self.word_range {
+            if word_pos < low || word_pos > high {
+                return None;
+            }
+        }
+        Some(self.tick_bitmap.get(&word_pos).copied().unwrap_or_default())
+    }
+
+    /// whether the current tick reached a word at an edge of `word_range` past which the pool has
+    /// more words, so that a swap may soon need words the snapshot does not hold
+    pub fn is_at_word_range_edge(&self) -> bool {
+        let Some((low, high)) = self.word_range else {
+            return false;
+        };
+        let word_pos = |tick: i32| (self.compress(tick) >> 8) as i16;
+        let current_word = word_pos(self.tick);
+        (current_word <= low && low > word_pos(MIN_TICK))
+            || (current_word >= high && high < word_pos(MAX_TICK))
+    }
+
+    fn flip_tick(&mut self, tick: i32) {
+        let compressed = self.compress(tick);
+        let (word_pos, bit_pos) = ((compressed >> 8) as i16, (compressed & 0xff) as usize);
+        let word = self.tick_bitmap.entry(word_pos).or_default();
+        *word ^= U256::one() << bit_pos;
+    }
+
+    /// next initialized tick contained in the same bitmap word as `tick`, to the left (`lte`) or
+    /// right of it; `None` if the word is not held by the snapshot
+    pub fn next_initialized_tick_within_one_word(
+        &self,
+        tick: i32,
+        lte: bool,
+    ) -> Option<(i32, bool)> {
+        let compressed = self.compress(tick);
+        if lte {
+            let (word_pos, bit_pos) = ((compressed >> 8) as i16, compressed & 0xff);
+            let mask = (U256::one() << bit_pos) - 1 + (U256::one() << bit_pos);
+            let masked = self.word(word_pos)? & mask;
+            let initialized = !masked.is_zero();
+            let next = if initialized {
+                let most_significant_bit = 255 - masked.leading_zeros() as i32;
+                (compressed - (bit_pos - most_significant_bit)) * self.tick_spacing
+            } else {
+                (compressed - bit_pos) * self.tick_spacing
+            };
+            Some((next, initialized))
+        } else {
+            let (word_pos, bit_pos) = (((compressed + 1) >> 8) as i16, (compressed + 1) & 0xff);
+            let mask = !((U256::one() << bit_pos) - 1);
+            let masked = self.word(word_pos)? & mask;
+            let initialized = !masked.is_zero();
+            let next = if initialized {
+                let least_significant_bit = masked.trailing_zeros() as i32;
+                (compressed + 1 + (least_significant_bit - bit_pos)) * self.tick_spacing
+            } else {
+                (compressed + 1 + (255 - bit_pos)) * self.tick_spacing
+            };
+            Some((next, initialized))
+        }
+    }
+
+    /// # Description
+    /// Simulates `UniswapV3Pool.swap` against the snapshot, without mutating it
+    /// # Arguments
+    /// * `amount_specified` - positive for exact input, negative for exact output
+    /// * `sqrt_price_limit_x96` - the price can not move beyond it
+    pub fn swap(
+        &self,
+        zero_for_one: bool,
+        amount_specified: I256,
+        sqrt_price_limit_x96: U256,
+    ) -> Option<V3SwapResult> {
+        if amount_specified.is_zero() {
+            return None;
+        }
+        let limit_valid = if zero_for_one {
+            sqrt_price_limit_x96 < self.sqrt_price_x96 && sqrt_price_limit_x96 > *MIN_SQRT_RATIO
+        } else {
+            sqrt_price_limit_x96 > self.sqrt_price_x96 && sqrt_price_limit_x96 < *MAX_SQRT_RATIO
+        };
+        if !limit_valid {
+            return None;
+        }
+
+        let exact_input = amount_specified.is_positive();
+        let mut amount_specified_remaining = amount_specified;
+        let mut amount_calculated = I256::zero();
+        let mut sqrt_price_x96 = self.sqrt_price_x96;
+        let mut tick = self.tick;
+        let mut liquidity = self.liquidity;
+        let mut initialized_ticks_crossed = 0;
+
+        while !amount_specified_remaining.is_zero() && sqrt_price_x96 != sqrt_price_limit_x96 {
+            let sqrt_price_start_x96 = sqrt_price_x96;
+            let (tick_next, initialized) =
+                self.next_initialized_tick_within_one_word(tick, zero_for_one)?;
+            let tick_next = tick_next.clamp(MIN_TICK, MAX_TICK);
+            let sqrt_price_next_x96 = get_sqrt_ratio_at_tick(tick_next);
+
+            let sqrt_price_target_x96 = if zero_for_one {
+                sqrt_price_next_x96.max(sqrt_price_limit_x96)
+            } else {
+                sqrt_price_next_x96.min(sqrt_price_limit_x96)
+            };
+            let step = compute_swap_step(
+                sqrt_price_x96,
+                sqrt_price_target_x96,
+                liquidity,
+                amount_specified_remaining,
+                self.fee,
+            )?;
+            sqrt_price_x96 = step.sqrt_ratio_next_x96;
+
+            if exact_input {
+                amount_specified_remaining -= I256::from_raw(step.amount_in + step.fee_amount);
+                amount_calculated -= I256::from_raw(step.amount_out);
+            } else {
+                amount_specified_remaining += I256::from_raw(step.amount_out);
+                amount_calculated += I256::from_raw(step.amount_in + step.fee_amount);
+            }
+
+            if sqrt_price_x96 == sqrt_price_next_x96 {
+                // crossed into the next tick range
+                if initialized {
+                    let liquidity_net =
+                        self.ticks.get(&tick_next).map(|info| info.liquidity_net).unwrap_or(0);
+                    let liquidity_net = if zero_for_one { -liquidity_net } else { liquidity_net };
+                    liquidity = liquidity.checked_add_signed(liquidity_net)?;
+                    initialized_ticks_crossed += 1;
+                }
+                tick = if zero_for_one { tick_next - 1 } else { tick_next };
+            } else if sqrt_price_x96 != sqrt_price_start_x96 {
+                tick = get_tick_at_sqrt_ratio(sqrt_price_x96);
+            }
+        }
+
+        let (amount_0, amount_1) = if zero_for_one == exact_input {
+            (amount_specified - amount_specified_remaining, amount_calculated)
+        } else {
+            (amount_calculated, amount_specified - amount_specified_remaining)
+        };
+        Some(V3SwapResult {
+            amount_0,
+            amount_1,
+            sqrt_price_x96,
+            tick,
+            liquidity,
+            initialized_ticks_crossed,
+        })
+    }
+
+    /// amount out of swapping `amount_in`, as `QuoterV2.quoteExactInputSingle` with no price limit
+    pub fn quote_exact_input(&self, zero_for_one: bool, amount_in: U256) -> Option<U256> {
+        let ret =
+            self.swap(zero_for_one, I256::from_raw(amount_in), no_price_limit(zero_for_one))?;
+        let amount_out = if zero_for_one { ret.amount_1 } else { ret.amount_0 };
+        Some(amount_out.unsigned_abs())
+    }
+
+    /// amount in needed to receive `amount_out`, as `QuoterV2.quoteExactOutputSingle` with no price
+    /// limit; `None` if the pool can not fill the whole output
+    pub fn quote_exact_output(&self, zero_for_one: bool, amount_out: U256) -> Option<U256> {
+        let ret =
+            self.swap(zero_for_one, -I256::from_raw(amount_out), no_price_limit(zero_for_one))?;
+        let (amount_in, amount_received) =
+            if zero_for_one { (ret.amount_0, ret.amount_1) } else { (ret.amount_1, ret.amount_0) };
+        if amount_received.unsigned_abs() != amount_out {
+            return None;
+        }
+        Some(amount_in.into_raw())
+    }
+
+    /// apply a `Swap` event of the pool
+    pub fn apply_swap(&mut self, sqrt_price_x96: U256, liquidity: u128, tick: i32) {
+        self.sqrt_price_x96 = sqrt_price_x96;
+        self.liquidity = liquidity;
+        self.tick = tick;
+    }
+
+    /// apply a `Mint` (positive `liquidity_delta`) or `Burn` (negative) event of the pool
+    pub fn apply_liquidity_delta(
+        &mut self,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity_delta: i128,
+    ) {
+        if liquidity_delta == 0 {
+            return;
+        }
+        self.update_tick(tick_lower, liquidity_delta, false);
+        self.update_tick(tick_upper, liquidity_delta, true);
+        if tick_lower <= self.tick && self.tick < tick_upper {
+            self.liquidity = self.liquidity.saturating_add_signed(liquidity_delta);
+        }
+    }
+
+    fn update_tick(&mut self, tick: i32, liquidity_delta: i128, upper: bool) {
+        let info = self.ticks.entry(tick).or_default();
+        let liquidity_gross_before = info.liquidity_gross;
+        info.liquidity_gross = info.liquidity_gross.saturating_add_signed(liquidity_delta);
+        info.liquidity_net += if upper { -liquidity_delta } else { liquidity_delta };
+
+        let liquidity_gross_after = info.liquidity_gross;
+        if liquidity_gross_after == 0 {
+            self.ticks.remove(&tick);
+        }
+        if (liquidity_gross_before == 0) != (liquidity_gross_after == 0) {
+            self.flip_tick(tick);
+        }
+    }
+}
+
+/// the price limit `QuoterV2` uses when none is given
+fn no_price_limit(zero_for_one: bool) -> U256 {
+    if zero_for_one {
+        *MIN_SQRT_RATIO + 1
+    } else {
+        *MAX_SQRT_RATIO - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_sqrt_ratio_at_tick() {
+        assert_eq!(get_sqrt_ratio_at_tick(MIN_TICK), *MIN_SQRT_RATIO);
+        assert_eq!(get_sqrt_ratio_at_tick(MAX_TICK), *MAX_SQRT_RATIO);
+        assert_eq!(get_sqrt_ratio_at_tick(0), *Q96);
+    }
+
+    /// pool `createPoolWithMultiplePositions` of the v3-periphery `QuoterV2` spec: fee 3000, price 1,
+    /// a full range position plus positions over [-60, 60] and [-120, 120]
+    fn quoter_v2_spec_pool() -> V3PoolState {
+        let mut state = V3PoolState::new(*Q96, 0, 0, 3000, 60);
+        state.apply_liquidity_delta(-887220, 887220, 1_000_000);
+        state.apply_liquidity_delta(-60, 60, 33385);
+        state.apply_liquidity_delta(-120, 120, 16717);
+        state
+    }
+
+    #[test]
+    fn test_swap_matches_quoter_v2() {
+        let state = quoter_v2_spec_pool();
+        assert_eq!(state.liquidity, 1_050_102);
+
+        // (zero_for_one, exact input, amount, quoted amount, sqrt price after, initialized ticks crossed)
+        let recorded = [
+            (true, true, 10000, 9871, "78461846509168490764501028180", 2),
+            (true, true, 6200, 6143, "78757224507315167622282810783", 1),
+            (true, true, 4000, 3971, "78926452400586371254602774705", 1),
+            (true, true, 10, 8, "79227483487511329217250071027", 0),
+            (false, true, 10000, 9871, "80001962924147897865541384515", 2),
+            (false, true, 6250, 6190, "79705728824507063507279123685", 2),
+            (true, false, 15000, 15273, "78055527257643669242286029831", 2),
+            (true, false, 6143, 6200, "78757225449310403327341205211", 1),
+            (true, false, 4000, 4029, "78924219757724709840818372098", 1),
+            (true, false, 10, 12, "79227408033628034983534698435", 0),
+            (false, false, 15000, 15273, "80418414376567919517220409857", 2),
+            (false, false, 6223, 6283, "79708304437530892332449657932", 2),
+            (false, false, 6000, 6055, "79690640184021170956740081887", 1),
+        ];
+        for (zero_for_one, exact_input, amount, quoted, sqrt_price_after, ticks_crossed) in recorded
+        {
+            let amount = U256::from(amount);
+            let amount_specified =
+                if exact_input { I256::from_raw(amount) } else { -I256::from_raw(amount) };
+            let ret =
+                state.swap(zero_for_one, amount_specified, no_price_limit(zero_for_one)).unwrap();
+            assert_eq!(ret.sqrt_price_x96, U256::from_dec_str(sqrt_price_after).unwrap());
+            assert_eq!(ret.initialized_ticks_crossed, ticks_crossed);
+
+            let ret = if exact_input {
+                state.quote_exact_input(zero_for_one, amount)
+            } else {
+                state.quote_exact_output(zero_for_one, amount)
+            };
+            assert_eq!(ret, Some(U256::from(quoted)));
+        }
+    }
+
+    #[test]
+    fn test_apply_liquidity_delta() {
+        let mut state = quoter_v2_spec_pool();
+        // a step stops at the word boundary even if the tick there is not initialized
+        assert_eq!(state.next_initialized_tick_within_one_word(0, true), Some((0, false)));
+        assert_eq!(state.next_initialized_tick_within_one_word(-1, true), Some((-60, true)));
+        assert_eq!(state.next_initialized_tick_within_one_word(0, false), Some((60, true)));
+
+        state.apply_liquidity_delta(-60, 60, -33385);
+        assert_eq!(state.liquidity, 1_016_717);
+        assert!(!state.ticks.contains_key(&60));
+        assert_eq!(state.next_initialized_tick_within_one_word(0, false), Some((120, true)));
+
+        state.word_range = Some((-1, 0));
+        assert_eq!(
+            state.next_initialized_tick_within_one_word(-60 * 256, true),
+            Some((-15360, false))
+        );
+        assert_eq!(state.next_initialized_tick_within_one_word(-60 * 257, true), None);
+    }
+
+    #[test]
+    fn test_is_at_word_range_edge() {
+        let mut state = quoter_v2_spec_pool();
+        assert!(!state.is_at_word_range_edge());
+        state.word_range = Some((-2, 2));
+        assert!(!state.is_at_word_range_edge());
+        state.word_range = Some((-1, 0));
+        assert!(state.is_at_word_range_edge());
+
+        state.word_range = Some((-3, 1));
+        state.apply_swap(get_sqrt_ratio_at_tick(-60 * 768), 0, -60 * 768);
+        assert!(state.is_at_word_range_edge());
+        // there is no word past the lowest one to load
+        state.apply_swap(get_sqrt_ratio_at_tick(MIN_TICK), 0, MIN_TICK);
+        state.word_range = Some(((MIN_TICK.div_euclid(60) >> 8) as i16, 0));
+        assert!(!state.is_at_word_range_edge());
+    }
+
+    #[test]
+    fn test_liquidity_amounts() {
+        let (sqrt_ratio_a, sqrt_ratio_b) =
+            (get_sqrt_ratio_at_tick(-60), get_sqrt_ratio_at_tick(60));
+        let amount = U256::exp10(18);
+
+        // in range, the amount of the binding token is deposited in full
+        let liquidity =
+            get_liquidity_for_amounts(*Q96, sqrt_ratio_a, sqrt_ratio_b, amount, amount).unwrap();
+        let (amount_0, amount_1) =
+            get_amounts_for_liquidity(*Q96, sqrt_ratio_a, sqrt_ratio_b, liquidity).unwrap();
+        assert!(amount_0 <= amount && amount_1 <= amount);
+        assert!(amount - amount_0.max(amount_1) <= U256::one());
+
+        // below the range only token0 is deposited, above it only token1
+        let below = get_sqrt_ratio_at_tick(-120);
+        let liquidity =
+            get_liquidity_for_amounts(below, sqrt_ratio_a, sqrt_ratio_b, amount, U256::zero())
+                .unwrap();
+        let (amount_0, amount_1) =
+            get_amounts_for_liquidity(below, sqrt_ratio_a, sqrt_ratio_b, liquidity).unwrap();
+        assert!(amount - amount_0 <= U256::one() && amount_1.is_zero());
+        let above = get_sqrt_ratio_at_tick(120);
+        assert_eq!(
+            get_liquidity_for_amounts(above, sqrt_ratio_a, sqrt_ratio_b, amount, U256::zero()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_get_tick_at_sqrt_ratio() {
+        assert_eq!(get_tick_at_sqrt_ratio(*MIN_SQRT_RATIO), MIN_TICK);
+        assert_eq!(get_tick_at_sqrt_ratio(*MAX_SQRT_RATIO - 1), MAX_TICK - 1);
+        for tick in [-202154, -60, -1, 0, 1, 60, 202094] {
+            let sqrt_ratio = get_sqrt_ratio_at_tick(tick);
+            assert_eq!(get_tick_at_sqrt_ratio(sqrt_ratio), tick);
+            assert_eq!(get_tick_at_sqrt_ratio(sqrt_ratio - 1), tick - 1);
+        }
+    }
+}
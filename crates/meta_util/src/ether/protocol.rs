@@ -1,4 +1,5 @@
 use ethers::prelude::*;
+use meta_common::enums::Network;
 
 /// Calculate the next block base fee
 // based on math provided here: https://ethereum.stackexchange.com/questions/107173/how-is-the-base-fee-per-gas-computed-for-a-new-block
@@ -28,6 +29,54 @@ pub fn calculate_next_block_base_fee(block: Block<TxHash>) -> U256 {
     }
 }
 
+/// average block time of the network, in milliseconds
+pub fn get_block_time_millis(network: Network) -> u64 {
+    match network {
+        Network::ETH | Network::ETH_GOERLI => 12_000,
+        Network::BSC | Network::BSC_TEST => 3_000,
+        Network::MANTLE => 2_000,
+        Network::ZK_SYNC_ERA | Network::ZK_SYNC_ERA_TEST => 1_000,
+        Network::ARBI => 250,
+    }
+}
+
+/// # Description
+/// Calculate the next block base fee following the fee market of `network`
+pub fn calculate_next_block_base_fee_of(network: Network, block: Block<TxHash>) -> U256 {
+    match network {
+        Network::ETH | Network::ETH_GOERLI => calculate_next_block_base_fee(block),
+        // bsc charges a fixed gas price, there is no base fee
+        Network::BSC | Network::BSC_TEST => U256::zero(),
+        // the base fee is set by the sequencer's pricing rather than the elasticity of the last
+        // block, so the latest one is the best estimate
+        _ => block.base_fee_per_gas.unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_calculate_next_block_base_fee_of() {
+        let block = Block::<TxHash> {
+            base_fee_per_gas: Some(U256::from(1_000_000_000u64)),
+            gas_limit: U256::from(30_000_000u64),
+            gas_used: U256::from(30_000_000u64),
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate_next_block_base_fee_of(Network::ETH, block.clone()),
+            U256::from(1_125_000_000u64)
+        );
+        assert_eq!(
+            calculate_next_block_base_fee_of(Network::ARBI, block.clone()),
+            U256::from(1_000_000_000u64)
+        );
+        assert_eq!(calculate_next_block_base_fee_of(Network::BSC, block), U256::zero());
+    }
+}
+
 // #[cfg(test)]
 // mod test_protocol {
 //     use ethers::prelude::*;